@@ -69,7 +69,7 @@ pub async fn db_count(
     credentials: Option<Arc<SharedCredentials>>,
 ) -> Result<usize, TauriFunctionError> {
     let db = db_connection(&app_handle, app_id, table_name, credentials).await?;
-    let cnt = db.count(None).await?;
+    let cnt = db.count(None, None).await?;
     Ok(cnt)
 }
 
@@ -97,7 +97,7 @@ pub async fn db_list(
     let db = db_connection(&app_handle, app_id, Some(table_name), credentials).await?;
     let limit = limit.unwrap_or(100).min(250) as usize;
     let offset = offset.unwrap_or(0) as usize;
-    let items = db.list(None, limit, offset).await?;
+    let items = db.list(None, limit, offset, None).await?;
     Ok(items)
 }
 
@@ -114,6 +114,8 @@ pub struct QueryTablePayload {
     filter: Option<String>,
     fts_term: Option<String>,
     rerank: Option<bool>,
+    rrf_k: Option<f32>,
+    fast_search: Option<bool>,
     select: Option<Vec<String>>,
 }
 
@@ -152,6 +154,8 @@ pub async fn db_query(
                     payload.select,
                     limit,
                     offset,
+                    payload.fast_search.unwrap_or(true),
+                    None,
                 )
                 .await?;
             Ok(items)
@@ -159,7 +163,7 @@ pub async fn db_query(
         (None, Some(fts_term), filter) => {
             let filter_str = filter.as_deref();
             let items = db
-                .fts_search(&fts_term, filter_str, payload.select, limit, offset)
+                .fts_search(&fts_term, filter_str, payload.select, limit, offset, None)
                 .await?;
             Ok(items)
         }
@@ -174,12 +178,16 @@ pub async fn db_query(
                     limit,
                     offset,
                     payload.rerank.unwrap_or(true),
+                    payload.rrf_k,
+                    None,
                 )
                 .await?;
             Ok(items)
         }
         (None, None, Some(filter)) => {
-            let items = db.filter(&filter, payload.select, limit, offset).await?;
+            let items = db
+                .filter(&filter, payload.select, limit, offset, None)
+                .await?;
             Ok(items)
         }
         _ => Err(anyhow::anyhow!("No query parameters provided").into()),
@@ -221,6 +221,18 @@ pub async fn cancel_execution(
     Ok(())
 }
 
+/// Resumes a run paused in a Wait For Signal node holding `token`.
+#[tauri::command(async)]
+pub async fn send_signal(
+    app_handle: AppHandle,
+    token: String,
+    payload: flow_like_types::Value,
+) -> Result<(), TauriFunctionError> {
+    let flow_like_state = TauriFlowLikeState::construct(&app_handle).await?;
+    flow_like_state.lock().await.send_signal(&token, payload)?;
+    Ok(())
+}
+
 #[tauri::command(async)]
 pub async fn list_runs(
     app_handle: AppHandle,
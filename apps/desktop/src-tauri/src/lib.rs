@@ -333,6 +333,7 @@ pub fn run() {
             functions::flow::run::list_runs,
             functions::flow::run::query_run,
             functions::flow::run::cancel_execution,
+            functions::flow::run::send_signal,
             functions::flow::event::validate_event,
             functions::flow::event::get_event,
             functions::flow::event::get_events,
@@ -3,6 +3,9 @@ use axum::{
     routing::{get, post, put},
 };
 
+use flow_like_storage::databases::vector::QueryOptions;
+use std::time::Duration;
+
 use crate::state::AppState;
 
 pub mod build_index;
@@ -16,6 +19,12 @@ pub mod get_db_schema;
 pub mod get_indices;
 pub mod list_tables;
 
+/// Deadline for the queries of the table routes, so a slow query can't hold a request forever.
+const QUERY_OPTIONS: QueryOptions = QueryOptions {
+    timeout: Some(Duration::from_secs(30)),
+    max_concurrent: None,
+};
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/", get(list_tables::list_tables))
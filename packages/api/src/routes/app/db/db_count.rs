@@ -1,3 +1,4 @@
+use super::QUERY_OPTIONS;
 use crate::{
     ensure_permission, error::ApiError, middleware::jwt::AppUser,
     permission::role_permission::RolePermissions, routes::PaginationParams, state::AppState,
@@ -20,7 +21,7 @@ pub async fn db_count(
     let connection = credentials.to_db(&app_id).await?.execute().await?;
     let db = LanceDBVectorStore::from_connection(connection, table).await;
 
-    let count = db.count(None).await?;
+    let count = db.count(None, Some(QUERY_OPTIONS)).await?;
 
     Ok(Json(count))
 }
@@ -1,3 +1,4 @@
+use super::QUERY_OPTIONS;
 use crate::{
     ensure_permission, error::ApiError, middleware::jwt::AppUser,
     permission::role_permission::RolePermissions, routes::PaginationParams, state::AppState,
@@ -24,7 +25,7 @@ pub async fn list_items(
     let connection = credentials.to_db(&app_id).await?.execute().await?;
     let db = LanceDBVectorStore::from_connection(connection, table).await;
 
-    let items = db.list(None, limit, offset).await?;
+    let items = db.list(None, limit, offset, Some(QUERY_OPTIONS)).await?;
 
     Ok(Json(items))
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use super::QUERY_OPTIONS;
 use crate::{
     ensure_permission, error::ApiError, middleware::jwt::AppUser,
     permission::role_permission::RolePermissions, routes::PaginationParams, state::AppState,
@@ -30,6 +31,8 @@ pub struct QueryTablePayload {
     filter: Option<String>,
     fts_term: Option<String>,
     rerank: Option<bool>,
+    rrf_k: Option<f32>,
+    fast_search: Option<bool>,
     select: Option<Vec<String>>,
 }
 
@@ -70,6 +73,8 @@ pub async fn query_table(
                     payload.select,
                     limit,
                     offset,
+                    payload.fast_search.unwrap_or(true),
+                    Some(QUERY_OPTIONS),
                 )
                 .await?;
             return Ok(Json(items));
@@ -77,7 +82,14 @@ pub async fn query_table(
         (None, Some(fts_term), filter) => {
             let filter_str = filter.as_deref();
             let items = db
-                .fts_search(&fts_term, filter_str, payload.select, limit, offset)
+                .fts_search(
+                    &fts_term,
+                    filter_str,
+                    payload.select,
+                    limit,
+                    offset,
+                    Some(QUERY_OPTIONS),
+                )
                 .await?;
             return Ok(Json(items));
         }
@@ -92,12 +104,16 @@ pub async fn query_table(
                     limit,
                     offset,
                     payload.rerank.unwrap_or(true),
+                    payload.rrf_k,
+                    Some(QUERY_OPTIONS),
                 )
                 .await?;
             return Ok(Json(items));
         }
         (None, None, Some(filter)) => {
-            let items = db.filter(&filter, payload.select, limit, offset).await?;
+            let items = db
+                .filter(&filter, payload.select, limit, offset, Some(QUERY_OPTIONS))
+                .await?;
             return Ok(Json(items));
         }
         _ => {
@@ -0,0 +1,317 @@
+use crate::ai::generative::embedding::{CachedEmbeddingModel, CachedEmbeddingModelObject};
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_model_provider::{
+    embedding::EmbeddingModelLogic, image_embedding::ImageEmbeddingModelLogic,
+};
+use flow_like_types::{anyhow, async_trait, bail, json::json, tokio};
+use futures::StreamExt;
+use std::{sync::Arc, time::Duration};
+
+const MAX_BACKOFF_MS: u64 = 30_000;
+
+/// Embeds a large array of texts in provider-sized batches with bounded concurrency.
+/// Failed batches are retried with exponential backoff, a batch that still fails is
+/// split into single items so only the offending texts end up in `errors`.
+#[derive(Default)]
+pub struct EmbedTextsBatchedNode {}
+
+impl EmbedTextsBatchedNode {
+    pub fn new() -> Self {
+        EmbedTextsBatchedNode {}
+    }
+}
+
+enum Embedder {
+    Text(Arc<dyn EmbeddingModelLogic>),
+    Image(Arc<dyn ImageEmbeddingModelLogic>),
+}
+
+impl Embedder {
+    async fn embed(
+        &self,
+        texts: &Vec<String>,
+        query: bool,
+    ) -> flow_like_types::Result<Vec<Vec<f32>>> {
+        let vectors = match (self, query) {
+            (Embedder::Text(model), true) => model.text_embed_query(texts).await?,
+            (Embedder::Text(model), false) => model.text_embed_document(texts).await?,
+            (Embedder::Image(model), true) => model.text_embed_query(texts).await?,
+            (Embedder::Image(model), false) => model.text_embed_document(texts).await?,
+        };
+
+        if vectors.len() != texts.len() {
+            bail!(
+                "Model returned {} vectors for {} texts",
+                vectors.len(),
+                texts.len()
+            );
+        }
+
+        Ok(vectors)
+    }
+}
+
+struct RetryPolicy {
+    query: bool,
+    max_retries: u32,
+    backoff_ms: u64,
+}
+
+/// Exponential backoff for the given (zero based) retry attempt, capped at 30 seconds.
+fn backoff_delay(backoff_ms: u64, attempt: u32) -> u64 {
+    backoff_ms
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(MAX_BACKOFF_MS)
+}
+
+async fn embed_with_retry(
+    embedder: &Embedder,
+    texts: &Vec<String>,
+    policy: &RetryPolicy,
+) -> flow_like_types::Result<Vec<Vec<f32>>> {
+    let mut attempt = 0;
+    loop {
+        match embedder.embed(texts, policy.query).await {
+            Ok(vectors) => return Ok(vectors),
+            Err(error) if attempt >= policy.max_retries => return Err(error),
+            Err(_) => {
+                let delay = backoff_delay(policy.backoff_ms, attempt);
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+async fn embed_batch(
+    embedder: &Embedder,
+    batch: Vec<String>,
+    policy: &RetryPolicy,
+) -> Vec<Result<Vec<f32>, String>> {
+    let error = match embed_with_retry(embedder, &batch, policy).await {
+        Ok(vectors) => return vectors.into_iter().map(Ok).collect(),
+        Err(error) => error,
+    };
+
+    if batch.len() == 1 {
+        return vec![Err(error.to_string())];
+    }
+
+    let mut results = Vec::with_capacity(batch.len());
+    for text in batch {
+        let result = embed_with_retry(embedder, &vec![text], policy)
+            .await
+            .map(|mut vectors| vectors.remove(0))
+            .map_err(|error| error.to_string());
+        results.push(result);
+    }
+    results
+}
+
+#[async_trait]
+impl NodeLogic for EmbedTextsBatchedNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "embed_texts_batched",
+            "Embed Texts (Batched)",
+            "Embeds many texts in batches with bounded concurrency and retries. Vectors keep the input order, failed texts get an empty vector and an entry in Errors",
+            "AI/Embedding",
+        );
+
+        node.set_long_running(true);
+        node.add_icon("/flow/icons/bot-invoke.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+
+        node.add_input_pin("texts", "Texts", "The texts to embed", VariableType::String)
+            .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "model",
+            "Model",
+            "The embedding model",
+            VariableType::Struct,
+        )
+        .set_schema::<CachedEmbeddingModel>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "mode",
+            "Mode",
+            "Embed the texts as documents or as queries",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Document".to_string(), "Query".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Document")));
+
+        node.add_input_pin(
+            "batch_size",
+            "Batch Size",
+            "Texts per provider request",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(32)));
+
+        node.add_input_pin(
+            "concurrency",
+            "Concurrency",
+            "Maximum number of batches in flight",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(4)));
+
+        node.add_input_pin(
+            "max_retries",
+            "Max Retries",
+            "Retries per failed request before giving up",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(3)));
+
+        node.add_input_pin(
+            "backoff_ms",
+            "Backoff (ms)",
+            "Initial retry delay, doubled after every attempt",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(500)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "vectors",
+            "Vectors",
+            "One embedding per input text, empty for failed texts",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "errors",
+            "Errors",
+            "Error message per input text, empty for successful texts",
+            VariableType::String,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "failed",
+            "Failed",
+            "Number of texts that could not be embedded",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let texts: Vec<String> = context.evaluate_pin("texts").await?;
+        let model: CachedEmbeddingModel = context.evaluate_pin("model").await?;
+        let mode: String = context.evaluate_pin("mode").await?;
+        let batch_size: i64 = context.evaluate_pin("batch_size").await?;
+        let concurrency: i64 = context.evaluate_pin("concurrency").await?;
+        let max_retries: i64 = context.evaluate_pin("max_retries").await?;
+        let backoff_ms: i64 = context.evaluate_pin("backoff_ms").await?;
+
+        let cached_model = context
+            .get_cache(&model.cache_key)
+            .await
+            .ok_or(anyhow!("Model not found in cache"))?;
+        let cached_model = cached_model
+            .as_any()
+            .downcast_ref::<CachedEmbeddingModelObject>()
+            .ok_or(anyhow!("Failed to Downcast Model"))?;
+        let embedder = match (&cached_model.text_model, &cached_model.image_model) {
+            (Some(model), _) => Embedder::Text(model.clone()),
+            (None, Some(model)) => Embedder::Image(model.clone()),
+            (None, None) => bail!("Cached model cannot embed text"),
+        };
+
+        let policy = RetryPolicy {
+            query: mode == "Query",
+            max_retries: max_retries.max(0) as u32,
+            backoff_ms: backoff_ms.max(0) as u64,
+        };
+
+        let batches = texts
+            .chunks(batch_size.max(1) as usize)
+            .map(|batch| batch.to_vec())
+            .collect::<Vec<_>>();
+        let results = futures::stream::iter(
+            batches
+                .into_iter()
+                .map(|batch| embed_batch(&embedder, batch, &policy)),
+        )
+        .buffered(concurrency.max(1) as usize)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut vectors = Vec::with_capacity(texts.len());
+        let mut errors = Vec::with_capacity(texts.len());
+        let mut failed = 0;
+        for result in results.into_iter().flatten() {
+            match result {
+                Ok(vector) => {
+                    vectors.push(vector);
+                    errors.push(String::new());
+                }
+                Err(error) => {
+                    vectors.push(vec![]);
+                    errors.push(error);
+                    failed += 1;
+                }
+            }
+        }
+
+        if failed > 0 {
+            context.log_message(
+                &format!("Failed to embed {} of {} texts", failed, texts.len()),
+                LogLevel::Warn,
+            );
+        }
+
+        context.set_pin_value("vectors", json!(vectors)).await?;
+        context.set_pin_value("errors", json!(errors)).await?;
+        context.set_pin_value("failed", json!(failed)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        assert_eq!(backoff_delay(500, 0), 500);
+        assert_eq!(backoff_delay(500, 1), 1000);
+        assert_eq!(backoff_delay(500, 3), 4000);
+        assert_eq!(backoff_delay(500, 10), MAX_BACKOFF_MS);
+        assert_eq!(backoff_delay(u64::MAX, 40), MAX_BACKOFF_MS);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod branch;
+pub mod extract_structured;
 pub mod find_llm;
 pub mod history;
 pub mod invoke;
@@ -43,8 +44,11 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(history::set_response_format::SetHistoryResponseFormatNode::default()),
         Arc::new(history::set_n::SetHistoryNNode::default()),
         Arc::new(history::from_string::HistoryFromStringNode::default()),
+        Arc::new(history::render::RenderHistoryNode::default()),
+        Arc::new(history::extract_tool_calls::ExtractToolCallsNode::default()),
         Arc::new(branch::LLMBranchNode::default()),
         Arc::new(with_structured_output::LLMWithStructuredOutput::default()),
+        Arc::new(extract_structured::ExtractStructuredNode::default()),
         Arc::new(invoke_with_tools::InvokeLLMWithToolsNode::default()),
         Arc::new(make_schema::LLMMakeSchema::default()),
     ];
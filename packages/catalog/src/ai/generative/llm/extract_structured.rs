@@ -0,0 +1,215 @@
+/// # Extract Structured Node
+/// Turns raw assistant text into a struct conforming to a JSON schema.
+/// The text is repaired and validated first, if that fails the model is asked to fix its
+/// output, with the parse or validation error fed back, up to `max_retries` times.
+/// Unlike With Structured Output, a failed extraction doesn't fail the node but sets `valid` to false.
+use crate::utils::json::parse_with_schema::validate_json_data;
+use flow_like::{
+    bit::Bit,
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+    utils::json::parse_malformed_json,
+};
+use flow_like_model_provider::history::{History, HistoryMessage, Role};
+use flow_like_types::{Value, async_trait, json::json};
+
+const REPAIR_PROMPT_TEMPLATE: &str = r#"
+# Instructions
+You fix json data so it conforms to the json schema below.
+
+Reply with the corrected json data only, without explanations or markdown.
+
+# Schema
+SCHEMA_STR
+"#;
+
+/// Repairs `text` into JSON and validates it against `schema`,
+/// a JSON schema or OpenAI function definition.
+fn parse_against_schema(schema: &str, text: &str) -> flow_like_types::Result<Value> {
+    let value = parse_malformed_json(text)?;
+    validate_json_data(schema, &value.to_string())
+}
+
+#[derive(Default)]
+pub struct ExtractStructuredNode {}
+
+impl ExtractStructuredNode {
+    pub fn new() -> Self {
+        ExtractStructuredNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ExtractStructuredNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "llm_extract_structured",
+            "Extract Structured",
+            "Parses assistant text into a struct matching a JSON schema, asking the model to fix invalid output",
+            "AI/Generative",
+        );
+        node.add_icon("/flow/icons/bot-invoke.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        node.add_input_pin(
+            "model",
+            "Model",
+            "Model used for repairs",
+            VariableType::Struct,
+        )
+        .set_schema::<Bit>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "text",
+            "Text",
+            "Raw assistant text containing the JSON data",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "schema",
+            "Schema",
+            "JSON Schema or OpenAI function definition the data has to conform to",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "max_retries",
+            "Max Retries",
+            "How often the model may try to fix invalid output",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(2)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Execution Output",
+            "Execution Output",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "value",
+            "Value",
+            "Parsed data, null if invalid",
+            VariableType::Struct,
+        );
+
+        node.add_output_pin(
+            "valid",
+            "Valid",
+            "True if the data conforms to the schema",
+            VariableType::Boolean,
+        );
+
+        node.add_output_pin(
+            "error",
+            "Error",
+            "Last parse or validation error, empty if valid",
+            VariableType::String,
+        );
+
+        node.set_long_running(true);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let model = context.evaluate_pin::<Bit>("model").await?;
+        let mut text: String = context.evaluate_pin("text").await?;
+        let schema: String = context.evaluate_pin("schema").await?;
+        let max_retries: i64 = context.evaluate_pin("max_retries").await?;
+
+        let mut model_name = model.id.clone();
+        if let Some(meta) = model.meta.get("en") {
+            model_name = meta.name.clone();
+        }
+
+        let mut attempt = 0;
+        let result = loop {
+            let error = match parse_against_schema(&schema, &text) {
+                Ok(value) => break Ok(value),
+                Err(error) => error.to_string(),
+            };
+
+            if attempt >= max_retries {
+                break Err(error);
+            }
+            attempt += 1;
+
+            context.log_message(
+                &format!("Invalid structured output (attempt {attempt}): {error}"),
+                LogLevel::Debug,
+            );
+
+            let mut history = History::new(model_name.clone(), vec![]);
+            history.set_system_prompt(REPAIR_PROMPT_TEMPLATE.replace("SCHEMA_STR", &schema));
+            history.push_message(HistoryMessage::from_string(
+                Role::User,
+                &format!("# Data\n{text}\n\n# Error\n{error}"),
+            ));
+
+            let response = {
+                let model_factory = context.app_state.lock().await.model_factory.clone();
+                let model = model_factory
+                    .lock()
+                    .await
+                    .build(&model, context.app_state.clone())
+                    .await?;
+                model.invoke(&history, None).await?
+            };
+
+            text = response
+                .last_message()
+                .and_then(|message| message.content.clone())
+                .unwrap_or_default();
+        };
+
+        let (value, valid, error) = match result {
+            Ok(value) => (value, true, String::new()),
+            Err(error) => {
+                context.log_message(
+                    &format!("Failed to extract structured output: {error}"),
+                    LogLevel::Warn,
+                );
+                (Value::Null, false, error)
+            }
+        };
+
+        context.set_pin_value("value", value).await?;
+        context.set_pin_value("valid", json!(valid)).await?;
+        context.set_pin_value("error", json!(error)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str =
+        r#"{"type":"object","properties":{"name":{"type":"string"}},"required":["name"]}"#;
+
+    #[test]
+    fn repairs_wrapped_json() {
+        let text = "Sure, here you go:\n```json\n{\"name\": \"Ada\",}\n```";
+        let value = parse_against_schema(SCHEMA, text).unwrap();
+        assert_eq!(value, json!({"name": "Ada"}));
+    }
+
+    #[test]
+    fn rejects_schema_violations() {
+        assert!(parse_against_schema(SCHEMA, r#"{"age": 3}"#).is_err());
+        assert!(parse_against_schema(SCHEMA, "no json at all").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 pub mod clear;
 pub mod extract_attachments;
+pub mod extract_tool_calls;
 pub mod from_messages;
 pub mod from_string;
 pub mod get_system;
@@ -7,6 +8,7 @@ pub mod make;
 pub mod message;
 pub mod pop_message;
 pub mod push_message;
+pub mod render;
 pub mod set_frequency_penalty;
 pub mod set_max_completion_tokens;
 pub mod set_n;
@@ -0,0 +1,136 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_model_provider::history::{History, HistoryMessage, Role};
+use flow_like_types::{Value, async_trait, json::json};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ExtractedToolCall {
+    pub id: String,
+    pub name: String,
+    /// Parsed JSON arguments, the raw string if they are not valid JSON
+    pub arguments: Value,
+}
+
+#[derive(Default)]
+pub struct ExtractToolCallsNode {}
+
+impl ExtractToolCallsNode {
+    pub fn new() -> Self {
+        ExtractToolCallsNode {}
+    }
+}
+
+fn extract_tool_calls(messages: &[HistoryMessage], last_only: bool) -> Vec<ExtractedToolCall> {
+    let assistant_messages = messages
+        .iter()
+        .filter(|message| message.role == Role::Assistant && message.tool_calls.is_some());
+    let assistant_messages: Vec<&HistoryMessage> = if last_only {
+        assistant_messages.last().into_iter().collect()
+    } else {
+        assistant_messages.collect()
+    };
+
+    assistant_messages
+        .into_iter()
+        .flat_map(|message| message.tool_calls.iter().flatten())
+        .map(|call| ExtractedToolCall {
+            id: call.id.clone(),
+            name: call.function.name.clone(),
+            arguments: flow_like_types::json::from_str(&call.function.arguments)
+                .unwrap_or_else(|_| Value::String(call.function.arguments.clone())),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for ExtractToolCallsNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "ai_generative_history_extract_tool_calls",
+            "Extract Tool Calls",
+            "Collects the tool calls of assistant messages in a ChatHistory, e.g. to dispatch them to handlers",
+            "AI/Generative/History",
+        );
+        node.add_icon("/flow/icons/history.svg");
+
+        node.add_input_pin("history", "History", "ChatHistory", VariableType::Struct)
+            .set_schema::<History>()
+            .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "last_only",
+            "Last Only",
+            "Only the tool calls of the last assistant message that made any",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "tool_calls",
+            "Tool Calls",
+            "Tool calls in history order, empty if there are none",
+            VariableType::Struct,
+        )
+        .set_schema::<ExtractedToolCall>()
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let history: History = context.evaluate_pin("history").await?;
+        let last_only: bool = context.evaluate_pin("last_only").await?;
+
+        let tool_calls = extract_tool_calls(&history.messages, last_only);
+
+        context
+            .set_pin_value("tool_calls", json!(tool_calls))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flow_like_types::json::from_value;
+
+    fn messages() -> Vec<HistoryMessage> {
+        from_value(json!([
+            {"role": "user", "content": "Weather in Berlin and Paris?"},
+            {"role": "assistant", "content": "", "tool_calls": [
+                {"id": "a", "type": "function", "function": {"name": "weather", "arguments": "{\"city\":\"Berlin\"}"}}
+            ]},
+            {"role": "tool", "content": "12C", "tool_call_id": "a"},
+            {"role": "assistant", "content": "", "tool_calls": [
+                {"id": "b", "type": "function", "function": {"name": "weather", "arguments": {"city": "Paris"}}},
+                {"id": "c", "type": "function", "function": {"name": "noop", "arguments": "not json"}}
+            ]}
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn extracts_calls_with_parsed_arguments() {
+        let calls = extract_tool_calls(&messages(), false);
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0].arguments, json!({"city": "Berlin"}));
+        assert_eq!(calls[1].arguments, json!({"city": "Paris"}));
+        assert_eq!(calls[2].arguments, json!("not json"));
+
+        let last = extract_tool_calls(&messages(), true);
+        assert_eq!(last.len(), 2);
+        assert_eq!(last[0].id, "b");
+
+        assert!(extract_tool_calls(&messages()[..1], false).is_empty());
+    }
+}
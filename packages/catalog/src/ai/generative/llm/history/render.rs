@@ -0,0 +1,174 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_model_provider::history::{History, HistoryMessage, Role};
+use flow_like_types::{Result, async_trait, bail, json::json};
+
+#[derive(Default)]
+pub struct RenderHistoryNode {}
+
+impl RenderHistoryNode {
+    pub fn new() -> Self {
+        RenderHistoryNode {}
+    }
+}
+
+fn role_name(role: &Role) -> &'static str {
+    match role {
+        Role::System => "system",
+        Role::User => "user",
+        Role::Assistant => "assistant",
+        Role::Function | Role::Tool => "tool",
+    }
+}
+
+fn plain_prefix(role: &Role) -> &'static str {
+    match role {
+        Role::System => "System",
+        Role::User => "User",
+        Role::Assistant => "Assistant",
+        Role::Function | Role::Tool => "Tool",
+    }
+}
+
+/// Renders messages into one prompt. Only the text parts of multi-part messages are kept,
+/// joined by newlines. With `generation_prompt` the prompt ends with an open assistant turn.
+fn render(messages: &[HistoryMessage], template: &str, generation_prompt: bool) -> Result<String> {
+    let mut prompt = String::new();
+    match template {
+        "ChatML" => {
+            for message in messages {
+                prompt.push_str(&format!(
+                    "<|im_start|>{}\n{}<|im_end|>\n",
+                    role_name(&message.role),
+                    message.as_str()
+                ));
+            }
+            if generation_prompt {
+                prompt.push_str("<|im_start|>assistant\n");
+            }
+        }
+        "Llama" => {
+            prompt.push_str("<|begin_of_text|>");
+            for message in messages {
+                prompt.push_str(&format!(
+                    "<|start_header_id|>{}<|end_header_id|>\n\n{}<|eot_id|>",
+                    role_name(&message.role),
+                    message.as_str()
+                ));
+            }
+            if generation_prompt {
+                prompt.push_str("<|start_header_id|>assistant<|end_header_id|>\n\n");
+            }
+        }
+        "Plain" => {
+            for message in messages {
+                prompt.push_str(&format!(
+                    "{}: {}\n\n",
+                    plain_prefix(&message.role),
+                    message.as_str()
+                ));
+            }
+            if generation_prompt {
+                prompt.push_str("Assistant:");
+            } else {
+                prompt.truncate(prompt.trim_end().len());
+            }
+        }
+        other => bail!("Unknown chat template '{}'", other),
+    }
+    Ok(prompt)
+}
+
+#[async_trait]
+impl NodeLogic for RenderHistoryNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "ai_generative_history_render",
+            "Render History",
+            "Renders a ChatHistory into a single prompt string for text completion backends",
+            "AI/Generative/History",
+        );
+        node.add_icon("/flow/icons/history.svg");
+
+        node.add_input_pin("history", "History", "ChatHistory", VariableType::Struct)
+            .set_schema::<History>()
+            .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "template",
+            "Template",
+            "Chat template, Plain prefixes every message with its role",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "ChatML".to_string(),
+                    "Llama".to_string(),
+                    "Plain".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("ChatML")));
+
+        node.add_input_pin(
+            "generation_prompt",
+            "Generation Prompt",
+            "End with an open assistant turn for the model to complete",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(true)));
+
+        node.add_output_pin("prompt", "Prompt", "Rendered prompt", VariableType::String);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        let history: History = context.evaluate_pin("history").await?;
+        let template: String = context.evaluate_pin("template").await?;
+        let generation_prompt: bool = context.evaluate_pin("generation_prompt").await?;
+
+        let prompt = render(&history.messages, &template, generation_prompt)?;
+
+        context.set_pin_value("prompt", json!(prompt)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages() -> Vec<HistoryMessage> {
+        vec![
+            HistoryMessage::from_string(Role::System, "Be brief."),
+            HistoryMessage::from_string(Role::User, "Hi"),
+        ]
+    }
+
+    #[test]
+    fn renders_presets() {
+        assert_eq!(
+            render(&messages(), "ChatML", true).unwrap(),
+            "<|im_start|>system\nBe brief.<|im_end|>\n<|im_start|>user\nHi<|im_end|>\n<|im_start|>assistant\n"
+        );
+        assert_eq!(
+            render(&messages(), "Plain", false).unwrap(),
+            "System: Be brief.\n\nUser: Hi"
+        );
+        assert!(
+            render(&messages(), "Llama", true)
+                .unwrap()
+                .ends_with("<|start_header_id|>assistant<|end_header_id|>\n\n")
+        );
+        assert!(render(&messages(), "Mistral", true).is_err());
+    }
+}
@@ -38,13 +38,18 @@ pub const MAX_ML_PREDICTION_RECORDS: usize = 20000;
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
     let nodes: Vec<Arc<dyn NodeLogic>> = vec![
         Arc::new(clustering::kmeans::FitKMeansNode::default()),
+        Arc::new(clustering::agglomerative::FitAgglomerativeNode::default()),
+        Arc::new(clustering::silhouette::SilhouetteScoreNode::default()),
         Arc::new(classification::svm::FitSVMMultiClassNode::default()),
+        Arc::new(classification::knn::KnnClassifyNode::default()),
         Arc::new(regression::linear::FitLinearRegressionNode::default()),
         Arc::new(prediction::MLPredictNode::default()),
         Arc::new(save::SaveMLModelNode::default()),
         Arc::new(load::LoadMLModelNode::default()),
         Arc::new(load::LoadMLModelNode::default()),
         Arc::new(dataset::split::SplitDatasetNode::default()),
+        Arc::new(dataset::scale::ScaleFeaturesNode::default()),
+        Arc::new(dataset::scale::ApplyScalingNode::default()),
     ];
     nodes
 }
@@ -1 +1,2 @@
+pub mod knn;
 pub mod svm;
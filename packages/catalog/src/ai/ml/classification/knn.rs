@@ -0,0 +1,250 @@
+//! Node for **K-Nearest-Neighbors Classification** over a Vector Database
+//!
+//! Looks up the k nearest labeled rows with a vector search and returns the class with the
+//! most votes, optionally weighting every vote by the inverse distance. Needs no training,
+//! the database itself is the model.
+
+use crate::data::db::vector::NodeDBConnection;
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::databases::vector::VectorStore;
+use flow_like_types::{Result, Value, anyhow, async_trait, json::json};
+use std::collections::HashMap;
+
+/// Column LanceDB adds to vector search results.
+const DISTANCE_COLUMN: &str = "_distance";
+
+/// Keeps inverse distance weights finite for exact matches.
+const DISTANCE_EPS: f64 = 1e-9;
+
+#[derive(Default)]
+pub struct KnnClassifyNode {}
+
+impl KnnClassifyNode {
+    pub fn new() -> Self {
+        KnnClassifyNode {}
+    }
+}
+
+fn label_to_string(label: &Value) -> String {
+    match label {
+        Value::String(label) => label.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Picks the class with the highest vote weight from `(label, distance)` neighbors.
+/// Ties go to the class with the nearest neighbor. Returns the class and its share of votes.
+fn vote(neighbors: &[(String, f64)], weighted: bool) -> Option<(String, f64)> {
+    let mut votes: HashMap<&str, (f64, f64)> = HashMap::new();
+    let mut total = 0.0;
+    for (label, distance) in neighbors {
+        let weight = if weighted {
+            1.0 / (distance.max(0.0) + DISTANCE_EPS)
+        } else {
+            1.0
+        };
+        total += weight;
+        let entry = votes.entry(label.as_str()).or_insert((0.0, f64::INFINITY));
+        entry.0 += weight;
+        entry.1 = entry.1.min(*distance);
+    }
+
+    votes
+        .into_iter()
+        .max_by(|(_, (weight_a, nearest_a)), (_, (weight_b, nearest_b))| {
+            weight_a
+                .total_cmp(weight_b)
+                .then_with(|| nearest_b.total_cmp(nearest_a))
+        })
+        .map(|(label, (weight, _))| (label.to_string(), weight / total))
+}
+
+#[async_trait]
+impl NodeLogic for KnnClassifyNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "ai_ml_knn_classify",
+            "Classify (KNN)",
+            "Classifies a vector by the labels of its k nearest rows in the database",
+            "AI/ML/Classification",
+        );
+        node.add_icon("/flow/icons/chart-network.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Start Classification",
+            VariableType::Execution,
+        );
+
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection with labeled vectors",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "vector",
+            "Vector",
+            "Vector to classify",
+            VariableType::Float,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "label_column",
+            "Label Col",
+            "Column Containing the Class Labels",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("label")));
+
+        node.add_input_pin("k", "K", "Number of Neighbors", VariableType::Integer)
+            .set_options(PinOptions::new().set_range((1., 100.)).build())
+            .set_default_value(Some(json!(5)));
+
+        node.add_input_pin(
+            "filter",
+            "SQL Filter",
+            "Optional SQL Filter",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_input_pin(
+            "weighted",
+            "Distance Weighted",
+            "Weight every neighbor's vote by its inverse distance",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Classifying",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin("label", "Label", "Predicted Class", VariableType::String);
+
+        node.add_output_pin(
+            "confidence",
+            "Confidence",
+            "Share of the votes for the predicted class",
+            VariableType::Float,
+        );
+
+        node.add_output_pin(
+            "neighbors",
+            "Neighbors",
+            "Nearest rows with their label and distance",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let vector: Vec<f64> = context.evaluate_pin("vector").await?;
+        let label_column: String = context.evaluate_pin("label_column").await?;
+        let k: i64 = context.evaluate_pin("k").await?;
+        let filter: String = context.evaluate_pin("filter").await?;
+        let weighted: bool = context.evaluate_pin("weighted").await?;
+
+        if k < 1 {
+            return Err(anyhow!("K must be at least 1, got {}", k));
+        }
+
+        let rows = {
+            let database = database.load(context).await?.db.clone();
+            let cancellation = context.cancellation_token().await;
+            let database = database.read().await.with_cancellation(cancellation);
+            let filter = (!filter.is_empty()).then_some(filter.as_str());
+            database
+                .vector_search(
+                    vector,
+                    filter,
+                    Some(vec![label_column.clone()]),
+                    k as usize,
+                    0,
+                    false,
+                    None,
+                )
+                .await?
+        };
+
+        let mut neighbors = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let label = row
+                .get(&label_column)
+                .filter(|label| !label.is_null())
+                .ok_or_else(|| anyhow!("Neighbor has no label in column `{}`", label_column))?;
+            let distance = row
+                .get(DISTANCE_COLUMN)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| anyhow!("Vector search returned no distances"))?;
+            neighbors.push((label_to_string(label), distance));
+        }
+
+        let (label, confidence) =
+            vote(&neighbors, weighted).ok_or_else(|| anyhow!("No neighbors found"))?;
+
+        context.set_pin_value("label", json!(label)).await?;
+        context
+            .set_pin_value("confidence", json!(confidence))
+            .await?;
+        context.set_pin_value("neighbors", json!(rows)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neighbors(entries: &[(&str, f64)]) -> Vec<(String, f64)> {
+        entries
+            .iter()
+            .map(|(label, distance)| (label.to_string(), *distance))
+            .collect()
+    }
+
+    #[test]
+    fn majority_wins_and_ties_go_to_nearest() {
+        let majority = neighbors(&[("a", 0.3), ("b", 0.1), ("a", 0.4)]);
+        assert_eq!(vote(&majority, false).unwrap().0, "a");
+
+        let tie = neighbors(&[("a", 0.3), ("b", 0.1), ("a", 0.4), ("b", 0.5)]);
+        assert_eq!(vote(&tie, false).unwrap(), ("b".to_string(), 0.5));
+    }
+
+    #[test]
+    fn weighting_favors_close_neighbors() {
+        let entries = neighbors(&[("a", 0.5), ("a", 0.5), ("b", 0.01)]);
+        assert_eq!(vote(&entries, false).unwrap().0, "a");
+        assert_eq!(vote(&entries, true).unwrap().0, "b");
+    }
+
+    #[test]
+    fn labels_keep_their_text() {
+        assert_eq!(label_to_string(&json!("spam")), "spam");
+        assert_eq!(label_to_string(&json!(3)), "3");
+    }
+}
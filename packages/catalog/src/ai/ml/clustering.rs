@@ -1 +1,3 @@
+pub mod agglomerative;
 pub mod kmeans;
+pub mod silhouette;
@@ -0,0 +1,337 @@
+//! Node for **Hierarchical / Agglomerative Clustering**
+//!
+//! Starts with every point in its own cluster and repeatedly merges the two closest clusters,
+//! either until the requested number of clusters is left or the closest clusters are further
+//! apart than a distance threshold. Works on raw vectors (euclidean distance) or on a
+//! precomputed distance or similarity matrix, e.g. from the Similarity Matrix node.
+
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Result, anyhow, async_trait, json::json};
+
+/// Max number of points, every merge scans the full distance matrix.
+const MAX_AGGLOMERATIVE_POINTS: usize = 2000;
+
+#[derive(Default)]
+pub struct FitAgglomerativeNode {}
+
+impl FitAgglomerativeNode {
+    pub fn new() -> Self {
+        FitAgglomerativeNode {}
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Linkage {
+    Single,
+    Complete,
+    Average,
+}
+
+impl Linkage {
+    fn parse(linkage: &str) -> Result<Self> {
+        match linkage {
+            "Single" => Ok(Linkage::Single),
+            "Complete" => Ok(Linkage::Complete),
+            "Average" => Ok(Linkage::Average),
+            other => Err(anyhow!("Unknown linkage method `{}`", other)),
+        }
+    }
+
+    /// Lance-Williams update: distance of the merged cluster `a + b` to another cluster.
+    fn merge(self, d_a: f64, d_b: f64, size_a: usize, size_b: usize) -> f64 {
+        match self {
+            Linkage::Single => d_a.min(d_b),
+            Linkage::Complete => d_a.max(d_b),
+            Linkage::Average => {
+                (size_a as f64 * d_a + size_b as f64 * d_b) / (size_a + size_b) as f64
+            }
+        }
+    }
+}
+
+fn euclidean_distances(vectors: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+    let dim = vectors.first().map(Vec::len).unwrap_or_default();
+    if let Some(index) = vectors.iter().position(|vector| vector.len() != dim) {
+        return Err(anyhow!(
+            "Vectors must have the same length, vector 0 has {} but vector {} has {}",
+            dim,
+            index,
+            vectors[index].len()
+        ));
+    }
+
+    let n = vectors.len();
+    let mut distances = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let distance = vectors[i]
+                .iter()
+                .zip(&vectors[j])
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f64>()
+                .sqrt();
+            distances[i][j] = distance;
+            distances[j][i] = distance;
+        }
+    }
+    Ok(distances)
+}
+
+fn check_square(matrix: &[Vec<f64>]) -> Result<()> {
+    if let Some(index) = matrix.iter().position(|row| row.len() != matrix.len()) {
+        return Err(anyhow!(
+            "Matrix must be square, row {} has {} entries but there are {} rows",
+            index,
+            matrix[index].len(),
+            matrix.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Clusters points given their pairwise distances. Merging stops once `n_clusters` are left
+/// or, with a `threshold`, once the closest clusters are further apart than it.
+/// Labels are numbered in order of first appearance, so equal inputs give equal labels.
+fn agglomerative(
+    mut distances: Vec<Vec<f64>>,
+    linkage: Linkage,
+    n_clusters: usize,
+    threshold: Option<f64>,
+) -> Vec<usize> {
+    let n = distances.len();
+    let mut active: Vec<bool> = vec![true; n];
+    let mut sizes: Vec<usize> = vec![1; n];
+    let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut clusters = n;
+
+    while clusters > n_clusters.max(1) {
+        let mut closest: Option<(usize, usize, f64)> = None;
+        for i in (0..n).filter(|&i| active[i]) {
+            for j in ((i + 1)..n).filter(|&j| active[j]) {
+                if closest.is_none_or(|(_, _, best)| distances[i][j] < best) {
+                    closest = Some((i, j, distances[i][j]));
+                }
+            }
+        }
+
+        let Some((a, b, distance)) = closest else {
+            break;
+        };
+        if threshold.is_some_and(|threshold| distance > threshold) {
+            break;
+        }
+
+        for k in (0..n).filter(|&k| active[k] && k != a && k != b) {
+            let merged = linkage.merge(distances[a][k], distances[b][k], sizes[a], sizes[b]);
+            distances[a][k] = merged;
+            distances[k][a] = merged;
+        }
+
+        active[b] = false;
+        sizes[a] += sizes[b];
+        let moved = std::mem::take(&mut members[b]);
+        members[a].extend(moved);
+        clusters -= 1;
+    }
+
+    let mut cluster_of = vec![0; n];
+    for (cluster, points) in members.iter().enumerate() {
+        for &point in points {
+            cluster_of[point] = cluster;
+        }
+    }
+
+    let mut relabel: Vec<Option<usize>> = vec![None; n];
+    let mut next = 0;
+    cluster_of
+        .into_iter()
+        .map(|cluster| {
+            *relabel[cluster].get_or_insert_with(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for FitAgglomerativeNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "fit_agglomerative",
+            "Hierarchical Clustering",
+            "Agglomerative clustering of vectors or a precomputed distance/similarity matrix into a fixed number of clusters or by a distance threshold",
+            "AI/ML/Clustering",
+        );
+        node.add_icon("/flow/icons/chart-network.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Start Clustering",
+            VariableType::Execution,
+        );
+
+        node.add_input_pin(
+            "data",
+            "Data",
+            "Array of vectors or N x N matrix, depending on Input",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "input",
+            "Input",
+            "What Data holds, similarities are turned into distances as 1 - similarity",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "Vectors".to_string(),
+                    "Distance Matrix".to_string(),
+                    "Similarity Matrix".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("Vectors")));
+
+        node.add_input_pin(
+            "linkage",
+            "Linkage",
+            "Distance between clusters: closest points (Single), farthest points (Complete) or mean distance (Average)",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "Single".to_string(),
+                    "Complete".to_string(),
+                    "Average".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("Average")));
+
+        node.add_input_pin(
+            "cluster",
+            "Cluster",
+            "Number of Clusters, used as a lower bound if a threshold is set",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(2)));
+
+        node.add_input_pin(
+            "threshold",
+            "Threshold",
+            "Stop merging once clusters are further apart than this, 0 to disable",
+            VariableType::Float,
+        )
+        .set_default_value(Some(json!(0.0)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Clustering",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "labels",
+            "Labels",
+            "Cluster label per point, in input order",
+            VariableType::Integer,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "n_clusters",
+            "Clusters",
+            "Number of clusters found",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        let data: Vec<Vec<f64>> = context.evaluate_pin("data").await?;
+        let input: String = context.evaluate_pin("input").await?;
+        let linkage = Linkage::parse(&context.evaluate_pin::<String>("linkage").await?)?;
+        let n_clusters: i64 = context.evaluate_pin("cluster").await?;
+        let threshold: f64 = context.evaluate_pin("threshold").await?;
+
+        if data.len() > MAX_AGGLOMERATIVE_POINTS {
+            return Err(anyhow!(
+                "Hierarchical clustering supports up to {} points, got {}",
+                MAX_AGGLOMERATIVE_POINTS,
+                data.len()
+            ));
+        }
+
+        let distances = match input.as_str() {
+            "Vectors" => euclidean_distances(&data)?,
+            "Distance Matrix" => {
+                check_square(&data)?;
+                data
+            }
+            "Similarity Matrix" => {
+                check_square(&data)?;
+                data.into_iter()
+                    .map(|row| row.into_iter().map(|s| 1.0 - s).collect())
+                    .collect()
+            }
+            other => return Err(anyhow!("Unknown input `{}`", other)),
+        };
+
+        let threshold = (threshold > 0.0).then_some(threshold);
+        let labels = agglomerative(distances, linkage, n_clusters.max(1) as usize, threshold);
+        let found = labels.iter().max().map(|max| max + 1).unwrap_or_default();
+
+        context.set_pin_value("labels", json!(labels)).await?;
+        context.set_pin_value("n_clusters", json!(found)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.0, 0.0],
+            vec![10.0, 10.0],
+            vec![0.1, 0.0],
+            vec![10.0, 10.2],
+            vec![0.0, 0.2],
+        ]
+    }
+
+    #[test]
+    fn merges_until_cluster_count() {
+        let distances = euclidean_distances(&points()).unwrap();
+        for linkage in [Linkage::Single, Linkage::Complete, Linkage::Average] {
+            let labels = agglomerative(distances.clone(), linkage, 2, None);
+            assert_eq!(labels, vec![0, 1, 0, 1, 0]);
+        }
+    }
+
+    #[test]
+    fn threshold_stops_merging() {
+        let distances = euclidean_distances(&points()).unwrap();
+        let labels = agglomerative(distances, Linkage::Complete, 1, Some(1.0));
+        assert_eq!(labels, vec![0, 1, 0, 1, 0]);
+    }
+}
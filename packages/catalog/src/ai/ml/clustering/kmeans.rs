@@ -121,6 +121,7 @@ impl NodeLogic for FitKMeansNode {
                             Some(vec![records_col.to_string()]),
                             MAX_ML_PREDICTION_RECORDS,
                             0,
+                            None,
                         )
                         .await?
                 }; // drop db
@@ -0,0 +1,188 @@
+//! Node for the **Silhouette Score** of a Clustering
+//!
+//! Measures how well each point fits its own cluster compared to the nearest other cluster,
+//! from -1 (likely in the wrong cluster) over 0 (on a border) to 1 (well separated).
+//! Useful to compare KMeans cluster counts or clustering parameters within a flow.
+
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Result, anyhow, async_trait, json::json};
+use std::collections::BTreeMap;
+
+/// Label marking noise points (e.g. from DBSCAN), excluded from the score.
+const NOISE_LABEL: i64 = -1;
+
+#[derive(Default)]
+pub struct SilhouetteScoreNode {}
+
+impl SilhouetteScoreNode {
+    pub fn new() -> Self {
+        SilhouetteScoreNode {}
+    }
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Returns the mean silhouette coefficient and the per-point coefficients using euclidean
+/// distance. Points in singleton clusters score 0, noise points score 0 and are left out
+/// of the mean.
+fn silhouette(vectors: &[Vec<f64>], labels: &[i64]) -> Result<(f64, Vec<f64>)> {
+    if vectors.len() != labels.len() {
+        return Err(anyhow!(
+            "Got {} vectors but {} labels",
+            vectors.len(),
+            labels.len()
+        ));
+    }
+
+    let mut clusters: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+    for (point, label) in labels.iter().enumerate() {
+        if *label != NOISE_LABEL {
+            clusters.entry(*label).or_default().push(point);
+        }
+    }
+    if clusters.len() < 2 {
+        return Err(anyhow!(
+            "Silhouette score needs at least 2 clusters, got {}",
+            clusters.len()
+        ));
+    }
+
+    let mean_distance = |point: usize, members: &[usize]| {
+        let total: f64 = members
+            .iter()
+            .filter(|&&other| other != point)
+            .map(|&other| distance(&vectors[point], &vectors[other]))
+            .sum();
+        let count = members.iter().filter(|&&other| other != point).count();
+        total / count.max(1) as f64
+    };
+
+    let mut scores = vec![0.0; vectors.len()];
+    let mut sum = 0.0;
+    let mut counted = 0;
+    for (label, members) in &clusters {
+        for &point in members {
+            counted += 1;
+            if members.len() == 1 {
+                continue;
+            }
+
+            let a = mean_distance(point, members);
+            let b = clusters
+                .iter()
+                .filter(|(other, _)| *other != label)
+                .map(|(_, others)| mean_distance(point, others))
+                .fold(f64::INFINITY, f64::min);
+
+            let max = a.max(b);
+            let score = if max > 0.0 { (b - a) / max } else { 0.0 };
+            scores[point] = score;
+            sum += score;
+        }
+    }
+
+    Ok((sum / counted as f64, scores))
+}
+
+#[async_trait]
+impl NodeLogic for SilhouetteScoreNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "silhouette_score",
+            "Silhouette Score",
+            "Evaluates a clustering from -1 (poor) to 1 (well separated). Noise labels (-1) are ignored",
+            "AI/ML/Clustering",
+        );
+        node.add_icon("/flow/icons/chart-network.svg");
+
+        node.add_input_pin(
+            "vectors",
+            "Vectors",
+            "Array of float vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "labels",
+            "Labels",
+            "Cluster label per vector, -1 for noise",
+            VariableType::Integer,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "score",
+            "Score",
+            "Mean silhouette coefficient over all non-noise points",
+            VariableType::Float,
+        );
+
+        node.add_output_pin(
+            "scores",
+            "Scores",
+            "Silhouette coefficient per point, 0 for singletons and noise",
+            VariableType::Float,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        let vectors: Vec<Vec<f64>> = context.evaluate_pin("vectors").await?;
+        let labels: Vec<i64> = context.evaluate_pin("labels").await?;
+
+        let (score, scores) = silhouette(&vectors, &labels)?;
+
+        context.set_pin_value("score", json!(score)).await?;
+        context.set_pin_value("scores", json!(scores)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separated_clusters_score_high() {
+        let vectors = vec![
+            vec![0.0, 0.0],
+            vec![0.0, 1.0],
+            vec![10.0, 0.0],
+            vec![10.0, 1.0],
+        ];
+        let (score, scores) = silhouette(&vectors, &[0, 0, 1, 1]).unwrap();
+        assert!(score > 0.9);
+        assert_eq!(scores.len(), 4);
+    }
+
+    #[test]
+    fn singletons_score_zero_and_noise_is_excluded() {
+        let vectors = vec![vec![0.0], vec![1.0], vec![10.0], vec![100.0]];
+        let (score, scores) = silhouette(&vectors, &[0, 0, 1, -1]).unwrap();
+        assert_eq!(scores[2], 0.0);
+        assert_eq!(scores[3], 0.0);
+        assert!((score - (scores[0] + scores[1]) / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn needs_two_clusters() {
+        assert!(silhouette(&[vec![0.0], vec![1.0]], &[0, 0]).is_err());
+    }
+}
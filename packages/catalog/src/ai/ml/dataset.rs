@@ -1 +1,2 @@
+pub mod scale;
 pub mod split;
@@ -0,0 +1,271 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Result, anyhow, async_trait, json::json};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq)]
+pub enum ScalingMode {
+    /// Maps every feature to [0, 1]
+    MinMax,
+    /// Shifts every feature to mean 0 and standard deviation 1
+    ZScore,
+    /// Scales every vector to unit length, has no fitted parameters
+    L2,
+}
+
+/// Fitted scaling parameters, a feature `x` is transformed to `(x - offset) / scale`.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct FeatureScaler {
+    pub mode: ScalingMode,
+    /// Min (MinMax) or mean (ZScore) per feature
+    pub offset: Vec<f64>,
+    /// Max - min (MinMax) or standard deviation (ZScore) per feature, 1 for constant features
+    pub scale: Vec<f64>,
+}
+
+impl FeatureScaler {
+    pub fn fit(vectors: &[Vec<f64>], mode: ScalingMode) -> Result<Self> {
+        let dim = check_dimensions(vectors, None)?;
+        let n = vectors.len() as f64;
+        let (offset, scale) = match mode {
+            ScalingMode::L2 => (vec![], vec![]),
+            ScalingMode::MinMax => (0..dim)
+                .map(|feature| {
+                    let column = vectors.iter().map(|vector| vector[feature]);
+                    let min = column.clone().fold(f64::INFINITY, f64::min);
+                    let max = column.fold(f64::NEG_INFINITY, f64::max);
+                    (min, non_zero(max - min))
+                })
+                .unzip(),
+            ScalingMode::ZScore => (0..dim)
+                .map(|feature| {
+                    let mean = vectors.iter().map(|vector| vector[feature]).sum::<f64>() / n;
+                    let variance = vectors
+                        .iter()
+                        .map(|vector| (vector[feature] - mean).powi(2))
+                        .sum::<f64>()
+                        / n;
+                    (mean, non_zero(variance.sqrt()))
+                })
+                .unzip(),
+        };
+
+        Ok(FeatureScaler {
+            mode,
+            offset,
+            scale,
+        })
+    }
+
+    pub fn transform(&self, vectors: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        if self.mode == ScalingMode::L2 {
+            check_dimensions(vectors, None)?;
+            return Ok(vectors
+                .iter()
+                .map(|vector| {
+                    let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+                    let norm = non_zero(norm);
+                    vector.iter().map(|x| x / norm).collect()
+                })
+                .collect());
+        }
+
+        check_dimensions(vectors, Some(self.offset.len()))?;
+        Ok(vectors
+            .iter()
+            .map(|vector| {
+                vector
+                    .iter()
+                    .zip(self.offset.iter().zip(&self.scale))
+                    .map(|(x, (offset, scale))| (x - offset) / scale)
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+/// Zero spreads and norms are replaced by 1, so constant features map to 0.
+fn non_zero(value: f64) -> f64 {
+    if value == 0.0 { 1.0 } else { value }
+}
+
+fn check_dimensions(vectors: &[Vec<f64>], expected: Option<usize>) -> Result<usize> {
+    let dim = expected
+        .or_else(|| vectors.first().map(Vec::len))
+        .ok_or_else(|| anyhow!("Cannot scale an empty set of vectors"))?;
+    if let Some(index) = vectors.iter().position(|vector| vector.len() != dim) {
+        return Err(anyhow!(
+            "Vectors must have {} features, vector {} has {}",
+            dim,
+            index,
+            vectors[index].len()
+        ));
+    }
+    Ok(dim)
+}
+
+#[derive(Default)]
+pub struct ScaleFeaturesNode {}
+
+impl ScaleFeaturesNode {
+    pub fn new() -> Self {
+        ScaleFeaturesNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ScaleFeaturesNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "ai_ml_dataset_scale",
+            "Scale Features",
+            "Fits a feature scaling (min-max, z-score or L2) to vectors and returns the scaled vectors, e.g. before clustering",
+            "AI/ML/Dataset",
+        );
+        node.add_icon("/flow/icons/chart-network.svg");
+
+        node.add_input_pin(
+            "vectors",
+            "Vectors",
+            "Array of float vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin("mode", "Mode", "Scaling to apply", VariableType::String)
+            .set_options(
+                PinOptions::new()
+                    .set_valid_values(vec![
+                        "MinMax".to_string(),
+                        "ZScore".to_string(),
+                        "L2".to_string(),
+                    ])
+                    .build(),
+            )
+            .set_default_value(Some(json!("ZScore")));
+
+        node.add_output_pin("scaled", "Scaled", "Scaled vectors", VariableType::Generic)
+            .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "scaler",
+            "Scaler",
+            "Fitted parameters to scale new data the same way",
+            VariableType::Struct,
+        )
+        .set_schema::<FeatureScaler>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        let vectors: Vec<Vec<f64>> = context.evaluate_pin("vectors").await?;
+        let mode: ScalingMode = context.evaluate_pin("mode").await?;
+
+        let scaler = FeatureScaler::fit(&vectors, mode)?;
+        let scaled = scaler.transform(&vectors)?;
+
+        context.set_pin_value("scaled", json!(scaled)).await?;
+        context.set_pin_value("scaler", json!(scaler)).await?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct ApplyScalingNode {}
+
+impl ApplyScalingNode {
+    pub fn new() -> Self {
+        ApplyScalingNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ApplyScalingNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "ai_ml_dataset_apply_scaling",
+            "Apply Scaling",
+            "Scales vectors with parameters fitted by Scale Features, e.g. new data before prediction",
+            "AI/ML/Dataset",
+        );
+        node.add_icon("/flow/icons/chart-network.svg");
+
+        node.add_input_pin(
+            "vectors",
+            "Vectors",
+            "Array of float vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "scaler",
+            "Scaler",
+            "Parameters fitted by Scale Features",
+            VariableType::Struct,
+        )
+        .set_schema::<FeatureScaler>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_output_pin("scaled", "Scaled", "Scaled vectors", VariableType::Generic)
+            .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> Result<()> {
+        let vectors: Vec<Vec<f64>> = context.evaluate_pin("vectors").await?;
+        let scaler: FeatureScaler = context.evaluate_pin("scaler").await?;
+
+        let scaled = scaler.transform(&vectors)?;
+
+        context.set_pin_value("scaled", json!(scaled)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_features_map_to_zero() {
+        let vectors = vec![vec![1.0, 5.0], vec![3.0, 5.0]];
+
+        let scaler = FeatureScaler::fit(&vectors, ScalingMode::MinMax).unwrap();
+        assert_eq!(
+            scaler.transform(&vectors).unwrap(),
+            vec![vec![0.0, 0.0], vec![1.0, 0.0]]
+        );
+
+        let scaler = FeatureScaler::fit(&vectors, ScalingMode::ZScore).unwrap();
+        assert_eq!(
+            scaler.transform(&vectors).unwrap(),
+            vec![vec![-1.0, 0.0], vec![1.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn l2_scales_to_unit_length() {
+        let scaler = FeatureScaler::fit(&[vec![3.0, 4.0]], ScalingMode::L2).unwrap();
+        assert_eq!(
+            scaler.transform(&[vec![3.0, 4.0], vec![0.0, 0.0]]).unwrap(),
+            vec![vec![0.6, 0.8], vec![0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn rejects_other_feature_counts() {
+        let scaler = FeatureScaler::fit(&[vec![1.0, 2.0]], ScalingMode::MinMax).unwrap();
+        assert!(scaler.transform(&[vec![1.0]]).is_err());
+    }
+}
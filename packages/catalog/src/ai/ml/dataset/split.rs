@@ -11,7 +11,7 @@ use flow_like::{
 use flow_like_storage::arrow_utils::record_batch_to_value;
 use flow_like_storage::databases::vector::VectorStore;
 use flow_like_storage::lancedb::query::ExecutableQuery;
-use flow_like_types::rand::{self, Rng};
+use flow_like_types::rand::Rng;
 use flow_like_types::{Result, async_trait, json::json};
 use futures::TryStreamExt;
 
@@ -108,23 +108,20 @@ impl NodeLogic for SplitDatasetNode {
         let query = source_table.query();
         let mut item_stream = query.execute().await?;
 
+        let mut rng = context.rng();
         while let Ok(Some(items)) = item_stream.try_next().await {
             let items = record_batch_to_value(&items)?;
 
             let mut train_items = Vec::with_capacity(items.len());
             let mut test_items = Vec::with_capacity(items.len());
 
-            {
-                let mut rng = rand::rng();
+            for item in items {
+                let random_bool = rng.random_bool(probability);
 
-                for item in items {
-                    let random_bool = rng.random_bool(probability);
-
-                    if random_bool {
-                        train_items.push(item);
-                    } else {
-                        test_items.push(item);
-                    }
+                if random_bool {
+                    train_items.push(item);
+                } else {
+                    test_items.push(item);
                 }
             }
 
@@ -115,6 +115,7 @@ impl NodeLogic for MLPredictNode {
                             Some(vec![records_col.to_string()]),
                             MAX_ML_PREDICTION_RECORDS,
                             0,
+                            None,
                         )
                         .await?;
                     (records, existing_cols)
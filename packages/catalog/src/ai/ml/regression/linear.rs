@@ -118,6 +118,7 @@ impl NodeLogic for FitLinearRegressionNode {
                             Some(vec![records_col.to_string(), targets_col.to_string()]),
                             MAX_ML_PREDICTION_RECORDS,
                             0,
+                            None,
                         )
                         .await?
                 }; // drop db
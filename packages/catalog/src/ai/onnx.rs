@@ -1,5 +1,6 @@
 /// # ONNX Nodes
 /// Loading and Inference for ONNX-based Models
+use crate::data::path::FlowPath;
 use flow_like::flow::{execution::context::ExecutionContext, node::NodeLogic};
 use flow_like_model_provider::ml::ort::session::Session;
 use flow_like_types::{Cacheable, Result, create_id, sync::Mutex};
@@ -89,6 +90,22 @@ impl NodeOnnxSession {
     }
 }
 
+/// Load an optional label map (one class name per line) from a FlowPath pin.
+/// Returns an empty list if the pin is not set.
+pub async fn load_labels(ctx: &mut ExecutionContext, pin: &str) -> Result<Vec<String>> {
+    let Ok(path) = ctx.evaluate_pin::<FlowPath>(pin).await else {
+        return Ok(vec![]);
+    };
+    let bytes = path.get(ctx, false).await?;
+    let labels = String::from_utf8(bytes)
+        .map_err(|e| flow_like_types::anyhow!("Failed to parse labels file: {}", e))?;
+    Ok(labels
+        .lines()
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty())
+        .collect())
+}
+
 /// Add ONNX-related Nodes to Catalog Lib
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
     let nodes: Vec<Arc<dyn NodeLogic>> = vec![
@@ -1,5 +1,6 @@
 use crate::{
-    ai::onnx::{NodeOnnxSession, Provider},
+    ai::onnx::{NodeOnnxSession, Provider, load_labels},
+    data::path::FlowPath,
     image::NodeImage,
 };
 use flow_like::{
@@ -27,6 +28,7 @@ use std::borrow::Cow;
 #[derive(Default, Serialize, Deserialize, JsonSchema, Clone, Debug)]
 pub struct ClassPrediction {
     pub class_idx: u32,
+    pub class_name: Option<String>,
     pub score: f32,
 }
 
@@ -101,6 +103,7 @@ impl Classification for TimmLike {
             let score = score.first().copied().unwrap_or(0.);
             predictions.push(ClassPrediction {
                 class_idx: class_idx as u32,
+                class_name: None,
                 score,
             });
         }
@@ -284,6 +287,23 @@ impl NodeLogic for ImageClassificationNode {
         )
         .set_default_value(Some(json!(true)));
 
+        node.add_input_pin(
+            "top_k",
+            "Top K",
+            "Number of best Predictions to return, 0 returns all",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(5)));
+
+        node.add_input_pin(
+            "labels",
+            "Labels",
+            "Optional labels.txt with one class name per line",
+            VariableType::Struct,
+        )
+        .set_schema::<FlowPath>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
         // outputs
         node.add_output_pin(
             "exec_out",
@@ -313,11 +333,13 @@ impl NodeLogic for ImageClassificationNode {
         let std_vec: Vec<f32> = context.evaluate_pin("std").await?;
         let crop_pct: f32 = context.evaluate_pin("crop_pct").await?;
         let apply_softmax: bool = context.evaluate_pin("softmax").await?;
+        let top_k: i64 = context.evaluate_pin("top_k").await?;
+        let labels = load_labels(context, "labels").await?;
         let mean_rgb = <&[f32; 3]>::try_from(mean_vec.as_slice())?;
         let std_rgb = <&[f32; 3]>::try_from(std_vec.as_slice())?;
 
         // run inference
-        let predictions = {
+        let mut predictions = {
             let img = node_img.get_image(context).await?;
             let img_guard = img.lock().await;
             let session = node_session.get_session(context).await?;
@@ -340,6 +362,14 @@ impl NodeLogic for ImageClassificationNode {
             }?
         };
 
+        // predictions are sorted by score
+        if top_k > 0 {
+            predictions.truncate(top_k as usize);
+        }
+        for prediction in &mut predictions {
+            prediction.class_name = labels.get(prediction.class_idx as usize).cloned();
+        }
+
         // set outputs
         context
             .set_pin_value("predictions", json!(predictions))
@@ -1,6 +1,7 @@
 /// # ONNX Object Detection Nodes
 use crate::{
-    ai::onnx::{NodeOnnxSession, Provider},
+    ai::onnx::{NodeOnnxSession, Provider, load_labels},
+    data::path::FlowPath,
     image::NodeImage,
 };
 use flow_like::{
@@ -14,7 +15,10 @@ use flow_like::{
 };
 use flow_like_types::{
     Error, JsonSchema, Result, anyhow, async_trait,
-    image::{DynamicImage, GenericImageView, imageops::FilterType},
+    image::{
+        DynamicImage, GenericImageView, Rgb, RgbImage,
+        imageops::{self, FilterType},
+    },
     json::{Deserialize, Serialize, json},
 };
 
@@ -195,8 +199,57 @@ impl ObjectDetection for YoloLike {
     }
 }
 
+impl YoloLike {
+    /// Like [`ObjectDetection::run`], but resizes with preserved aspect ratio and gray padding
+    /// the way YOLO models are trained, then maps the boxes back onto the original image.
+    pub fn run_letterboxed(
+        &self,
+        session: &Session,
+        img: &DynamicImage,
+        conf_thres: f32,
+        iou_thres: f32,
+        max_detect: usize,
+    ) -> Result<Vec<BoundingBox>, Error> {
+        let (padded, scale, pad_x, pad_y) = letterbox(img, self.input_width, self.input_height);
+        let session_inputs = self.make_inputs(&padded)?;
+        let session_outputs = session.run(session_inputs)?;
+        let mut bboxes = self.make_results(session_outputs, conf_thres, iou_thres, max_detect)?;
+        for bbox in &mut bboxes {
+            bbox.x1 = (bbox.x1 - pad_x) / scale;
+            bbox.y1 = (bbox.y1 - pad_y) / scale;
+            bbox.x2 = (bbox.x2 - pad_x) / scale;
+            bbox.y2 = (bbox.y2 - pad_y) / scale;
+        }
+        Ok(bboxes)
+    }
+}
+
 // ## Detection-Related Utilities
 
+/// Resize into `width` x `height` keeping the aspect ratio, centered on gray padding.
+/// Returns the padded image, the applied scale and the left/top padding.
+fn letterbox(img: &DynamicImage, width: u32, height: u32) -> (DynamicImage, f32, f32, f32) {
+    let (img_width, img_height) = img.dimensions();
+    let scale = (width as f32 / img_width as f32).min(height as f32 / img_height as f32);
+    let new_width = ((img_width as f32 * scale).round() as u32).clamp(1, width);
+    let new_height = ((img_height as f32 * scale).round() as u32).clamp(1, height);
+
+    let resized = img
+        .resize_exact(new_width, new_height, FilterType::Triangle)
+        .into_rgb8();
+    let mut canvas = RgbImage::from_pixel(width, height, Rgb([114, 114, 114]));
+    let pad_x = (width - new_width) / 2;
+    let pad_y = (height - new_height) / 2;
+    imageops::overlay(&mut canvas, &resized, pad_x as i64, pad_y as i64);
+
+    (
+        DynamicImage::ImageRgb8(canvas),
+        scale,
+        pad_x as f32,
+        pad_y as f32,
+    )
+}
+
 /// Load DynamicImage as Array4
 /// Resulting normalized 4-dim array has shape [B, C, W, H] (batch size, channels, width, height)
 /// ONNX detection model requires Array4-shaped, 0..1 normalized input
@@ -260,6 +313,18 @@ impl BoundingBox {
         (self.x1 as u32, self.y1 as u32, w as u32, h as u32)
     }
 
+    /// left, top, width, height clamped to an image of the given size, `None` if nothing remains
+    pub fn clamped_x1y1wh(&self, width: u32, height: u32) -> Option<(u32, u32, u32, u32)> {
+        let x1 = self.x1.clamp(0.0, width as f32).floor() as u32;
+        let y1 = self.y1.clamp(0.0, height as f32).floor() as u32;
+        let x2 = self.x2.clamp(0.0, width as f32).ceil() as u32;
+        let y2 = self.y2.clamp(0.0, height as f32).ceil() as u32;
+        if x2 <= x1 || y2 <= y1 {
+            return None;
+        }
+        Some((x1, y1, x2 - x1, y2 - y1))
+    }
+
     pub fn area(&self) -> f32 {
         let w = self.x2 - self.x1;
         let h = self.y2 - self.y1;
@@ -445,6 +510,23 @@ impl NodeLogic for ObjectDetectionNode {
         .set_options(PinOptions::new().set_range((0., 1000.)).build())
         .set_default_value(Some(json!(300)));
 
+        node.add_input_pin(
+            "letterbox",
+            "Letterbox",
+            "Keep the aspect ratio and pad when resizing, as YOLO models expect",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(true)));
+
+        node.add_input_pin(
+            "labels",
+            "Labels",
+            "Optional labels.txt with one class name per line",
+            VariableType::Struct,
+        )
+        .set_schema::<FlowPath>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
         // outputs
         node.add_output_pin(
             "exec_out",
@@ -474,9 +556,11 @@ impl NodeLogic for ObjectDetectionNode {
         let conf_thres: f32 = context.evaluate_pin("conf").await?;
         let iou_thres: f32 = context.evaluate_pin("iou").await?;
         let max_detect: usize = context.evaluate_pin("max").await?;
+        let letterbox: bool = context.evaluate_pin("letterbox").await?;
+        let labels = load_labels(context, "labels").await?;
 
         // run inference
-        let predictions = {
+        let mut predictions = {
             let img = node_img.get_image(context).await?;
             let img_guard = img.lock().await;
             let session = node_session.get_session(context).await?;
@@ -490,6 +574,13 @@ impl NodeLogic for ObjectDetectionNode {
                     iou_thres,
                     max_detect,
                 ),
+                Provider::YoloLike(model) if letterbox => model.run_letterboxed(
+                    &session_guard.session,
+                    &img_guard,
+                    conf_thres,
+                    iou_thres,
+                    max_detect,
+                ),
                 Provider::YoloLike(model) => model.run(
                     &session_guard.session,
                     &img_guard,
@@ -503,6 +594,12 @@ impl NodeLogic for ObjectDetectionNode {
             }?
         };
 
+        for bbox in &mut predictions {
+            bbox.class_name = usize::try_from(bbox.class_idx)
+                .ok()
+                .and_then(|idx| labels.get(idx).cloned());
+        }
+
         // set outputs
         context.set_pin_value("bboxes", json!(predictions)).await?;
         context.activate_exec_pin("exec_out").await?;
@@ -1,16 +1,31 @@
+pub mod accumulator;
+pub mod assert;
+pub mod batch;
 pub mod branch_node;
+pub mod cache;
 pub mod call_ref;
+pub mod collect;
+pub mod debounce;
 pub mod delay;
 pub mod do_n;
 pub mod do_once;
+pub mod filter_array;
 pub mod flip_flop;
 pub mod for_each;
 pub mod for_each_with_break;
 pub mod gate;
 pub mod gather;
+pub mod join;
+pub mod loop_control;
+pub mod mutex;
 pub mod par_execution;
+pub mod recover;
 pub mod reroute;
 pub mod sequence;
+pub mod switch_count;
+pub mod throttle;
+pub mod try_catch;
+pub mod wait_for_signal;
 pub mod while_loop;
 
 use flow_like::flow::node::NodeLogic;
@@ -32,5 +47,22 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(flip_flop::FlipFlopNode::default()),
         Arc::new(for_each_with_break::ForEachWithBreakNode::default()),
         Arc::new(gate::GateNode::default()),
+        Arc::new(assert::AssertNode::default()),
+        Arc::new(join::JoinExecNode::default()),
+        Arc::new(throttle::ThrottleNode::default()),
+        Arc::new(accumulator::AccumulatorNode::default()),
+        Arc::new(collect::CollectNode::default()),
+        Arc::new(batch::BatchNode::default()),
+        Arc::new(filter_array::FilterArrayNode::default()),
+        Arc::new(recover::RecoverNode::default()),
+        Arc::new(try_catch::TryCatchNode::default()),
+        Arc::new(switch_count::SwitchOnCountNode::default()),
+        Arc::new(debounce::DebounceNode::default()),
+        Arc::new(cache::CacheNode::default()),
+        Arc::new(cache::CacheStoreNode::default()),
+        Arc::new(mutex::MutexNode::default()),
+        Arc::new(loop_control::BreakNode::default()),
+        Arc::new(loop_control::ContinueNode::default()),
+        Arc::new(wait_for_signal::WaitForSignalNode::default()),
     ]
 }
@@ -0,0 +1,162 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+use std::sync::Arc;
+
+/// Running total across triggers of the same run, stored via [`ExecutionContext::run_state`].
+/// The total starts at zero for every new run.
+#[derive(Default)]
+pub struct AccumulatorNode {}
+
+impl AccumulatorNode {
+    pub fn new() -> Self {
+        AccumulatorNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for AccumulatorNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_accumulator",
+            "Accumulator",
+            "Adds a delta to a running total that persists across loop iterations within a run",
+            "Control/Flow",
+        );
+        node.add_icon("/flow/icons/workflow.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Add",
+            "Adds Delta to the total",
+            VariableType::Execution,
+        );
+        node.add_input_pin(
+            "reset",
+            "Reset",
+            "Resets the total to zero",
+            VariableType::Execution,
+        );
+
+        node.add_input_pin(
+            "number_type",
+            "Number Type",
+            "Type of the running total",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Integer".to_string(), "Float".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Float")));
+
+        node.add_input_pin("delta", "Delta", "Value to add", VariableType::Float)
+            .set_default_value(Some(json!(1.0)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "total",
+            "Total",
+            "Current running total",
+            VariableType::Float,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let mut reset = false;
+        if let Some(pins) = &context.started_by {
+            for pin in pins {
+                if pin.lock().await.pin.lock().await.name == "reset" {
+                    reset = true;
+                }
+            }
+        }
+
+        let number_type: String = context.evaluate_pin("number_type").await?;
+        let delta: Value = if reset {
+            Value::Null
+        } else {
+            context.evaluate_pin("delta").await?
+        };
+
+        let state = context.run_state().await?;
+        let mut state = state.lock().await;
+
+        let total = match (number_type.as_str(), reset) {
+            ("Integer", true) => json!(0),
+            ("Integer", false) => {
+                let current = state.as_i64().unwrap_or(0);
+                let delta = delta
+                    .as_i64()
+                    .ok_or_else(|| anyhow!("Delta {} is not an Integer", delta))?;
+                let total = current
+                    .checked_add(delta)
+                    .ok_or_else(|| anyhow!("Accumulator overflowed at {} + {}", current, delta))?;
+                json!(total)
+            }
+            (_, true) => json!(0.0),
+            (_, false) => {
+                let current = state.as_f64().unwrap_or(0.0);
+                let delta = delta
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Delta {} is not a Float", delta))?;
+                json!(current + delta)
+            }
+        };
+
+        *state = total.clone();
+        drop(state);
+
+        context.set_pin_value("total", total).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, _board: Arc<Board>) {
+        let number_type: String = node
+            .get_pin_by_name("number_type")
+            .and_then(|pin| pin.default_value.clone())
+            .and_then(|bytes| flow_like_types::json::from_slice::<Value>(&bytes).ok())
+            .and_then(|json| json.as_str().map(ToOwned::to_owned))
+            .unwrap_or_default();
+
+        let data_type = if number_type == "Integer" {
+            VariableType::Integer
+        } else {
+            VariableType::Float
+        };
+
+        if let Some(delta) = node.get_pin_mut_by_name("delta")
+            && delta.data_type != data_type
+        {
+            delta.data_type = data_type.clone();
+            delta.set_default_value(Some(if data_type == VariableType::Integer {
+                json!(1)
+            } else {
+                json!(1.0)
+            }));
+        }
+
+        if let Some(total) = node.get_pin_mut_by_name("total") {
+            total.data_type = data_type;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, bail, json::json};
+
+#[derive(Default)]
+pub struct AssertNode {}
+
+impl AssertNode {
+    pub fn new() -> Self {
+        AssertNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for AssertNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_assert",
+            "Assert",
+            "Fails the flow if the condition is false. Failures are routed into the error handling of this node",
+            "Control",
+        );
+        node.add_icon("/flow/icons/split.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+        node.add_input_pin(
+            "condition",
+            "Condition",
+            "The invariant that has to hold",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(true)));
+        node.add_input_pin(
+            "message",
+            "Message",
+            "Message to report if the assertion fails",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("Assertion failed")));
+        node.add_input_pin(
+            "severity",
+            "Severity",
+            "Error fails the flow, Warning only logs and continues",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Error".to_string(), "Warning".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Error")));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Continues if the assertion holds (or is only a warning)",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let condition: bool = context.evaluate_pin("condition").await?;
+
+        if !condition {
+            let message: String = context.evaluate_pin("message").await?;
+            let severity: String = context.evaluate_pin("severity").await?;
+
+            if severity != "Warning" {
+                context.log_message(&message, LogLevel::Error);
+                bail!("Assertion failed: {}", message);
+            }
+
+            context.log_message(&message, LogLevel::Warn);
+        }
+
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+/// Groups items into batches across triggers of the same run, stored via [`ExecutionContext::run_state`].
+/// A batch is emitted once `batch_size` items are buffered; flush emits the remaining partial batch.
+#[derive(Default)]
+pub struct BatchNode {}
+
+impl BatchNode {
+    pub fn new() -> Self {
+        BatchNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for BatchNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_batch",
+            "Batch",
+            "Buffers one item per trigger and emits them as an array once the batch is full, e.g. in front of a batch upsert",
+            "Control/Flow",
+        );
+        node.add_icon("/flow/icons/for-each.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Add",
+            "Appends Item to the current batch",
+            VariableType::Execution,
+        );
+        node.add_input_pin(
+            "flush",
+            "Flush",
+            "Emits the current partial batch",
+            VariableType::Execution,
+        );
+        node.add_input_pin("item", "Item", "Item to buffer", VariableType::Generic);
+        node.add_input_pin(
+            "batch_size",
+            "Batch Size",
+            "Number of items per batch",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(32)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Buffered",
+            "Fires after an item was buffered without completing a batch",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "batch_ready",
+            "Batch Ready",
+            "Fires with a full batch, or a non-empty partial batch on flush",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "batch",
+            "Batch",
+            "Items of the batch",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        context.deactivate_exec_pin("batch_ready").await?;
+
+        let mut flush = false;
+        if let Some(pins) = &context.started_by {
+            for pin in pins {
+                if pin.lock().await.pin.lock().await.name == "flush" {
+                    flush = true;
+                }
+            }
+        }
+
+        let batch_size: i64 = context.evaluate_pin("batch_size").await?;
+        let batch_size = batch_size.max(1) as usize;
+        let item: Value = if flush {
+            Value::Null
+        } else {
+            context.evaluate_pin("item").await?
+        };
+
+        let state = context.run_state().await?;
+        let mut state = state.lock().await;
+        let mut buffer = match std::mem::take(&mut *state) {
+            Value::Array(buffer) => buffer,
+            _ => vec![],
+        };
+
+        if !flush {
+            buffer.push(item);
+        }
+
+        if flush && buffer.is_empty() {
+            return Ok(());
+        }
+
+        if !flush && buffer.len() < batch_size {
+            *state = Value::Array(buffer);
+            drop(state);
+            context.activate_exec_pin("exec_out").await?;
+            return Ok(());
+        }
+        drop(state);
+
+        context.set_pin_value("batch", json!(buffer)).await?;
+        context.activate_exec_pin("batch_ready").await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type(
+            "item",
+            board.clone(),
+            Some(ValueType::Normal),
+            Some(ValueType::Normal),
+        );
+        let _ = node.match_type(
+            "batch",
+            board,
+            Some(ValueType::Array),
+            Some(ValueType::Array),
+        );
+        node.harmonize_type(vec!["item", "batch"], true);
+    }
+}
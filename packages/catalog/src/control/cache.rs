@@ -0,0 +1,192 @@
+use crate::data::path::{FlowPath, FlowPathRuntime};
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        utils::stable_hash,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+/// Resolves the entry file for `key` below `dir`. Keys are hashed so arbitrary strings
+/// (slashes, prompts, ...) map to a single flat file name.
+async fn entry_path(
+    context: &mut ExecutionContext,
+    dir: &FlowPath,
+    key: &str,
+) -> flow_like_types::Result<FlowPathRuntime> {
+    let mut runtime = dir.to_runtime(context).await?;
+    let file_name = format!("{}.json", stable_hash(&json!(key))?);
+    runtime.path = runtime.path.child(file_name);
+    Ok(runtime)
+}
+
+fn add_cache_pins(node: &mut Node) {
+    node.add_input_pin(
+        "dir",
+        "Directory",
+        "Directory holding the cache entries",
+        VariableType::Struct,
+    )
+    .set_schema::<FlowPath>()
+    .set_options(PinOptions::new().set_enforce_schema(true).build());
+    node.add_input_pin(
+        "key",
+        "Key",
+        "Cache key, e.g. the output of Stable Hash over the inputs",
+        VariableType::String,
+    );
+}
+
+/// Looks up a cached value on a FlowPath directory. On a hit the value is emitted on
+/// `Hit` and the expensive subgraph can be skipped, on a miss the graph continues on
+/// `Miss`, which is expected to end in a [`CacheStoreNode`] with the same key.
+#[derive(Default)]
+pub struct CacheNode {}
+
+impl CacheNode {
+    pub fn new() -> Self {
+        CacheNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CacheNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_cache",
+            "Cache",
+            "Returns a previously stored value for a key, or continues on Miss to compute it",
+            "Control/Flow",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+        add_cache_pins(&mut node);
+
+        node.add_output_pin(
+            "hit",
+            "Hit",
+            "Fires when a cached value exists for the key",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "miss",
+            "Miss",
+            "Fires when nothing is cached for the key",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "value",
+            "Value",
+            "Cached value, only set on Hit",
+            VariableType::Generic,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("hit").await?;
+        context.deactivate_exec_pin("miss").await?;
+
+        let dir: FlowPath = context.evaluate_pin("dir").await?;
+        let key: String = context.evaluate_pin("key").await?;
+        let entry = entry_path(context, &dir, &key).await?;
+
+        if entry.store.as_generic().head(&entry.path).await.is_err() {
+            context.activate_exec_pin("miss").await?;
+            return Ok(());
+        }
+
+        let bytes = entry.serialize().await.get(context, false).await?;
+        let value: Value = flow_like_types::json::from_slice(&bytes)?;
+
+        context.set_pin_value("value", value).await?;
+        context.activate_exec_pin("hit").await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("value", board, None, None);
+    }
+}
+
+/// Writes a value to the FlowPath cache directory under `key`, pairs with [`CacheNode`].
+#[derive(Default)]
+pub struct CacheStoreNode {}
+
+impl CacheStoreNode {
+    pub fn new() -> Self {
+        CacheStoreNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CacheStoreNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_cache_store",
+            "Cache Store",
+            "Stores a value for a key so later Cache lookups hit",
+            "Control/Flow",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+        add_cache_pins(&mut node);
+        node.add_input_pin("value", "Value", "Value to cache", VariableType::Generic);
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Fires once the value is stored",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "value_out",
+            "Value",
+            "The stored value, passed through",
+            VariableType::Generic,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let dir: FlowPath = context.evaluate_pin("dir").await?;
+        let key: String = context.evaluate_pin("key").await?;
+        let value: Value = context.evaluate_pin("value").await?;
+
+        let entry = entry_path(context, &dir, &key).await?;
+        let bytes = flow_like_types::json::to_vec(&value)?;
+        entry.serialize().await.put(context, bytes, false).await?;
+
+        context.set_pin_value("value_out", value).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("value", board.clone(), Some(ValueType::Normal), None);
+        let _ = node.match_type("value_out", board, Some(ValueType::Normal), None);
+        node.harmonize_type(vec!["value", "value_out"], true);
+    }
+}
@@ -0,0 +1,146 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+/// Buffers values across triggers of the same run, stored via [`ExecutionContext::run_state`].
+/// Flushing emits and clears the buffer, so the node can be reused later in the run.
+#[derive(Default)]
+pub struct CollectNode {}
+
+impl CollectNode {
+    pub fn new() -> Self {
+        CollectNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CollectNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_collect",
+            "Collect",
+            "Appends a value per trigger, e.g. from a loop body, and emits all collected values on flush",
+            "Control/Flow",
+        );
+        node.add_icon("/flow/icons/for-each.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Add",
+            "Appends Value to the buffer",
+            VariableType::Execution,
+        );
+        node.add_input_pin(
+            "flush",
+            "Flush",
+            "Emits the collected values and clears the buffer",
+            VariableType::Execution,
+        );
+        node.add_input_pin("value", "Value", "Value to append", VariableType::Generic);
+
+        node.add_output_pin(
+            "exec_out",
+            "Added",
+            "Fires after a value was appended",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "flushed",
+            "Flushed",
+            "Fires after a flush with the collected values",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "array",
+            "Array",
+            "Collected values, set on flush",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+        node.add_output_pin(
+            "count",
+            "Count",
+            "Number of values in the buffer",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        context.deactivate_exec_pin("flushed").await?;
+
+        let mut flush = false;
+        if let Some(pins) = &context.started_by {
+            for pin in pins {
+                if pin.lock().await.pin.lock().await.name == "flush" {
+                    flush = true;
+                }
+            }
+        }
+
+        let value: Value = if flush {
+            Value::Null
+        } else {
+            context.evaluate_pin("value").await?
+        };
+
+        let state = context.run_state().await?;
+        let mut state = state.lock().await;
+
+        if flush {
+            let collected = match std::mem::take(&mut *state) {
+                Value::Array(collected) => collected,
+                _ => vec![],
+            };
+            drop(state);
+
+            context.set_pin_value("array", json!(collected)).await?;
+            context.set_pin_value("count", json!(0)).await?;
+            context.activate_exec_pin("flushed").await?;
+            return Ok(());
+        }
+
+        if !state.is_array() {
+            *state = Value::Array(vec![]);
+        }
+        let count = match &mut *state {
+            Value::Array(buffer) => {
+                buffer.push(value);
+                buffer.len()
+            }
+            _ => 0,
+        };
+        drop(state);
+
+        context.set_pin_value("count", json!(count)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type(
+            "value",
+            board.clone(),
+            Some(ValueType::Normal),
+            Some(ValueType::Normal),
+        );
+        let _ = node.match_type(
+            "array",
+            board,
+            Some(ValueType::Array),
+            Some(ValueType::Array),
+        );
+        node.harmonize_type(vec!["value", "array"], true);
+    }
+}
@@ -0,0 +1,104 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    async_trait, bail,
+    json::json,
+    tokio::{self, time::Duration},
+};
+
+/// Every trigger waits for the window. Only a trigger that is still the latest one after
+/// the window passes through, all earlier triggers leave through `suppressed`.
+///
+/// Triggers wait inline and the latest one is tracked in the run state, so only triggers that
+/// overlap within one run are collapsed (e.g. parallel branches). A trigger arriving after the
+/// previous one finished waiting passes on its own, and so does every event, as each event
+/// starts its own run.
+#[derive(Default)]
+pub struct DebounceNode {}
+
+impl DebounceNode {
+    pub fn new() -> Self {
+        DebounceNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for DebounceNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_debounce",
+            "Debounce",
+            "Passes only once triggers stopped arriving for the window, e.g. to react once to parallel branches. Only overlapping triggers of the same run are collapsed, separate events start separate runs and each pass",
+            "Control",
+        );
+
+        node.set_long_running(true);
+        node.add_icon("/flow/icons/clock.svg");
+
+        node.add_input_pin("exec_in", "Execute", "Execution", VariableType::Execution);
+        node.add_input_pin(
+            "window_ms",
+            "Window (ms)",
+            "Quiet time required before a trigger passes, in milliseconds",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(500)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Fires once the window elapsed without a newer trigger",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "suppressed",
+            "Suppressed",
+            "Fires for triggers superseded by a newer one",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        context.deactivate_exec_pin("suppressed").await?;
+
+        let window_ms: i64 = context.evaluate_pin("window_ms").await?;
+        let window = Duration::from_millis(window_ms.max(0) as u64);
+
+        // the number of the latest trigger of this node within the run
+        let state = context.run_state().await?;
+        let generation = {
+            let mut state = state.lock().await;
+            let generation = state.as_u64().unwrap_or(0) + 1;
+            *state = json!(generation);
+            generation
+        };
+
+        match context.cancellation_token().await {
+            Some(token) => {
+                tokio::select! {
+                    _ = tokio::time::sleep(window) => {}
+                    _ = token.cancelled() => bail!("Run cancelled while debouncing"),
+                }
+            }
+            None => tokio::time::sleep(window).await,
+        }
+
+        if state.lock().await.as_u64() != Some(generation) {
+            context.log_message("Debounce: superseded by a newer trigger", LogLevel::Debug);
+            context.activate_exec_pin("suppressed").await?;
+            return Ok(());
+        }
+
+        context.activate_exec_pin("exec_out").await?;
+        return Ok(());
+    }
+}
@@ -94,7 +94,7 @@ impl NodeLogic for DoNNode {
         // Read parameters
         let mut n: i64 = context.evaluate_pin("n").await.unwrap_or(1);
         let start_index: i64 = context.evaluate_pin("start_index").await.unwrap_or(0);
-        let do_reset: bool = context.evaluate_pin("reset").await.unwrap_or(false);
+        let do_reset: bool = context.is_exec_active("reset").await?;
 
         // Read current state from our own output pin (persisted across triggers)
         let mut index: i64 = context.evaluate_pin("index").await.unwrap_or(start_index);
@@ -74,8 +74,8 @@ impl NodeLogic for DoOnceNode {
 
         // Read inputs
         let start_closed: bool = context.evaluate_pin("start_closed").await.unwrap_or(false);
-        let did_reset: bool = context.evaluate_pin("reset").await.unwrap_or(false);
-        let did_exec: bool = context.evaluate_pin("exec_in").await.unwrap_or(false);
+        let did_reset: bool = context.is_exec_active("reset").await?;
+        let did_exec: bool = context.is_exec_active("exec_in").await?;
 
         // Load persistent state (defaults to `start_closed`):
         // - `has_fired == false`  => open (has not passed yet)
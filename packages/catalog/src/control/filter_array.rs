@@ -0,0 +1,168 @@
+use ahash::AHashSet;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+/// Runs the `predicate` subgraph once per element and reads `keep` after each iteration.
+/// Iterations whose subgraph fails are logged and the element is dropped.
+#[derive(Default)]
+pub struct FilterArrayNode {}
+
+impl FilterArrayNode {
+    pub fn new() -> Self {
+        FilterArrayNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for FilterArrayNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_filter_array",
+            "Filter Array",
+            "Keeps the elements of an Array for which the predicate sets Keep to true",
+            "Control",
+        );
+        node.add_icon("/flow/icons/for-each.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+        node.add_input_pin("array", "Array", "Array to filter", VariableType::Generic)
+            .set_value_type(ValueType::Array)
+            .set_options(
+                PinOptions::new()
+                    .set_enforce_generic_value_type(true)
+                    .build(),
+            );
+        node.add_input_pin(
+            "keep",
+            "Keep",
+            "Set by the predicate, true keeps the current item",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "predicate",
+            "Predicate",
+            "Executes for every item, set Keep from here",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "item",
+            "Item",
+            "The current item Value",
+            VariableType::Generic,
+        );
+        node.add_output_pin(
+            "index",
+            "Index",
+            "Current Array Index",
+            VariableType::Integer,
+        );
+        node.add_output_pin(
+            "done",
+            "Done",
+            "Executes once the array is filtered",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "filtered",
+            "Filtered",
+            "Items that were kept, in their original order",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let done = context.get_pin_by_name("done").await?;
+        let predicate = context.get_pin_by_name("predicate").await?;
+        context.deactivate_exec_pin_ref(&done).await?;
+        context.deactivate_exec_pin_ref(&predicate).await?;
+
+        let array = context.get_pin_by_name("array").await?;
+        let keep = context.get_pin_by_name("keep").await?;
+        let item = context.get_pin_by_name("item").await?;
+        let index = context.get_pin_by_name("index").await?;
+
+        let array_value: Value = context.evaluate_pin_ref(array).await?;
+        let array_value = array_value
+            .as_array()
+            .ok_or(flow_like_types::anyhow!("Array value is not an array"))?;
+
+        let id = context.read_node().await.id.clone();
+        let recursion_guard = AHashSet::from_iter(vec![id]);
+        let connected = predicate.lock().await.get_connected_nodes().await;
+
+        let mut filtered = Vec::with_capacity(array_value.len());
+        context.activate_exec_pin_ref(&predicate).await?;
+        'items: for (i, value) in array_value.iter().enumerate() {
+            item.lock().await.set_value(value.to_owned()).await;
+            index.lock().await.set_value(Value::from(i)).await;
+
+            for node in connected.iter() {
+                let mut sub_context = context.create_sub_context(node).await;
+                sub_context.clear_loop_signal();
+                let run = InternalNode::trigger(
+                    &mut sub_context,
+                    &mut Some(recursion_guard.clone()),
+                    true,
+                )
+                .await;
+                sub_context.end_trace();
+                context.push_sub_context(&mut sub_context);
+
+                if let Err(error) = run {
+                    context.log_message(
+                        &format!("Error: {:?} in predicate for index {}", error, i),
+                        LogLevel::Error,
+                    );
+                    continue 'items;
+                }
+            }
+
+            let keep_item: bool = context.evaluate_pin_ref(keep.clone()).await?;
+            if keep_item {
+                filtered.push(value.to_owned());
+            }
+        }
+        context.deactivate_exec_pin_ref(&predicate).await?;
+
+        context.set_pin_value("filtered", json!(filtered)).await?;
+        context.activate_exec_pin_ref(&done).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type(
+            "array",
+            board.clone(),
+            Some(ValueType::Array),
+            Some(ValueType::Array),
+        );
+        let _ = node.match_type(
+            "item",
+            board.clone(),
+            Some(ValueType::Normal),
+            Some(ValueType::Normal),
+        );
+        let _ = node.match_type(
+            "filtered",
+            board,
+            Some(ValueType::Array),
+            Some(ValueType::Array),
+        );
+        node.harmonize_type(vec!["array", "item", "filtered"], true);
+    }
+}
@@ -76,7 +76,7 @@ impl NodeLogic for FlipFlopNode {
 
         // Read inputs
         let start_on_a: bool = context.evaluate_pin("start_on_a").await.unwrap_or(true);
-        let did_exec: bool = context.evaluate_pin("exec_in").await.unwrap_or(false);
+        let did_exec: bool = context.is_exec_active("exec_in").await?;
 
         // Load persisted state (default derives from start_on_a)
         let mut is_a: bool = context.evaluate_pin("is_a").await.unwrap_or(start_on_a);
@@ -1,7 +1,11 @@
 use flow_like::{
     flow::{
         board::Board,
-        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, LoopSignal},
+            internal_node::InternalNode,
+        },
         node::{Node, NodeLogic},
         pin::{PinOptions, ValueType},
         variable::VariableType,
@@ -85,7 +89,9 @@ impl NodeLogic for LoopNode {
             .ok_or(flow_like_types::anyhow!("Array value is not an array"))?;
 
         context.activate_exec_pin_ref(&exec_item).await?;
+        let signal = LoopSignal::new();
         for (i, item) in array_value.iter().enumerate() {
+            signal.next_pass();
             let item = item.to_owned();
             value.lock().await.set_value(item).await;
             index
@@ -95,6 +101,7 @@ impl NodeLogic for LoopNode {
                 .await;
             for node in connected.iter() {
                 let mut sub_context = context.create_sub_context(node).await;
+                sub_context.set_loop_signal(signal.clone());
                 let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
                 sub_context.end_trace();
                 context.push_sub_context(&mut sub_context);
@@ -106,6 +113,15 @@ impl NodeLogic for LoopNode {
                         LogLevel::Error,
                     );
                 }
+
+                if signal.pass_ended() {
+                    break;
+                }
+            }
+
+            if signal.is_broken() {
+                context.log_message(&format!("Loop broken at index {}", i), LogLevel::Debug);
+                break;
             }
         }
 
@@ -2,7 +2,11 @@ use ahash::AHashSet;
 use flow_like::{
     flow::{
         board::Board,
-        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, LoopSignal},
+            internal_node::InternalNode,
+        },
         node::{Node, NodeLogic},
         pin::{PinOptions, ValueType},
         variable::VariableType,
@@ -115,7 +119,10 @@ impl NodeLogic for ForEachWithBreakNode {
 
         context.activate_exec_pin_ref(&exec_item).await?;
 
+        let signal = LoopSignal::new();
         'outer: for (i, item) in array.iter().enumerate() {
+            signal.next_pass();
+
             // Publish per-iteration values
             value.lock().await.set_value(item.to_owned()).await;
             index.lock().await.set_value(Value::from(i)).await;
@@ -124,6 +131,7 @@ impl NodeLogic for ForEachWithBreakNode {
             let connected = exec_item.lock().await.get_connected_nodes().await;
             for node in connected.iter() {
                 let mut sub_context = context.create_sub_context(node).await;
+                sub_context.set_loop_signal(signal.clone());
                 let run = InternalNode::trigger(
                     &mut sub_context,
                     &mut Some(recursion_guard.clone()),
@@ -140,6 +148,18 @@ impl NodeLogic for ForEachWithBreakNode {
                     );
                 }
 
+                // A Break node in the body stops the loop, a Continue node ends the pass
+                if signal.is_broken() {
+                    context.log_message(
+                        &format!("ForEach(Break): breaking at index {}", i),
+                        LogLevel::Debug,
+                    );
+                    break 'outer;
+                }
+                if signal.pass_ended() {
+                    continue 'outer;
+                }
+
                 // Check if a Break was requested during the body execution
                 match context.evaluate_pin_ref(break_pin.clone()).await {
                     Ok(should_break) => {
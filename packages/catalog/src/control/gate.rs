@@ -94,10 +94,10 @@ impl NodeLogic for GateNode {
         context.deactivate_exec_pin("exit").await?;
 
         // Read triggers
-        let did_enter: bool = context.evaluate_pin("enter").await.unwrap_or(false);
-        let did_open: bool = context.evaluate_pin("open").await.unwrap_or(false);
-        let did_close: bool = context.evaluate_pin("close").await.unwrap_or(false);
-        let did_toggle: bool = context.evaluate_pin("toggle").await.unwrap_or(false);
+        let did_enter: bool = context.is_exec_active("enter").await?;
+        let did_open: bool = context.is_exec_active("open").await?;
+        let did_close: bool = context.is_exec_active("close").await?;
+        let did_toggle: bool = context.is_exec_active("toggle").await?;
 
         // Determine default/open state
         let start_closed: bool = context.evaluate_pin("start_closed").await.unwrap_or(false);
@@ -46,19 +46,7 @@ impl NodeLogic for GatherExecutionNode {
         let input_pins = context.get_pins_by_name("exec_in").await?;
 
         for pin in &input_pins {
-            let value: bool = match context.evaluate_pin_ref(pin.to_owned()).await {
-                Ok(value) => value,
-                Err(val) => {
-                    // This means the pin is not set.
-                    context.log_message(
-                        &format!("Failed to evaluate pin {}", val),
-                        flow_like::flow::execution::LogLevel::Debug,
-                    );
-                    return Ok(());
-                }
-            };
-
-            if !value {
+            if !context.is_exec_active_ref(pin).await {
                 return Ok(());
             }
         }
@@ -0,0 +1,149 @@
+use ahash::AHashSet;
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Cacheable, anyhow, async_trait, json::json, sync::Mutex};
+use std::sync::Arc;
+
+/// Run-scoped set of input pin ids that already fired, stored in the run cache.
+#[derive(Default)]
+struct JoinState {
+    arrived: Mutex<AHashSet<String>>,
+}
+
+impl Cacheable for JoinState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Gates on all connected exec inputs. Every branch triggers the join on its own, which
+/// only works if each arrival gets a fresh recursion guard (successors, parallel tasks).
+/// Arrivals from the same guard, e.g. two routes inside one loop body, are only counted once.
+/// After firing, the arrival set is reset, so the join can be re-entered within the same run.
+#[derive(Default)]
+pub struct JoinExecNode {}
+
+impl JoinExecNode {
+    pub fn new() -> Self {
+        JoinExecNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for JoinExecNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_join",
+            "Join",
+            "Waits until every connected input branch arrived in this run, then continues once",
+            "Control/Parallel",
+        );
+        node.add_icon("/flow/icons/par_execution.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Branch to wait for",
+            VariableType::Execution,
+        );
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Branch to wait for",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Fires once all connected branches arrived",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "arrived",
+            "Arrived",
+            "Number of branches that arrived since the last time the join fired",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(0)));
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let mut expected = AHashSet::new();
+        for pin in context.get_pins_by_name("exec_in").await? {
+            let pin = pin.lock().await;
+            if !pin.depends_on.is_empty() {
+                expected.insert(pin.pin.lock().await.id.clone());
+            }
+        }
+
+        let mut incoming = Vec::new();
+        match &context.started_by {
+            Some(pins) => {
+                for pin in pins {
+                    incoming.push(pin.lock().await.pin.lock().await.id.clone());
+                }
+            }
+            // not reached through an exec pin (e.g. run directly), fall back to the pin values
+            None => {
+                for pin in context.get_pins_by_name("exec_in").await? {
+                    if context.is_exec_active_ref(&pin).await {
+                        incoming.push(pin.lock().await.pin.lock().await.id.clone());
+                    }
+                }
+            }
+        }
+
+        let key = format!("control_join_{}", context.id);
+        let state = context
+            .cache
+            .write()
+            .await
+            .entry(key)
+            .or_insert_with(|| Arc::new(JoinState::default()))
+            .clone();
+        let state = state
+            .as_any()
+            .downcast_ref::<JoinState>()
+            .ok_or_else(|| anyhow!("Could not downcast join state"))?;
+
+        // check and reset under the same lock, parallel branches may arrive concurrently
+        let (arrived, complete) = {
+            let mut arrived = state.arrived.lock().await;
+            arrived.extend(incoming.into_iter().filter(|id| expected.contains(id)));
+            let complete = !expected.is_empty() && expected.is_subset(&arrived);
+            let count = arrived.len();
+            if complete {
+                arrived.clear();
+            }
+            (count, complete)
+        };
+
+        context.set_pin_value("arrived", json!(arrived)).await?;
+
+        if !complete {
+            context.log_message(
+                &format!("Join: {} of {} branches arrived", arrived, expected.len()),
+                LogLevel::Debug,
+            );
+            return Ok(());
+        }
+
+        context.activate_exec_pin("exec_out").await?;
+        return Ok(());
+    }
+}
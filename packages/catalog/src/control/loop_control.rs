@@ -0,0 +1,229 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::async_trait;
+
+/// Placed in the body of a loop node like For Each, While Loop or For Each Row, it stops the loop.
+/// Nothing after it in the current pass runs and the loop continues with its done pin.
+/// In nested loops only the innermost loop around the node is stopped.
+#[derive(Default)]
+pub struct BreakNode {}
+
+impl BreakNode {
+    pub fn new() -> Self {
+        BreakNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for BreakNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_break",
+            "Break",
+            "Stops the innermost loop around this node. The rest of the current pass is skipped",
+            "Control",
+        );
+        node.add_icon("/flow/icons/for-each.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        if !context.break_loop() {
+            context.log_message(
+                "Break is not part of a loop body, nothing to break",
+                LogLevel::Warn,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Placed in a loop body, it skips the rest of the current pass and the loop carries on
+/// with the next item or iteration. Like [`BreakNode`] it only affects the innermost loop.
+#[derive(Default)]
+pub struct ContinueNode {}
+
+impl ContinueNode {
+    pub fn new() -> Self {
+        ContinueNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ContinueNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_continue",
+            "Continue",
+            "Skips the rest of the current pass of the innermost loop around this node",
+            "Control",
+        );
+        node.add_icon("/flow/icons/for-each.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        if !context.continue_loop() {
+            context.log_message(
+                "Continue is not part of a loop body, nothing to skip",
+                LogLevel::Warn,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BreakNode, ContinueNode};
+    use crate::control::{for_each::LoopNode, sequence::SequenceNode};
+    use flow_like::{
+        flow::{
+            execution::{context::ExecutionContext, test_board::TestBoard},
+            node::{Node, NodeLogic},
+            variable::VariableType,
+        },
+        state::FlowLikeState,
+    };
+    use flow_like_types::{async_trait, json::json, tokio};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    /// Passes execution on and counts its runs.
+    struct CountNode {
+        name: &'static str,
+        runs: AtomicUsize,
+    }
+
+    impl CountNode {
+        fn new(name: &'static str) -> Arc<Self> {
+            Arc::new(CountNode {
+                name,
+                runs: AtomicUsize::new(0),
+            })
+        }
+
+        fn runs(&self) -> usize {
+            self.runs.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl NodeLogic for CountNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new(self.name, "Count", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_output_pin("exec_out", "Output", "", VariableType::Execution);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            context.activate_exec_pin("exec_out").await
+        }
+    }
+
+    async fn board(counters: &[&Arc<CountNode>]) -> TestBoard {
+        let mut logics: Vec<Arc<dyn NodeLogic>> = vec![
+            Arc::new(LoopNode::new()),
+            Arc::new(SequenceNode::new()),
+            Arc::new(BreakNode::new()),
+            Arc::new(ContinueNode::new()),
+        ];
+        for counter in counters {
+            logics.push((*counter).clone());
+        }
+        TestBoard::new(logics).await
+    }
+
+    async fn for_each(board: &mut TestBoard, items: usize) -> String {
+        let node = board.add(&LoopNode::new()).await;
+        board.set_input(&node, "array", json!(vec![0; items]));
+        node
+    }
+
+    #[tokio::test]
+    async fn break_stops_the_loop() {
+        let body = CountNode::new("test_body");
+        let done = CountNode::new("test_done");
+        let mut board = board(&[&body, &done]).await;
+
+        let looped = for_each(&mut board, 3).await;
+        let body_id = board.add(body.as_ref()).await;
+        let break_id = board.add(&BreakNode::new()).await;
+        let done_id = board.add(done.as_ref()).await;
+        board.connect(&looped, "exec_out", &body_id, "exec_in");
+        board.connect(&body_id, "exec_out", &break_id, "exec_in");
+        board.connect(&looped, "done", &done_id, "exec_in");
+        board.run(&looped).await;
+
+        assert_eq!(body.runs(), 1);
+        assert_eq!(done.runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn continue_skips_the_rest_of_the_pass() {
+        let body = CountNode::new("test_body");
+        let skipped = CountNode::new("test_skipped");
+        let done = CountNode::new("test_done");
+        let mut board = board(&[&body, &skipped, &done]).await;
+
+        let looped = for_each(&mut board, 3).await;
+        let sequence = board.add(&SequenceNode::new()).await;
+        let body_id = board.add(body.as_ref()).await;
+        let continue_id = board.add(&ContinueNode::new()).await;
+        let skipped_id = board.add(skipped.as_ref()).await;
+        let done_id = board.add(done.as_ref()).await;
+        board.connect(&looped, "exec_out", &sequence, "exec_in");
+        board.connect_nth(&sequence, "exec_out", 0, &body_id, "exec_in");
+        board.connect(&body_id, "exec_out", &continue_id, "exec_in");
+        board.connect_nth(&sequence, "exec_out", 1, &skipped_id, "exec_in");
+        board.connect(&looped, "done", &done_id, "exec_in");
+        board.run(&looped).await;
+
+        assert_eq!(body.runs(), 3);
+        assert_eq!(skipped.runs(), 0);
+        assert_eq!(done.runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn break_in_a_nested_loop_stops_the_inner_loop_only() {
+        let inner_body = CountNode::new("test_inner_body");
+        let inner_done = CountNode::new("test_inner_done");
+        let outer_done = CountNode::new("test_outer_done");
+        let mut board = board(&[&inner_body, &inner_done, &outer_done]).await;
+
+        let outer = for_each(&mut board, 2).await;
+        let inner = for_each(&mut board, 3).await;
+        let inner_body_id = board.add(inner_body.as_ref()).await;
+        let break_id = board.add(&BreakNode::new()).await;
+        let inner_done_id = board.add(inner_done.as_ref()).await;
+        let outer_done_id = board.add(outer_done.as_ref()).await;
+        board.connect(&outer, "exec_out", &inner, "exec_in");
+        board.connect(&inner, "exec_out", &inner_body_id, "exec_in");
+        board.connect(&inner_body_id, "exec_out", &break_id, "exec_in");
+        board.connect(&inner, "done", &inner_done_id, "exec_in");
+        board.connect(&outer, "done", &outer_done_id, "exec_in");
+        board.run(&outer).await;
+
+        assert_eq!(inner_body.runs(), 2);
+        assert_eq!(inner_done.runs(), 2);
+        assert_eq!(outer_done.runs(), 1);
+    }
+}
@@ -0,0 +1,160 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Cacheable, anyhow, async_trait, bail,
+    json::json,
+    sync::Mutex,
+    tokio::{self, time::Instant},
+};
+use std::sync::Arc;
+
+/// Lock shared by all mutex nodes of a run using the same name.
+struct MutexState {
+    lock: Mutex<()>,
+}
+
+impl Cacheable for MutexState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// # Mutex Node
+/// Lets only one branch at a time run the nodes behind it, e.g. when parallel branches
+/// write to the same file. Branches wait in line until the current one is done.
+///
+/// Mutexes with different names can deadlock if two branches take them in opposite order,
+/// so nested mutexes should always be taken in the same order.
+#[derive(Default)]
+pub struct MutexNode {}
+
+impl MutexNode {
+    pub fn new() -> Self {
+        MutexNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for MutexNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_mutex",
+            "Mutex",
+            "Runs the Locked branch for only one pass at a time per name within a run, other passes wait. Taking two mutexes in opposite order on different branches deadlocks",
+            "Control",
+        );
+
+        node.set_long_running(true);
+        node.add_icon("/flow/icons/sequence.svg");
+
+        node.add_input_pin("exec_in", "Execute", "Execution", VariableType::Execution);
+        node.add_input_pin(
+            "name",
+            "Name",
+            "Mutex nodes with the same name share the lock",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("default")));
+
+        node.add_output_pin(
+            "exec_out",
+            "Locked",
+            "Runs while holding the lock",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "done",
+            "Released",
+            "Fires once the Locked branch finished and the lock is released",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "waited_ms",
+            "Waited (ms)",
+            "Time spent waiting for the lock in milliseconds",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let done = context.get_pin_by_name("done").await?;
+        context.deactivate_exec_pin_ref(&done).await?;
+        let locked = context.get_pin_by_name("exec_out").await?;
+        context.deactivate_exec_pin_ref(&locked).await?;
+
+        let name: String = context.evaluate_pin("name").await?;
+        let key = format!("control_mutex_{}", name);
+        let state = context
+            .cache
+            .write()
+            .await
+            .entry(key)
+            .or_insert_with(|| {
+                Arc::new(MutexState {
+                    lock: Mutex::new(()),
+                })
+            })
+            .clone();
+        let state = state
+            .as_any()
+            .downcast_ref::<MutexState>()
+            .ok_or_else(|| anyhow!("Could not downcast mutex state"))?;
+
+        let started = Instant::now();
+        let guard = match context.cancellation_token().await {
+            Some(token) => {
+                tokio::select! {
+                    guard = state.lock.lock() => guard,
+                    _ = token.cancelled() => bail!("Run cancelled while waiting for mutex '{}'", name),
+                }
+            }
+            None => state.lock.lock().await,
+        };
+        let waited = started.elapsed();
+        if !waited.is_zero() {
+            context.log_message(
+                &format!("Mutex '{}': waited {} ms", name, waited.as_millis()),
+                LogLevel::Debug,
+            );
+        }
+        context
+            .set_pin_value("waited_ms", json!(waited.as_millis() as u64))
+            .await?;
+
+        context.activate_exec_pin_ref(&locked).await?;
+        let connected = locked.lock().await.get_connected_nodes().await;
+        let mut failed = None;
+        for node in connected.iter() {
+            let mut sub_context = context.create_sub_context(node).await;
+            let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
+            sub_context.end_trace();
+            context.push_sub_context(&mut sub_context);
+
+            if let Err(error) = run {
+                failed = Some(error);
+                break;
+            }
+        }
+        drop(guard);
+        context.deactivate_exec_pin_ref(&locked).await?;
+
+        if let Some(error) = failed {
+            bail!("Locked branch of mutex '{}' failed: {:?}", name, error);
+        }
+
+        context.activate_exec_pin_ref(&done).await?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,56 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::async_trait;
+
+/// Placed in an error handler chain (connected to a node's error pin), it marks the error
+/// as recovered. The failed node then continues with its regular successors.
+#[derive(Default)]
+pub struct RecoverNode {}
+
+impl RecoverNode {
+    pub fn new() -> Self {
+        RecoverNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for RecoverNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_recover",
+            "Recover",
+            "Marks the handled error as recovered, so the failed node continues with its normal output",
+            "Control",
+        );
+        node.add_icon("/flow/icons/workflow.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Continues the error handler chain",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        if !context.recover_error() {
+            context.log_message(
+                "Recover is not part of an error handler chain, nothing to recover",
+                LogLevel::Warn,
+            );
+        }
+
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
@@ -85,6 +85,11 @@ impl NodeLogic for SequenceNode {
                     .await;
             sub_context.end_trace();
             context.push_sub_context(&mut sub_context);
+
+            // a break or continue in an earlier branch ends the loop pass
+            if context.loop_pass_ended() {
+                break;
+            }
         }
 
         let exec_out_pins = context.get_pins_by_name("exec_out").await?;
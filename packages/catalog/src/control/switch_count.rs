@@ -0,0 +1,121 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SwitchOnCountNode {}
+
+impl SwitchOnCountNode {
+    pub fn new() -> Self {
+        SwitchOnCountNode {}
+    }
+}
+
+/// Name of the exec output to activate for an array of `count` items.
+fn count_branch(count: usize, one_max: usize) -> &'static str {
+    match count {
+        0 => "none",
+        count if count <= one_max => "one",
+        _ => "many",
+    }
+}
+
+#[async_trait]
+impl NodeLogic for SwitchOnCountNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_switch_count",
+            "Switch On Count",
+            "Branches on whether an Array has no items, one item or many items, e.g. after a search",
+            "Control",
+        );
+        node.add_icon("/flow/icons/split.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+        node.add_input_pin("array", "Array", "Array to count", VariableType::Generic)
+            .set_value_type(ValueType::Array)
+            .set_options(
+                PinOptions::new()
+                    .set_enforce_generic_value_type(true)
+                    .build(),
+            );
+        node.add_input_pin(
+            "one_max",
+            "One Up To",
+            "Largest count that still follows One, counts above follow Many",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(1)));
+
+        node.add_output_pin(
+            "none",
+            "None",
+            "The flow to follow if the Array is empty",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "one",
+            "One",
+            "The flow to follow if the Array has between 1 and One Up To items",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "many",
+            "Many",
+            "The flow to follow if the Array has more items",
+            VariableType::Execution,
+        );
+        node.add_output_pin("count", "Count", "Number of items", VariableType::Integer);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        for branch in ["none", "one", "many"] {
+            context.deactivate_exec_pin(branch).await?;
+        }
+
+        let array: Vec<Value> = context.evaluate_pin("array").await?;
+        let one_max: i64 = context.evaluate_pin("one_max").await?;
+        if one_max < 1 {
+            return Err(anyhow!("One Up To must be at least 1, got {}", one_max));
+        }
+
+        context.set_pin_value("count", json!(array.len())).await?;
+        context
+            .activate_exec_pin(count_branch(array.len(), one_max as usize))
+            .await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type(
+            "array",
+            board,
+            Some(ValueType::Array),
+            Some(ValueType::Array),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_branch;
+
+    #[test]
+    fn picks_branch_by_count() {
+        assert_eq!(count_branch(0, 1), "none");
+        assert_eq!(count_branch(1, 1), "one");
+        assert_eq!(count_branch(2, 1), "many");
+        assert_eq!(count_branch(3, 3), "one");
+    }
+}
@@ -0,0 +1,154 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Cacheable, anyhow, async_trait, bail,
+    json::json,
+    sync::Mutex,
+    tokio::{
+        self,
+        time::{Duration, Instant},
+    },
+};
+use std::sync::Arc;
+
+/// Token bucket shared by all passes of one throttle node within a run.
+struct ThrottleState {
+    bucket: Mutex<Option<(f64, Instant)>>,
+}
+
+impl Cacheable for ThrottleState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct ThrottleNode {}
+
+impl ThrottleNode {
+    pub fn new() -> Self {
+        ThrottleNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ThrottleNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_throttle",
+            "Throttle",
+            "Enforces a minimum interval between passes by waiting if the previous pass was too recent, e.g. for rate-limited APIs in loops",
+            "Control",
+        );
+
+        node.set_long_running(true);
+        node.add_icon("/flow/icons/clock.svg");
+
+        node.add_input_pin("exec_in", "Execute", "Execution", VariableType::Execution);
+        node.add_input_pin(
+            "min_interval_ms",
+            "Min Interval (ms)",
+            "Minimum time between two passes in milliseconds",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(1000)));
+        node.add_input_pin(
+            "burst",
+            "Burst",
+            "Number of passes allowed back to back before throttling kicks in",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(1)));
+
+        node.add_output_pin("exec_out", "Done", "Execution", VariableType::Execution);
+        node.add_output_pin(
+            "waited_ms",
+            "Waited (ms)",
+            "Time this pass was held back in milliseconds",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let min_interval_ms: i64 = context.evaluate_pin("min_interval_ms").await?;
+        let burst: i64 = context.evaluate_pin("burst").await?;
+        let interval = Duration::from_millis(min_interval_ms.max(0) as u64);
+        let burst = burst.max(1) as f64;
+
+        let key = format!("control_throttle_{}", context.id);
+        let state = context
+            .cache
+            .write()
+            .await
+            .entry(key)
+            .or_insert_with(|| {
+                Arc::new(ThrottleState {
+                    bucket: Mutex::new(None),
+                })
+            })
+            .clone();
+        let state = state
+            .as_any()
+            .downcast_ref::<ThrottleState>()
+            .ok_or_else(|| anyhow!("Could not downcast throttle state"))?;
+
+        // the lock is held while waiting, so concurrent passes queue up behind each other
+        let mut bucket = state.bucket.lock().await;
+        let now = Instant::now();
+        let (mut tokens, last) = bucket.unwrap_or((burst, now));
+        if !interval.is_zero() {
+            tokens = (tokens + now.duration_since(last).as_secs_f64() / interval.as_secs_f64())
+                .min(burst);
+        } else {
+            tokens = burst;
+        }
+
+        let wait = if tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            interval.mul_f64(1.0 - tokens)
+        };
+
+        if !wait.is_zero() {
+            context.log_message(
+                &format!("Throttle: waiting {} ms", wait.as_millis()),
+                LogLevel::Debug,
+            );
+
+            match context.cancellation_token().await {
+                Some(token) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep(wait) => {}
+                        _ = token.cancelled() => bail!("Run cancelled while throttling"),
+                    }
+                }
+                None => tokio::time::sleep(wait).await,
+            }
+            tokens = 1.0;
+        }
+
+        *bucket = Some((tokens - 1.0, Instant::now()));
+        drop(bucket);
+
+        context
+            .set_pin_value("waited_ms", json!(wait.as_millis() as u64))
+            .await?;
+        context.activate_exec_pin("exec_out").await?;
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,111 @@
+use ahash::AHashSet;
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+
+/// Runs everything reachable from `try` to completion before continuing. If any node in that
+/// subgraph fails and its own error pin does not recover, `catch` fires with the error.
+///
+/// Nesting: an inner Try/Catch inside the `try` subgraph catches its own errors first and
+/// counts as succeeded for the outer one. Only errors raised in the inner `catch` chain
+/// reach the outer Try/Catch.
+#[derive(Default)]
+pub struct TryCatchNode {}
+
+impl TryCatchNode {
+    pub fn new() -> Self {
+        TryCatchNode {}
+    }
+}
+
+fn last_error_message(context: &mut ExecutionContext) -> Option<String> {
+    context
+        .take_traces()
+        .iter()
+        .rev()
+        .flat_map(|trace| trace.logs.iter().rev())
+        .find(|log| log.log_level == LogLevel::Error)
+        .map(|log| log.message.clone())
+}
+
+#[async_trait]
+impl NodeLogic for TryCatchNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_try_catch",
+            "Try / Catch",
+            "Runs the Try branch and fires Catch if anything in it failed",
+            "Control",
+        );
+        node.add_icon("/flow/icons/workflow.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        node.add_output_pin(
+            "try",
+            "Try",
+            "Branch whose errors are caught",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "catch",
+            "Catch",
+            "Fires if a node in the Try branch failed",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "error",
+            "Error",
+            "Message of the error caught in the Try branch",
+            VariableType::String,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let try_pin = context.get_pin_by_name("try").await?;
+        let catch_pin = context.get_pin_by_name("catch").await?;
+        context.deactivate_exec_pin_ref(&catch_pin).await?;
+        context.set_pin_value("error", json!("")).await?;
+
+        let id = context.read_node().await.id.clone();
+        let recursion_guard = AHashSet::from_iter(vec![id]);
+        let connected = try_pin.lock().await.get_connected_nodes().await;
+
+        context.activate_exec_pin_ref(&try_pin).await?;
+        let mut caught = None;
+        for node in connected.iter() {
+            let mut sub_context = context.create_sub_context(node).await;
+            let run =
+                InternalNode::trigger(&mut sub_context, &mut Some(recursion_guard.clone()), true)
+                    .await;
+            sub_context.end_trace();
+
+            if let Err(error) = run {
+                caught =
+                    Some(last_error_message(&mut sub_context).unwrap_or(format!("{:?}", error)));
+            }
+
+            context.push_sub_context(&mut sub_context);
+            if caught.is_some() {
+                break;
+            }
+        }
+        context.deactivate_exec_pin_ref(&try_pin).await?;
+
+        if let Some(error) = caught {
+            context.log_message(&format!("Caught error: {}", error), LogLevel::Debug);
+            context.set_pin_value("error", json!(error)).await?;
+            context.activate_exec_pin_ref(&catch_pin).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    async_trait, bail, create_id,
+    json::json,
+    tokio::{self, time::Duration},
+};
+use std::sync::Arc;
+
+/// Pauses the run until an external signal arrives, e.g. an approval from a human or a
+/// webhook call. The node hands out a token, whoever holds it resumes the run through
+/// [`FlowLikeState::send_signal`] and passes a payload along.
+#[derive(Default)]
+pub struct WaitForSignalNode {}
+
+impl WaitForSignalNode {
+    pub fn new() -> Self {
+        WaitForSignalNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for WaitForSignalNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "control_wait_for_signal",
+            "Wait For Signal",
+            "Pauses execution until an external signal with the emitted token arrives",
+            "Control/Flow",
+        );
+
+        node.set_long_running(true);
+        node.add_icon("/flow/icons/clock.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+        node.add_input_pin(
+            "timeout_ms",
+            "Timeout (ms)",
+            "How long to wait for the signal, 0 waits until the run is cancelled",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(0)));
+
+        node.add_output_pin(
+            "exec_waiting",
+            "Waiting",
+            "Runs once the token is issued, before pausing. Use it to hand the token out, e.g. in a notification",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "token",
+            "Token",
+            "Token the signal has to be sent with",
+            VariableType::String,
+        );
+        node.add_output_pin(
+            "exec_out",
+            "Received",
+            "Fires once the signal arrived",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "payload",
+            "Payload",
+            "Value sent along with the signal",
+            VariableType::Generic,
+        );
+        node.add_output_pin(
+            "timed_out",
+            "Timed Out",
+            "Fires when no signal arrived in time. If not connected, the node fails instead",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        context.deactivate_exec_pin("timed_out").await?;
+        let waiting = context.get_pin_by_name("exec_waiting").await?;
+        context.deactivate_exec_pin_ref(&waiting).await?;
+
+        let timeout_ms: i64 = context.evaluate_pin("timeout_ms").await?;
+        let token = create_id();
+        let app_state = context.app_state.clone();
+        let receiver = app_state.lock().await.register_signal(&token);
+        context.set_pin_value("token", json!(token)).await?;
+
+        context.activate_exec_pin_ref(&waiting).await?;
+        let connected = waiting.lock().await.get_connected_nodes().await;
+        for node in connected.iter() {
+            let mut sub_context = context.create_sub_context(node).await;
+            let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
+            sub_context.end_trace();
+            context.push_sub_context(&mut sub_context);
+
+            if let Err(error) = run {
+                app_state.lock().await.remove_signal(&token);
+                bail!("Waiting branch failed: {:?}", error);
+            }
+        }
+        context.deactivate_exec_pin_ref(&waiting).await?;
+
+        context.log_message(&format!("Waiting for signal {}", token), LogLevel::Debug);
+        let cancelled = async {
+            match context.cancellation_token().await {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let timeout = async {
+            if timeout_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(timeout_ms as u64)).await
+            } else {
+                std::future::pending().await
+            }
+        };
+
+        let payload = tokio::select! {
+            payload = receiver => payload.ok(),
+            _ = cancelled => {
+                app_state.lock().await.remove_signal(&token);
+                bail!("Run cancelled while waiting for signal {}", token);
+            }
+            _ = timeout => None,
+        };
+
+        let Some(payload) = payload else {
+            app_state.lock().await.remove_signal(&token);
+            let timed_out = context.get_pin_by_name("timed_out").await?;
+            if timed_out
+                .lock()
+                .await
+                .get_connected_nodes()
+                .await
+                .is_empty()
+            {
+                bail!("No signal arrived for {} within {} ms", token, timeout_ms);
+            }
+            context.activate_exec_pin_ref(&timed_out).await?;
+            return Ok(());
+        };
+
+        context.set_pin_value("payload", payload).await?;
+        context.activate_exec_pin("exec_out").await?;
+        return Ok(());
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("payload", board, Some(ValueType::Normal), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WaitForSignalNode;
+    use flow_like::{
+        flow::{
+            execution::{context::ExecutionContext, test_board::TestBoard},
+            node::{Node, NodeLogic},
+            variable::VariableType,
+        },
+        state::FlowLikeState,
+    };
+    use flow_like_types::{Value, async_trait, json::json, tokio};
+    use std::sync::{Arc, Mutex};
+
+    /// Sends `approved` to the token it receives, standing in for an external caller.
+    struct SendSignalNode;
+
+    #[async_trait]
+    impl NodeLogic for SendSignalNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_send_signal", "Send Signal", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_input_pin("token", "Token", "", VariableType::String);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            let token: String = context.evaluate_pin("token").await?;
+            context
+                .app_state
+                .lock()
+                .await
+                .send_signal(&token, json!("approved"))
+        }
+    }
+
+    /// Records the value it receives on every run.
+    #[derive(Default)]
+    struct RecordNode {
+        values: Mutex<Vec<Value>>,
+    }
+
+    #[async_trait]
+    impl NodeLogic for RecordNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_record", "Record", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_input_pin("value", "Value", "", VariableType::Generic)
+                .set_default_value(Some(json!(null)));
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            let value: Value = context.evaluate_pin("value").await?;
+            self.values.lock().unwrap().push(value);
+            Ok(())
+        }
+    }
+
+    async fn wait_board(record: &Arc<RecordNode>, timeout_ms: i64) -> (TestBoard, String) {
+        let mut board = TestBoard::new(vec![
+            Arc::new(WaitForSignalNode::new()),
+            Arc::new(SendSignalNode),
+            record.clone(),
+        ])
+        .await;
+        let wait = board.add(&WaitForSignalNode::new()).await;
+        board.set_input(&wait, "timeout_ms", json!(timeout_ms));
+        (board, wait)
+    }
+
+    async fn pending_signals(board: &TestBoard) -> usize {
+        board.state.lock().await.signal_registry.len()
+    }
+
+    #[tokio::test]
+    async fn signal_resumes_the_run_with_its_payload() {
+        let record = Arc::new(RecordNode::default());
+        let (mut board, wait) = wait_board(&record, 5_000).await;
+        let send = board.add(&SendSignalNode).await;
+        let received = board.add(record.as_ref()).await;
+        board.connect(&wait, "exec_waiting", &send, "exec_in");
+        board.connect(&wait, "token", &send, "token");
+        board.connect(&wait, "exec_out", &received, "exec_in");
+        board.connect(&wait, "payload", &received, "value");
+        board.run(&wait).await;
+
+        assert_eq!(*record.values.lock().unwrap(), vec![json!("approved")]);
+        assert_eq!(pending_signals(&board).await, 0);
+    }
+
+    #[tokio::test]
+    async fn timed_out_signal_is_removed() {
+        let record = Arc::new(RecordNode::default());
+        let (mut board, wait) = wait_board(&record, 10).await;
+        let timed_out = board.add(record.as_ref()).await;
+        board.connect(&wait, "timed_out", &timed_out, "exec_in");
+        board.run(&wait).await;
+
+        assert_eq!(record.values.lock().unwrap().len(), 1);
+        assert_eq!(pending_signals(&board).await, 0);
+    }
+
+    #[tokio::test]
+    async fn timeout_without_handler_fails_and_removes_the_signal() {
+        let record = Arc::new(RecordNode::default());
+        let (board, wait) = wait_board(&record, 10).await;
+        let mut run = board.prepare(&wait).await;
+        run.execute(board.state.clone()).await;
+
+        let traces = run.get_traces().await;
+        assert!(
+            traces
+                .iter()
+                .flat_map(|trace| &trace.logs)
+                .any(|log| log.message.contains("No signal arrived"))
+        );
+        assert_eq!(pending_signals(&board).await, 0);
+    }
+}
@@ -1,6 +1,10 @@
 use flow_like::{
     flow::{
-        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, LoopSignal},
+            internal_node::InternalNode,
+        },
         node::{Node, NodeLogic},
         variable::VariableType,
     },
@@ -83,6 +87,7 @@ impl NodeLogic for WhileLoopNode {
         context.activate_exec_pin_ref(&exec_item).await?;
         let flow = exec_item.lock().await.get_connected_nodes().await;
 
+        let signal = LoopSignal::new();
         for i in 0..max_iter {
             let condition = context
                 .evaluate_pin_ref::<bool>(condition_pin.clone())
@@ -90,12 +95,14 @@ impl NodeLogic for WhileLoopNode {
             if !condition {
                 break;
             }
+            signal.next_pass();
             iter.lock()
                 .await
                 .set_value(flow_like_types::json::json!(i))
                 .await;
             for node in &flow {
                 let mut sub_context = context.create_sub_context(node).await;
+                sub_context.set_loop_signal(signal.clone());
                 let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
                 sub_context.end_trace();
                 context.push_sub_context(&mut sub_context);
@@ -107,6 +114,15 @@ impl NodeLogic for WhileLoopNode {
                         LogLevel::Error,
                     );
                 }
+
+                if signal.pass_ended() {
+                    break;
+                }
+            }
+
+            if signal.is_broken() {
+                context.log_message(&format!("Loop broken at iteration {}", i), LogLevel::Debug);
+                break;
             }
         }
 
@@ -14,16 +14,33 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(db::vector::insert::BatchInsertCSVLocalDatabaseNode::default()),
         Arc::new(db::vector::upsert::UpsertLocalDatabaseNode::default()),
         Arc::new(db::vector::upsert::BatchUpsertLocalDatabaseNode::default()),
+        Arc::new(db::vector::upsert::ConditionalUpsertLocalDatabaseNode::default()),
+        Arc::new(db::vector::upsert::LastWriteWinsUpsertLocalDatabaseNode::default()),
         Arc::new(db::vector::purge::PurgeLocalDatabaseNode::default()),
         Arc::new(db::vector::optimize::OptimizeLocalDatabaseNode::default()),
+        Arc::new(db::vector::optimize::PruneLocalDatabaseNode::default()),
+        Arc::new(db::vector::optimize::CompactLocalDatabaseNode::default()),
+        Arc::new(db::vector::optimize::ReindexLocalDatabaseNode::default()),
         Arc::new(db::vector::list::ListLocalDatabaseNode::default()),
         Arc::new(db::vector::index::IndexLocalDatabaseNode::default()),
+        Arc::new(db::vector::index_composite::CompositeIndexLocalDatabaseNode::default()),
         Arc::new(db::vector::hybrid_search::HybridSearchLocalDatabaseNode::default()),
         Arc::new(db::vector::fts_search::FTSLocalDatabaseNode::default()),
         Arc::new(db::vector::filter::FilterLocalDatabaseNode::default()),
         Arc::new(db::vector::delete::DeleteLocalDatabaseNode::default()),
         Arc::new(db::vector::count::CountLocalDatabaseNode::default()),
+        Arc::new(db::vector::distinct::DistinctCountLocalDatabaseNode::default()),
         Arc::new(db::vector::schema::GetSchemaLocalDatabaseNode::default()),
+        Arc::new(db::vector::migrate::MigrateSchemaLocalDatabaseNode::default()),
+        Arc::new(db::vector::switch_table::SwitchTableLocalDatabaseNode::default()),
+        Arc::new(db::vector::scope::ScopeLocalDatabaseNode::default()),
+        Arc::new(db::vector::sql::DatabaseSqlNode::default()),
+        Arc::new(db::vector::explain::ExplainQueryNode::default()),
+        Arc::new(db::vector::replace::ReplacePartitionLocalDatabaseNode::default()),
+        Arc::new(db::vector::copy_table::CopyTableLocalDatabaseNode::default()),
+        Arc::new(db::vector::wait_indexed::WaitIndexedLocalDatabaseNode::default()),
+        Arc::new(db::vector::paginate::PaginateLocalDatabaseNode::default()),
+        Arc::new(db::vector::open_uri::OpenDatabaseUriNode::default()),
     ];
 
     nodes.extend(path::register_functions().await);
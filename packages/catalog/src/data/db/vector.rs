@@ -14,19 +14,31 @@ use flow_like_types::{
 };
 use std::sync::Arc;
 
+pub mod copy_table;
 pub mod count;
 pub mod delete;
+pub mod distinct;
+pub mod explain;
 pub mod filter;
 pub mod fts_search;
 pub mod hybrid_search;
 pub mod index;
+pub mod index_composite;
 pub mod insert;
 pub mod list;
+pub mod migrate;
+pub mod open_uri;
 pub mod optimize;
+pub mod paginate;
 pub mod purge;
+pub mod replace;
 pub mod schema;
+pub mod scope;
+pub mod sql;
+pub mod switch_table;
 pub mod upsert;
 pub mod vector_search;
+pub mod wait_indexed;
 
 #[derive(Default, Serialize, Deserialize, JsonSchema, Clone)]
 pub struct NodeDBConnection {
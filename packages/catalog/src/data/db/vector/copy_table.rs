@@ -0,0 +1,98 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct CopyTableLocalDatabaseNode {}
+
+impl CopyTableLocalDatabaseNode {
+    pub fn new() -> Self {
+        CopyTableLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CopyTableLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "copy_table_local_db",
+            "Copy Table",
+            "Copies a table with its schema and data to a new table on the same connection, e.g. as a working copy before optimizing or altering. Indices are not copied",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "source",
+            "Source Table",
+            "Name of the Table to copy",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "destination",
+            "Destination Table",
+            "Name of the new Table",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "overwrite",
+            "Overwrite",
+            "Replace the destination if it already exists",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Copied Table",
+            "Done Copying Table",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let source: String = context.evaluate_pin("source").await?;
+        let destination: String = context.evaluate_pin("destination").await?;
+        let overwrite: bool = context.evaluate_pin("overwrite").await?;
+
+        if source.is_empty() || destination.is_empty() {
+            return Err(anyhow!("Source and destination table names are required"));
+        }
+
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        database
+            .copy_table(&source, &destination, overwrite)
+            .await?;
+        drop(database);
+
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
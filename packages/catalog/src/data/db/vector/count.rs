@@ -74,7 +74,7 @@ impl NodeLogic for CountLocalDatabaseNode {
         } else {
             Some(filter)
         };
-        let result = database.count(filter).await?;
+        let result = database.count(filter, None).await?;
         context.set_pin_value("count", json!(result)).await?;
         context.activate_exec_pin("exec_out").await?;
         Ok(())
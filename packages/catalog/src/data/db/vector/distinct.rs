@@ -0,0 +1,147 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::databases::vector::lancedb::record_batches_to_vec;
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+const TABLE_ALIAS: &str = "profiled_table";
+
+#[derive(Default)]
+pub struct DistinctCountLocalDatabaseNode {}
+
+impl DistinctCountLocalDatabaseNode {
+    pub fn new() -> Self {
+        DistinctCountLocalDatabaseNode {}
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[async_trait]
+impl NodeLogic for DistinctCountLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "distinct_count_local_db",
+            "Distinct Count",
+            "Counts the distinct values of a column and optionally returns the most frequent values, e.g. to judge if a bitmap index makes sense",
+            "Data/Database/Meta",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "column",
+            "Column",
+            "Column to profile",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "top_n",
+            "Top N",
+            "Number of most frequent values to return, 0 skips the histogram",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(0)));
+
+        node.add_input_pin(
+            "filter",
+            "SQL Filter",
+            "Optional SQL Filter",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Profiling Column",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "distinct_count",
+            "Distinct Count",
+            "Number of distinct non-null values",
+            VariableType::Integer,
+        );
+
+        node.add_output_pin(
+            "histogram",
+            "Histogram",
+            "Most frequent values as {value, count}, in descending order",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        let column: String = context.evaluate_pin("column").await?;
+        let top_n: i64 = context.evaluate_pin("top_n").await?;
+        let filter: String = context.evaluate_pin("filter").await?;
+
+        if column.is_empty() {
+            return Err(anyhow!("No column to profile"));
+        }
+
+        let column = quote_identifier(&column);
+        let where_clause = if filter.trim().is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", filter)
+        };
+
+        let sql = format!(
+            "SELECT COUNT(DISTINCT {column}) AS distinct_count FROM {TABLE_ALIAS}{where_clause}"
+        );
+        let batches = database.sql(TABLE_ALIAS, &sql).await?.collect().await?;
+        let distinct_count = record_batches_to_vec(Some(batches))?
+            .first()
+            .and_then(|row| row.get("distinct_count"))
+            .and_then(Value::as_i64)
+            .unwrap_or(0);
+
+        let histogram = if top_n > 0 {
+            let sql = format!(
+                "SELECT {column} AS value, COUNT(*) AS count FROM {TABLE_ALIAS}{where_clause} GROUP BY {column} ORDER BY count DESC LIMIT {top_n}"
+            );
+            let batches = database.sql(TABLE_ALIAS, &sql).await?.collect().await?;
+            record_batches_to_vec(Some(batches))?
+        } else {
+            vec![]
+        };
+
+        context
+            .set_pin_value("distinct_count", json!(distinct_count))
+            .await?;
+        context.set_pin_value("histogram", json!(histogram)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct ExplainQueryNode {}
+
+impl ExplainQueryNode {
+    pub fn new() -> Self {
+        ExplainQueryNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ExplainQueryNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "explain_local_db",
+            "Explain Query",
+            "Shows the plan of a SQL query, e.g. to check whether a filter uses an index or scans the whole table",
+            "Data/Database/Optimization",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "table_alias",
+            "Table Alias",
+            "Name the table is referenced by in the query",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("data")));
+
+        node.add_input_pin(
+            "sql",
+            "SQL",
+            "Query to explain, e.g. SELECT * FROM data WHERE id = 1",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("SELECT * FROM data LIMIT 10")));
+
+        node.add_input_pin(
+            "analyze",
+            "Analyze",
+            "Run the query and include the measured metrics in the plan",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin("plan", "Plan", "Query Plan", VariableType::String);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        let table_alias: String = context.evaluate_pin("table_alias").await?;
+        let sql: String = context.evaluate_pin("sql").await?;
+        let analyze: bool = context.evaluate_pin("analyze").await?;
+
+        if table_alias.trim().is_empty() {
+            return Err(anyhow!("Table alias must not be empty"));
+        }
+
+        let plan = database
+            .explain(&table_alias, &sql, analyze)
+            .await
+            .map_err(|e| anyhow!("Failed to explain SQL query: {}", e))?;
+
+        context.set_pin_value("plan", json!(plan)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
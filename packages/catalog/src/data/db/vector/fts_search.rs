@@ -63,6 +63,14 @@ impl NodeLogic for FTSLocalDatabaseNode {
         node.add_input_pin("offset", "Offset", "Offset", VariableType::Integer)
             .set_default_value(Some(json!(0)));
 
+        node.add_input_pin(
+            "with_row_id",
+            "With Row ID",
+            "Include Lance's stable _rowid in every item, e.g. for a Delete filter like _rowid = 42",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
         node.add_output_pin(
             "exec_out",
             "Created Database",
@@ -90,9 +98,15 @@ impl NodeLogic for FTSLocalDatabaseNode {
         let limit: i64 = context.evaluate_pin("limit").await?;
         let offset: i64 = context.evaluate_pin("offset").await?;
         let database = database.load(context).await?.db.clone();
-        let database = database.read().await;
+        let cancellation = context.cancellation_token().await;
+        let with_row_id: bool = context.evaluate_pin("with_row_id").await?;
+        let database = database
+            .read()
+            .await
+            .with_cancellation(cancellation)
+            .with_row_id(with_row_id);
         let results = database
-            .fts_search(&search, filter, None, limit as usize, offset as usize)
+            .fts_search(&search, filter, None, limit as usize, offset as usize, None)
             .await?;
         context.set_pin_value("values", json!(results)).await?;
         context.activate_exec_pin("exec_out").await?;
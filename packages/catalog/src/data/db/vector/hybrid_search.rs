@@ -7,7 +7,7 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_storage::databases::vector::VectorStore;
+use flow_like_storage::databases::vector::{VectorStore, lancedb::DEFAULT_RRF_K};
 use flow_like_types::{async_trait, json::json};
 
 use super::NodeDBConnection;
@@ -66,12 +66,28 @@ impl NodeLogic for HybridSearchLocalDatabaseNode {
         )
         .set_default_value(Some(json!(true)));
 
+        node.add_input_pin(
+            "rrf_k",
+            "RRF k",
+            "Reciprocal Rank Fusion constant used when re-ranking, lower values favor top ranks more",
+            VariableType::Float,
+        )
+        .set_default_value(Some(json!(DEFAULT_RRF_K)));
+
         node.add_input_pin("limit", "Limit", "Limit", VariableType::Integer)
             .set_default_value(Some(json!(10)));
 
         node.add_input_pin("offset", "Offset", "Offset", VariableType::Integer)
             .set_default_value(Some(json!(0)));
 
+        node.add_input_pin(
+            "with_row_id",
+            "With Row ID",
+            "Include Lance's stable _rowid in every item, e.g. for a Delete filter like _rowid = 42",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
         node.add_output_pin(
             "exec_out",
             "Created Database",
@@ -100,8 +116,15 @@ impl NodeLogic for HybridSearchLocalDatabaseNode {
         let limit: i64 = context.evaluate_pin("limit").await?;
         let offset: i64 = context.evaluate_pin("offset").await?;
         let rerank: bool = context.evaluate_pin("rerank").await?;
+        let rrf_k: f64 = context.evaluate_pin("rrf_k").await?;
         let database = database.load(context).await?.db.clone();
-        let database = database.read().await;
+        let cancellation = context.cancellation_token().await;
+        let with_row_id: bool = context.evaluate_pin("with_row_id").await?;
+        let database = database
+            .read()
+            .await
+            .with_cancellation(cancellation)
+            .with_row_id(with_row_id);
         let results = database
             .hybrid_search(
                 vector,
@@ -111,6 +134,8 @@ impl NodeLogic for HybridSearchLocalDatabaseNode {
                 limit as usize,
                 offset as usize,
                 rerank,
+                Some(rrf_k as f32),
+                None,
             )
             .await?;
         context.set_pin_value("values", json!(results)).await?;
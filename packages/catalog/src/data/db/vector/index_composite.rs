@@ -0,0 +1,87 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::databases::vector::VectorStore;
+use flow_like_types::{async_trait, json::json};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct CompositeIndexLocalDatabaseNode {}
+
+impl CompositeIndexLocalDatabaseNode {
+    pub fn new() -> Self {
+        CompositeIndexLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CompositeIndexLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "index_composite_local_db",
+            "Build Composite Index",
+            "Builds one index over several columns, for filters combining them (BTREE) or full text search across several text columns (FULL TEXT)",
+            "Data/Database/Optimization",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "columns",
+            "Columns",
+            "Columns to Index",
+            VariableType::String,
+        )
+        .set_value_type(ValueType::Array)
+        .set_default_value(Some(json!([])));
+        node.add_input_pin("type", "Type", "Index Type to build", VariableType::String)
+            .set_options(
+                PinOptions::new()
+                    .set_valid_values(vec!["BTREE".to_string(), "FULL TEXT".to_string()])
+                    .build(),
+            )
+            .set_default_value(Some(json!("BTREE")));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done Building Index",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let index_type: String = context.evaluate_pin("type").await?;
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        let columns: Vec<String> = context.evaluate_pin("columns").await?;
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        database
+            .index_composite(&columns, Some(&index_type))
+            .await?;
+
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
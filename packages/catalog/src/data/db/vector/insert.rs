@@ -64,6 +64,7 @@ impl NodeLogic for InsertLocalDatabaseNode {
         let mut database = database.write().await;
         let value: Value = context.evaluate_pin("value").await?;
         let value = vec![value];
+        database.validate_records(&value).await?;
         database.insert(value).await?;
 
         context.activate_exec_pin("exec_out").await?;
@@ -121,6 +122,7 @@ impl NodeLogic for BatchInsertLocalDatabaseNode {
         let database = database.load(context).await?.db.clone();
         let mut database = database.write().await;
         let value: Vec<Value> = context.evaluate_pin("value").await?;
+        database.validate_records(&value).await?;
         database.insert(value).await?;
 
         context.activate_exec_pin("exec_out").await?;
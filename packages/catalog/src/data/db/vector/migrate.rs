@@ -0,0 +1,132 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::arrow_schema::Schema;
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+/// # Migrate Schema
+/// Declaratively converges a table towards a target schema, only applying safe changes
+#[derive(Default)]
+pub struct MigrateSchemaLocalDatabaseNode {}
+
+impl MigrateSchemaLocalDatabaseNode {
+    pub fn new() -> Self {
+        MigrateSchemaLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for MigrateSchemaLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "migrate_schema_local_db",
+            "Migrate Schema",
+            "Adds, widens or relaxes columns until the table matches the target schema. Unsafe migrations fail without changing anything",
+            "Data/Database/Meta",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "schema",
+            "Target Schema",
+            "Schema the table should have, in the format of Get Schema",
+            VariableType::Struct,
+        );
+
+        node.add_input_pin(
+            "allow_destructive",
+            "Allow Destructive",
+            "Drop columns missing from the target schema, including their data",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_input_pin(
+            "dry_run",
+            "Dry Run",
+            "Only report the changes without applying them",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Migrating Schema",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "changes",
+            "Changes",
+            "Applied (or planned) changes",
+            VariableType::String,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "changed",
+            "Changed",
+            "True if the schema differed from the target",
+            VariableType::Boolean,
+        );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let schema: Value = context.evaluate_pin("schema").await?;
+        let allow_destructive: bool = context.evaluate_pin("allow_destructive").await?;
+        let dry_run: bool = context.evaluate_pin("dry_run").await?;
+
+        let target: Schema = flow_like_types::json::from_value(schema)
+            .map_err(|e| anyhow!("Invalid target schema: {}", e))?;
+
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        let changes = database
+            .migrate_schema(&target, allow_destructive, dry_run)
+            .await?;
+        drop(database);
+
+        let changes: Vec<String> = changes.iter().map(ToString::to_string).collect();
+        for change in &changes {
+            context.log_message(
+                &format!(
+                    "{}: {}",
+                    if dry_run { "Planned" } else { "Applied" },
+                    change
+                ),
+                LogLevel::Debug,
+            );
+        }
+
+        context
+            .set_pin_value("changed", json!(!changes.is_empty()))
+            .await?;
+        context.set_pin_value("changes", json!(changes)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
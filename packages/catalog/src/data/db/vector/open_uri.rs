@@ -0,0 +1,133 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        utils::stable_hash,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::databases::vector::lancedb::LanceDBVectorStore;
+use flow_like_types::{Cacheable, Value, anyhow, async_trait, json::json, sync::RwLock};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use super::{CachedDB, NodeDBConnection};
+
+#[derive(Default)]
+pub struct OpenDatabaseUriNode {}
+
+impl OpenDatabaseUriNode {
+    pub fn new() -> Self {
+        OpenDatabaseUriNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for OpenDatabaseUriNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "open_db_uri",
+            "Open Database (URI)",
+            "Opens a database at a local path or object store URI like s3://bucket/db. A missing table is created on the first write",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "uri",
+            "URI",
+            "Location of the database, e.g. /data/db or s3://bucket/db",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "name",
+            "Table Name",
+            "Name of the Table",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "storage_options",
+            "Storage Options",
+            "Object store options, e.g. region, endpoint or aws_access_key_id",
+            VariableType::String,
+        )
+        .set_value_type(ValueType::HashMap)
+        .set_default_value(Some(json!({})));
+
+        node.add_input_pin(
+            "read_only",
+            "Read Only",
+            "Reject inserts, deletes, schema changes and optimizing, e.g. for query-only flows",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Opened Database",
+            "Done Opening Database",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let uri: String = context.evaluate_pin("uri").await?;
+        let table: String = context.evaluate_pin("name").await?;
+        let storage_options: HashMap<String, String> =
+            context.evaluate_pin("storage_options").await?;
+        let read_only: bool = context.evaluate_pin("read_only").await?;
+
+        if uri.is_empty() || table.is_empty() {
+            return Err(anyhow!("URI and table name are required"));
+        }
+
+        // storage options may hold credentials, so only their hash is part of the key
+        let mut cache_key = format!("db_uri_{}/{}", uri, table);
+        if !storage_options.is_empty() {
+            let options_hash = stable_hash(&json!(storage_options))?;
+            cache_key.push_str(&format!("#{}", options_hash));
+        }
+        if read_only {
+            cache_key.push_str("?read_only");
+        }
+        let cache_set = context.cache.read().await.contains_key(&cache_key);
+        if !cache_set {
+            let storage_options = Some(storage_options).filter(|options| !options.is_empty());
+            let db = LanceDBVectorStore::new(PathBuf::from(&uri), table, storage_options)
+                .await?
+                .with_read_only(read_only);
+            let intermediate = CachedDB {
+                db: Arc::new(RwLock::new(db)),
+            };
+            let cacheable: Arc<dyn Cacheable> = Arc::new(intermediate);
+            context
+                .cache
+                .write()
+                .await
+                .insert(cache_key.clone(), cacheable);
+        }
+
+        let db = NodeDBConnection { cache_key };
+        let db: Value = flow_like_types::json::to_value(&db)?;
+
+        context.set_pin_value("database", db).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
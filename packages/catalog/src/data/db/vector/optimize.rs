@@ -9,6 +9,7 @@ use flow_like::{
 };
 use flow_like_storage::databases::vector::VectorStore;
 use flow_like_types::{async_trait, json::json};
+use std::time::Duration;
 
 use super::NodeDBConnection;
 
@@ -73,3 +74,141 @@ impl NodeLogic for OptimizeLocalDatabaseNode {
         Ok(())
     }
 }
+
+fn add_database_pins(node: &mut Node) {
+    node.set_long_running(true);
+    node.add_icon("/flow/icons/database.svg");
+
+    node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+    node.add_input_pin(
+        "database",
+        "Database",
+        "Database Connection Reference",
+        VariableType::Struct,
+    )
+    .set_schema::<NodeDBConnection>()
+    .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+    node.add_output_pin(
+        "exec_out",
+        "Done",
+        "Done Optimizing",
+        VariableType::Execution,
+    );
+}
+
+#[derive(Default)]
+pub struct PruneLocalDatabaseNode {}
+
+impl PruneLocalDatabaseNode {
+    pub fn new() -> Self {
+        PruneLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for PruneLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "prune_local_db",
+            "Prune Versions",
+            "Deletes table versions older than the given age",
+            "Data/Database/Optimization",
+        );
+        add_database_pins(&mut node);
+        node.add_input_pin(
+            "older_than",
+            "Older Than (s)",
+            "Minimum age of removed versions in seconds",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(604800)));
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let older_than: i64 = context.evaluate_pin("older_than").await?;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        database
+            .prune(Some(Duration::from_secs(older_than.max(0) as u64)))
+            .await?;
+
+        context.activate_exec_pin("exec_out").await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct CompactLocalDatabaseNode {}
+
+impl CompactLocalDatabaseNode {
+    pub fn new() -> Self {
+        CompactLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CompactLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "compact_local_db",
+            "Compact",
+            "Merges small data files, cheap enough to run after every larger write",
+            "Data/Database/Optimization",
+        );
+        add_database_pins(&mut node);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        database.read().await.compact().await?;
+
+        context.activate_exec_pin("exec_out").await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct ReindexLocalDatabaseNode {}
+
+impl ReindexLocalDatabaseNode {
+    pub fn new() -> Self {
+        ReindexLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ReindexLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "reindex_local_db",
+            "Reindex",
+            "Adds rows written since the last index update to all indices, the expensive part of optimizing",
+            "Data/Database/Optimization",
+        );
+        add_database_pins(&mut node);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        database.read().await.reindex().await?;
+
+        context.activate_exec_pin("exec_out").await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct PaginateLocalDatabaseNode {}
+
+impl PaginateLocalDatabaseNode {
+    pub fn new() -> Self {
+        PaginateLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for PaginateLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "paginate_local_db",
+            "Next Page",
+            "Emits the next page of the table on every execution and Done once every page was emitted, e.g. to walk a large table without loading it into memory by looping back into Input. Rows written or deleted in between don't shift the pages",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "filter",
+            "Filter",
+            "Optional SQL filter, empty walks every row",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_input_pin(
+            "page_size",
+            "Page Size",
+            "Rows per page",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(100)));
+
+        node.add_output_pin(
+            "exec_out",
+            "On Page",
+            "Executes with the next page",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "page",
+            "Page",
+            "Rows of the current page",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "page_index",
+            "Page Index",
+            "Index of the current page",
+            VariableType::Integer,
+        );
+
+        node.add_output_pin(
+            "done",
+            "Done",
+            "Executes on the first execution after the last page, the next execution starts over",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        context.deactivate_exec_pin("done").await?;
+
+        // cursor of the next page, the index of it and whether the last page was emitted
+        let state = context.run_state().await?;
+        let mut state = state.lock().await;
+        if state["exhausted"].as_bool().unwrap_or(false) {
+            *state = Value::Null;
+            context.activate_exec_pin("done").await?;
+            return Ok(());
+        }
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let filter: String = context.evaluate_pin("filter").await?;
+        let page_size: i64 = context.evaluate_pin("page_size").await?;
+        if page_size < 1 {
+            return Err(anyhow!("Page size must be at least 1, got {}", page_size));
+        }
+
+        let cancellation = context.cancellation_token().await;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await.with_cancellation(cancellation);
+        let filter = Some(filter.as_str()).filter(|filter| !filter.trim().is_empty());
+
+        let cursor = state["cursor"].as_u64();
+        let index = state["index"].as_i64().unwrap_or(0);
+        let (items, next) = database
+            .scan_page(cursor, filter, page_size as usize)
+            .await?;
+        if items.is_empty() {
+            *state = Value::Null;
+            context.activate_exec_pin("done").await?;
+            return Ok(());
+        }
+
+        *state = json!({
+            "cursor": next,
+            "index": index + 1,
+            "exhausted": next.is_none(),
+        });
+        drop(state);
+
+        context.set_pin_value("page", json!(items)).await?;
+        context.set_pin_value("page_index", json!(index)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
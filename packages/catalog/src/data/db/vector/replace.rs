@@ -0,0 +1,90 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::databases::vector::VectorStore;
+use flow_like_types::{Value, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+/// Deletes the rows matching the filter and inserts the new rows afterwards.
+/// Not transactional, readers in between see the partition empty.
+#[derive(Default)]
+pub struct ReplacePartitionLocalDatabaseNode {}
+
+impl ReplacePartitionLocalDatabaseNode {
+    pub fn new() -> Self {
+        ReplacePartitionLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ReplacePartitionLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "replace_where_local_db",
+            "Replace Partition",
+            "Replaces all rows matching the filter with new rows, e.g. a daily snapshot. Deletes first and inserts afterwards, this is not atomic",
+            "Data/Database/Insert",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "filter",
+            "SQL Filter",
+            "Selects the partition to replace, e.g. day = '2024-01-01'",
+            VariableType::String,
+        );
+
+        node.add_input_pin("value", "Value", "New rows", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done replacing the partition",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "inserted",
+            "Inserted",
+            "Number of inserted rows",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let mut database = database.write().await;
+        let filter: String = context.evaluate_pin("filter").await?;
+        let value: Vec<Value> = context.evaluate_pin("value").await?;
+        let inserted = value.len();
+
+        database.replace_where(&filter, value).await?;
+
+        context.set_pin_value("inserted", json!(inserted)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
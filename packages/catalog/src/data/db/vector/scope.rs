@@ -0,0 +1,110 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        utils::stable_hash,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Cacheable, Value, anyhow, async_trait, json::json, sync::RwLock};
+use std::sync::Arc;
+
+use super::{CachedDB, NodeDBConnection};
+
+#[derive(Default)]
+pub struct ScopeLocalDatabaseNode {}
+
+impl ScopeLocalDatabaseNode {
+    pub fn new() -> Self {
+        ScopeLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ScopeLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "scope_local_db",
+            "Scope Database",
+            "Restricts the connection to rows matching a filter, e.g. the rows of one tenant. Searches, filters, counts, deletes and SQL only see these rows, raw table access and copying tables are rejected",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "filter",
+            "Scope Filter",
+            "SQL filter every query is restricted to, e.g. tenant_id = 'acme'. A scoped connection can only be narrowed further",
+            VariableType::String,
+        );
+
+        node.add_output_pin(
+            "exec_out",
+            "Scoped Database",
+            "Done Scoping Database",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "database",
+            "Database",
+            "Database Connection Reference restricted to the Scope",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let filter: String = context.evaluate_pin("filter").await?;
+
+        if filter.trim().is_empty() {
+            return Err(anyhow!("No scope filter provided"));
+        }
+
+        // the filter may name a tenant or user, so only its hash is part of the key
+        let cache_key = format!(
+            "{}?scope#{}",
+            database.cache_key,
+            stable_hash(&json!(filter))?
+        );
+        let cache_set = context.cache.read().await.contains_key(&cache_key);
+        if !cache_set {
+            let source = database.load(context).await?.db.clone();
+            let scoped = source.read().await.with_scope_filter(Some(&filter))?;
+
+            let intermediate = CachedDB {
+                db: Arc::new(RwLock::new(scoped)),
+            };
+            let cacheable: Arc<dyn Cacheable> = Arc::new(intermediate);
+            context
+                .cache
+                .write()
+                .await
+                .insert(cache_key.clone(), cacheable);
+        }
+
+        let db = NodeDBConnection { cache_key };
+        let db: Value = flow_like_types::json::to_value(&db)?;
+
+        context.set_pin_value("database", db).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
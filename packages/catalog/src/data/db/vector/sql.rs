@@ -0,0 +1,116 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::{
+    databases::vector::lancedb::record_batches_to_vec, datafusion::execution::context::SQLOptions,
+};
+use flow_like_types::{anyhow, async_trait, json::json};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct DatabaseSqlNode {}
+
+impl DatabaseSqlNode {
+    pub fn new() -> Self {
+        DatabaseSqlNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for DatabaseSqlNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "sql_local_db",
+            "SQL Query",
+            "Runs a SQL query against the table, which is available under the alias. Only queries are allowed unless mutations are enabled",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "table_alias",
+            "Table Alias",
+            "Name the table is referenced by in the query",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("data")));
+
+        node.add_input_pin(
+            "sql",
+            "SQL",
+            "Query, e.g. SELECT category, COUNT(*) FROM data GROUP BY category",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("SELECT * FROM data LIMIT 10")));
+
+        node.add_input_pin(
+            "allow_mutations",
+            "Allow Mutations",
+            "Allow statements that create or modify data, not only queries",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin("rows", "Rows", "Result Rows", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let database = database.read().await;
+        let table_alias: String = context.evaluate_pin("table_alias").await?;
+        let sql: String = context.evaluate_pin("sql").await?;
+        let allow_mutations: bool = context.evaluate_pin("allow_mutations").await?;
+
+        if table_alias.trim().is_empty() {
+            return Err(anyhow!("Table alias must not be empty"));
+        }
+
+        let options = SQLOptions::new()
+            .with_allow_ddl(allow_mutations)
+            .with_allow_dml(allow_mutations)
+            .with_allow_statements(allow_mutations);
+
+        let batches = database
+            .sql_with_options(&table_alias, &sql, options)
+            .await
+            .map_err(|e| anyhow!("Failed to plan SQL query: {}", e))?
+            .collect()
+            .await
+            .map_err(|e| anyhow!("Failed to execute SQL query: {}", e))?;
+        let rows = record_batches_to_vec(Some(batches))?;
+
+        context.set_pin_value("rows", json!(rows)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
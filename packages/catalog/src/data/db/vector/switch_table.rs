@@ -0,0 +1,117 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Cacheable, Value, anyhow, async_trait, json::json, sync::RwLock};
+use std::sync::Arc;
+
+use super::{CachedDB, NodeDBConnection};
+
+#[derive(Default)]
+pub struct SwitchTableLocalDatabaseNode {}
+
+impl SwitchTableLocalDatabaseNode {
+    pub fn new() -> Self {
+        SwitchTableLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for SwitchTableLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "switch_table_local_db",
+            "Switch Table",
+            "Targets another table on the same database connection, e.g. a per-tenant table chosen at runtime",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "table_name",
+            "Table Name",
+            "Name of the Table to switch to",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "allow_create",
+            "Allow Create",
+            "Allow a missing table, it is created on the first write",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Switched Table",
+            "Done Switching Table",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "database",
+            "Database",
+            "Database Connection Reference for the Table",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let table_name: String = context.evaluate_pin("table_name").await?;
+        let allow_create: bool = context.evaluate_pin("allow_create").await?;
+
+        if table_name.is_empty() {
+            return Err(anyhow!("No table name provided"));
+        }
+
+        let cache_key = format!("{}/{}", database.cache_key, table_name);
+        let cache_set = context.cache.read().await.contains_key(&cache_key);
+        if !cache_set {
+            let source = database.load(context).await?.db.clone();
+            let source = source.read().await;
+
+            if !allow_create && !source.list_tables().await?.contains(&table_name) {
+                return Err(anyhow!("Table '{}' does not exist", table_name));
+            }
+
+            let intermediate = CachedDB {
+                db: Arc::new(RwLock::new(source.for_table(table_name).await)),
+            };
+            let cacheable: Arc<dyn Cacheable> = Arc::new(intermediate);
+            context
+                .cache
+                .write()
+                .await
+                .insert(cache_key.clone(), cacheable);
+        }
+
+        let db = NodeDBConnection { cache_key };
+        let db: Value = flow_like_types::json::to_value(&db)?;
+
+        context.set_pin_value("database", db).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
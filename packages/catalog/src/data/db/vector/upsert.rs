@@ -3,15 +3,45 @@ use flow_like::{
         execution::context::ExecutionContext,
         node::{Node, NodeLogic},
         pin::{PinOptions, ValueType},
+        utils::stable_hash,
         variable::VariableType,
     },
     state::FlowLikeState,
 };
 use flow_like_storage::databases::vector::VectorStore;
-use flow_like_types::{Value, async_trait};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
 
 use super::NodeDBConnection;
 
+/// Content hash of `item` without its id field, used as the id of records lacking one.
+fn content_id(item: &Value, id_field: &str) -> flow_like_types::Result<Value> {
+    let mut content = item.clone();
+    if let Some(content) = content.as_object_mut() {
+        content.remove(id_field);
+    }
+    Ok(Value::String(stable_hash(&content)?))
+}
+
+/// Treats a lookup on a table that wasn't created yet as finding nothing, other errors are kept.
+fn absent_if_uninitialized(
+    result: flow_like_types::Result<Vec<Value>>,
+) -> flow_like_types::Result<Vec<Value>> {
+    match result {
+        Err(error) if error.to_string() == "Table not initialized" => Ok(Vec::new()),
+        result => result,
+    }
+}
+
+fn add_hash_ids_pin(node: &mut Node) {
+    node.add_input_pin(
+        "hash_missing_ids",
+        "Hash Missing IDs",
+        "Use a hash of the content as ID for items without one, so identical content isn't inserted twice. The ID Column has to be a string",
+        VariableType::Boolean,
+    )
+    .set_default_value(Some(json!(false)));
+}
+
 #[derive(Default)]
 pub struct UpsertLocalDatabaseNode {}
 
@@ -44,6 +74,7 @@ impl NodeLogic for UpsertLocalDatabaseNode {
         node.add_input_pin("id_row", "ID Column", "The ID Column", VariableType::String);
 
         node.add_input_pin("value", "Value", "Value to Insert", VariableType::Struct);
+        add_hash_ids_pin(&mut node);
 
         node.add_output_pin(
             "exec_out",
@@ -63,8 +94,17 @@ impl NodeLogic for UpsertLocalDatabaseNode {
         let mut database = database.write().await;
         let id_row: String = context.evaluate_pin("id_row").await?;
         let value: Value = context.evaluate_pin("value").await?;
+        let hash_missing_ids: bool = context.evaluate_pin("hash_missing_ids").await?;
         let value = vec![value];
-        database.upsert(value, id_row).await?;
+        if hash_missing_ids {
+            let id_field = id_row.clone();
+            database
+                .upsert_with_id_fn(value, id_row, |item| content_id(item, &id_field))
+                .await?;
+        } else {
+            database.validate_records(&value).await?;
+            database.upsert(value, id_row).await?;
+        }
 
         context.activate_exec_pin("exec_out").await?;
 
@@ -105,6 +145,7 @@ impl NodeLogic for BatchUpsertLocalDatabaseNode {
 
         node.add_input_pin("value", "Value", "Value to Insert", VariableType::Struct)
             .set_value_type(ValueType::Array);
+        add_hash_ids_pin(&mut node);
 
         node.add_output_pin(
             "exec_out",
@@ -124,10 +165,318 @@ impl NodeLogic for BatchUpsertLocalDatabaseNode {
         let mut database = database.write().await;
         let value: Vec<Value> = context.evaluate_pin("value").await?;
         let id_row: String = context.evaluate_pin("id_row").await?;
-        database.upsert(value, id_row).await?;
+        let hash_missing_ids: bool = context.evaluate_pin("hash_missing_ids").await?;
+        if hash_missing_ids {
+            let id_field = id_row.clone();
+            database
+                .upsert_with_id_fn(value, id_row, |item| content_id(item, &id_field))
+                .await?;
+        } else {
+            database.validate_records(&value).await?;
+            database.upsert(value, id_row).await?;
+        }
+
+        context.activate_exec_pin("exec_out").await?;
+
+        Ok(())
+    }
+}
+
+/// SQL literal for an id value, used to look up the stored row.
+fn id_literal(id: &Value) -> flow_like_types::Result<String> {
+    match id {
+        Value::String(id) => Ok(format!("'{}'", id.replace('\'', "''"))),
+        Value::Number(id) => Ok(id.to_string()),
+        Value::Bool(id) => Ok(id.to_string()),
+        _ => Err(anyhow!("Unsupported ID value: {}", id)),
+    }
+}
+
+/// True if every field of `incoming` equals the stored field. Numbers are compared with a
+/// relative tolerance, because vectors are stored as f32 and read back with rounding noise.
+fn values_match(incoming: &Value, stored: &Value) -> bool {
+    match (incoming, stored) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => (a - b).abs() <= 1e-6 * a.abs().max(b.abs()).max(1.0),
+            _ => a == b,
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_match(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => a
+            .iter()
+            .all(|(key, a)| b.get(key).is_some_and(|b| values_match(a, b))),
+        _ => incoming == stored,
+    }
+}
+
+/// Last-write-wins check: true if `incoming` is strictly newer than `stored`, or nothing is
+/// stored. Numbers compare numerically, RFC 3339 strings as instants and other strings
+/// lexicographically.
+fn is_newer(incoming: &Value, stored: Option<&Value>) -> flow_like_types::Result<bool> {
+    let stored = match stored {
+        None | Some(Value::Null) => return Ok(true),
+        Some(stored) => stored,
+    };
+
+    match (incoming, stored) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => Ok(a > b),
+            _ => Err(anyhow!("Timestamps {} and {} can't be compared", a, b)),
+        },
+        (Value::String(a), Value::String(b)) => {
+            let parse = chrono::DateTime::parse_from_rfc3339;
+            match (parse(a), parse(b)) {
+                (Ok(a), Ok(b)) => Ok(a > b),
+                _ => Ok(a > b),
+            }
+        }
+        _ => Err(anyhow!(
+            "Timestamps {} and {} have different types",
+            incoming,
+            stored
+        )),
+    }
+}
+
+#[derive(Default)]
+pub struct ConditionalUpsertLocalDatabaseNode {}
+
+impl ConditionalUpsertLocalDatabaseNode {
+    pub fn new() -> Self {
+        ConditionalUpsertLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ConditionalUpsertLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "conditional_upsert_local_db",
+            "Upsert If Changed",
+            "Upserts the Item only if it differs from the stored one, avoiding needless table versions",
+            "Data/Database/Insert",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+        node.add_input_pin("id_row", "ID Column", "The ID Column", VariableType::String);
+
+        node.add_input_pin("value", "Value", "Value to Insert", VariableType::Struct);
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Upserting",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "changed",
+            "Changed",
+            "True if the Item was new or different and got written",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let mut database = database.write().await;
+        let id_row: String = context.evaluate_pin("id_row").await?;
+        let value: Value = context.evaluate_pin("value").await?;
+
+        let id = value
+            .get(&id_row)
+            .ok_or_else(|| anyhow!("Value is missing the ID Column '{}'", id_row))?;
+        let filter = format!("\"{}\" = {}", id_row.replace('"', "\"\""), id_literal(id)?);
+
+        // a missing table fails the lookup, the upsert below creates it
+        let stored = absent_if_uninitialized(database.filter(&filter, None, 1, 0, None).await)?;
+        let changed = match stored.first() {
+            Some(stored) => !values_match(&value, stored),
+            None => true,
+        };
+
+        if changed {
+            database.upsert(vec![value], id_row).await?;
+        }
+
+        context.set_pin_value("changed", json!(changed)).await?;
+        context.activate_exec_pin("exec_out").await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct LastWriteWinsUpsertLocalDatabaseNode {}
+
+impl LastWriteWinsUpsertLocalDatabaseNode {
+    pub fn new() -> Self {
+        LastWriteWinsUpsertLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for LastWriteWinsUpsertLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "lww_upsert_local_db",
+            "Upsert If Newer",
+            "Upserts the Item only if its timestamp is newer than the stored one (last write wins), e.g. when syncing from multiple sources",
+            "Data/Database/Insert",
+        );
+        node.add_icon("/flow/icons/database.svg");
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+        node.add_input_pin("id_row", "ID Column", "The ID Column", VariableType::String);
+
+        node.add_input_pin(
+            "timestamp_row",
+            "Timestamp Column",
+            "Column holding the last update as number or RFC 3339 string",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("updated_at")));
+
+        node.add_input_pin("value", "Value", "Value to Insert", VariableType::Struct);
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Done Upserting",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "written",
+            "Written",
+            "True if the Item was new or newer and got written, false if the stored one won",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let database = database.load(context).await?.db.clone();
+        let mut database = database.write().await;
+        let id_row: String = context.evaluate_pin("id_row").await?;
+        let timestamp_row: String = context.evaluate_pin("timestamp_row").await?;
+        let value: Value = context.evaluate_pin("value").await?;
 
+        let id = value
+            .get(&id_row)
+            .ok_or_else(|| anyhow!("Value is missing the ID Column '{}'", id_row))?;
+        let timestamp = value
+            .get(&timestamp_row)
+            .ok_or_else(|| anyhow!("Value is missing the Timestamp Column '{}'", timestamp_row))?;
+        let filter = format!("\"{}\" = {}", id_row.replace('"', "\"\""), id_literal(id)?);
+
+        // the write lock is held until the upsert, so no other node writes in between
+        let stored = absent_if_uninitialized(database.filter(&filter, None, 1, 0, None).await)?;
+        let written = is_newer(
+            timestamp,
+            stored.first().and_then(|stored| stored.get(&timestamp_row)),
+        )?;
+
+        if written {
+            database.upsert(vec![value], id_row).await?;
+        }
+
+        context.set_pin_value("written", json!(written)).await?;
         context.activate_exec_pin("exec_out").await?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{absent_if_uninitialized, content_id, is_newer, values_match};
+    use flow_like_types::{anyhow, json::json};
+
+    #[test]
+    fn tolerates_f32_rounding() {
+        let incoming = json!({"id": "a", "vector": [0.1, 0.2], "text": "hi"});
+        let stored =
+            json!({"id": "a", "vector": [0.10000000149011612, 0.20000000298023224], "text": "hi"});
+        assert!(values_match(&incoming, &stored));
+    }
+
+    #[test]
+    fn detects_changed_fields() {
+        let incoming = json!({"id": "a", "text": "new"});
+        let stored = json!({"id": "a", "text": "old"});
+        assert!(!values_match(&incoming, &stored));
+        assert!(!values_match(
+            &json!({"id": "a", "extra": 1}),
+            &json!({"id": "a"})
+        ));
+    }
+
+    #[test]
+    fn content_id_ignores_id_and_key_order() {
+        let a = content_id(&json!({"id": null, "text": "hi", "n": 1}), "id").unwrap();
+        let b = content_id(&json!({"n": 1, "text": "hi"}), "id").unwrap();
+        let c = content_id(&json!({"n": 2, "text": "hi"}), "id").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn last_write_wins() {
+        assert!(is_newer(&json!(2), Some(&json!(1))).unwrap());
+        assert!(!is_newer(&json!(1), Some(&json!(1))).unwrap());
+        assert!(is_newer(&json!(1), None).unwrap());
+        assert!(is_newer(&json!(1), Some(&json!(null))).unwrap());
+
+        // same instant is not newer, even in another offset
+        assert!(
+            !is_newer(
+                &json!("2024-01-01T01:00:00+01:00"),
+                Some(&json!("2024-01-01T00:00:00Z"))
+            )
+            .unwrap()
+        );
+        assert!(
+            is_newer(
+                &json!("2024-01-02T00:00:00Z"),
+                Some(&json!("2024-01-01T23:00:00+02:00"))
+            )
+            .unwrap()
+        );
+        assert!(is_newer(&json!("1"), Some(&json!(0))).is_err());
+    }
+
+    #[test]
+    fn only_a_missing_table_counts_as_absent() {
+        let missing = absent_if_uninitialized(Err(anyhow!("Table not initialized")));
+        assert!(missing.unwrap().is_empty());
+        assert!(absent_if_uninitialized(Err(anyhow!("connection reset"))).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{anyhow, async_trait, json::json, tokio};
+use std::time::{Duration, Instant};
+
+use super::NodeDBConnection;
+
+#[derive(Default)]
+pub struct WaitIndexedLocalDatabaseNode {}
+
+impl WaitIndexedLocalDatabaseNode {
+    pub fn new() -> Self {
+        WaitIndexedLocalDatabaseNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for WaitIndexedLocalDatabaseNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "wait_indexed_local_db",
+            "Wait Until Indexed",
+            "Polls the index on a column until it covers every row or the timeout elapses, so Fast Search doesn't miss freshly written rows",
+            "Data/Database",
+        );
+        node.add_icon("/flow/icons/database.svg");
+        node.set_long_running(true);
+
+        node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+        node.add_input_pin(
+            "database",
+            "Database",
+            "Database Connection Reference",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeDBConnection>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "column",
+            "Column",
+            "Indexed column to wait for",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "timeout_ms",
+            "Timeout (ms)",
+            "Give up after this many milliseconds",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(60000)));
+
+        node.add_input_pin(
+            "poll_ms",
+            "Poll Interval (ms)",
+            "Delay between checks",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(1000)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Fires once the index is ready or the timeout elapsed",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "ready",
+            "Ready",
+            "True if the index covers every row, false on timeout",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let database: NodeDBConnection = context.evaluate_pin("database").await?;
+        let column: String = context.evaluate_pin("column").await?;
+        let timeout_ms: i64 = context.evaluate_pin("timeout_ms").await?;
+        let poll_ms: i64 = context.evaluate_pin("poll_ms").await?;
+
+        let database = database.load(context).await?.db.clone();
+        let cancellation = context.cancellation_token().await;
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms.max(0) as u64);
+        let poll = Duration::from_millis(poll_ms.max(1) as u64);
+
+        let ready = loop {
+            let coverage = database.read().await.index_coverage(&column).await?;
+            match coverage {
+                Some((_, 0)) => break true,
+                Some((indexed, unindexed)) => context.log_message(
+                    &format!(
+                        "Index on '{}' covers {} of {} rows",
+                        column,
+                        indexed,
+                        indexed + unindexed
+                    ),
+                    LogLevel::Debug,
+                ),
+                None => {
+                    context.log_message(&format!("No index on '{}' yet", column), LogLevel::Debug)
+                }
+            }
+
+            if Instant::now() >= deadline {
+                break false;
+            }
+
+            tokio::time::sleep(poll.min(deadline.saturating_duration_since(Instant::now()))).await;
+            if cancellation
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+            {
+                return Err(anyhow!("Cancelled"));
+            }
+        };
+
+        if !ready {
+            context.log_message(
+                &format!("Timed out waiting for the index on '{}'", column),
+                LogLevel::Warn,
+            );
+        }
+
+        context.set_pin_value("ready", json!(ready)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
@@ -1,5 +1,3 @@
-use std::collections::BTreeMap;
-
 use flow_like::{
     flow::{
         execution::context::ExecutionContext,
@@ -9,7 +7,10 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::{Value, anyhow, async_trait, json::json};
+use flow_like_types::{
+    Value, anyhow, async_trait,
+    json::{Map, json},
+};
 
 use crate::data::excel::{CSVTable, Cell};
 
@@ -88,7 +89,8 @@ impl NodeLogic for GetRowByIndexNode {
         }
 
         let row = &table.rows[idx0];
-        let mut obj: BTreeMap<String, Value> = BTreeMap::new();
+        // keeps the columns in sheet order
+        let mut obj: Map<String, Value> = Map::new();
         for col_idx in 0..table.ncols() {
             let key = table.headers[col_idx].as_ref();
             let val = row.get(col_idx).unwrap_or(&Cell::Null);
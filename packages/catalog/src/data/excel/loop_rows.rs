@@ -4,7 +4,11 @@ use std::sync::Arc;
 use flow_like::{
     flow::{
         board::Board,
-        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, LoopSignal},
+            internal_node::InternalNode,
+        },
         node::{Node, NodeLogic},
         pin::{PinOptions, ValueType},
         variable::VariableType,
@@ -91,7 +95,9 @@ impl NodeLogic for RowLoopNode {
         context.activate_exec_pin_ref(&exec_item).await?;
 
         // Iterate rows
+        let signal = LoopSignal::new();
         for (i, row) in table.rows.iter().enumerate() {
+            signal.next_pass();
             // Build { header: value } object
             let mut obj: BTreeMap<String, FlowValue> = BTreeMap::new();
             for col_idx in 0..table.ncols() {
@@ -111,6 +117,7 @@ impl NodeLogic for RowLoopNode {
             // Trigger connected nodes
             for node in connected.iter() {
                 let mut sub_context = context.create_sub_context(node).await;
+                sub_context.set_loop_signal(signal.clone());
                 let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
                 sub_context.end_trace();
                 context.push_sub_context(&mut sub_context);
@@ -121,6 +128,15 @@ impl NodeLogic for RowLoopNode {
                         LogLevel::Error,
                     );
                 }
+
+                if signal.pass_ended() {
+                    break;
+                }
+            }
+
+            if signal.is_broken() {
+                context.log_message(&format!("Loop broken at row {}", i), LogLevel::Debug);
+                break;
             }
         }
 
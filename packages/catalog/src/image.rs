@@ -89,8 +89,10 @@ impl NodeImage {
 }
 
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
-    let mut nodes: Vec<Arc<dyn NodeLogic>> =
-        vec![Arc::new(metadata::dims::ImageDimsNode::default())];
+    let mut nodes: Vec<Arc<dyn NodeLogic>> = vec![
+        Arc::new(metadata::dims::ImageDimsNode::default()),
+        Arc::new(metadata::exif::ReadImageMetadataNode::default()),
+    ];
     nodes.extend(annotate::register_functions().await);
     nodes.extend(content::register_functions().await);
     nodes.extend(transform::register_functions().await);
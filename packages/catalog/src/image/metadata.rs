@@ -1 +1,2 @@
 pub mod dims;
+pub mod exif;
@@ -0,0 +1,288 @@
+use crate::data::path::FlowPath;
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Ok, async_trait,
+    image::{ImageDecoder, ImageReader},
+    json::json,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+
+const TAG_ORIENTATION: u16 = 0x0112;
+const TAG_GPS_IFD: u16 = 0x8825;
+const TAG_GPS_LATITUDE_REF: u16 = 0x0001;
+const TAG_GPS_LATITUDE: u16 = 0x0002;
+const TAG_GPS_LONGITUDE_REF: u16 = 0x0003;
+const TAG_GPS_LONGITUDE: u16 = 0x0004;
+const TAG_GPS_ALTITUDE_REF: u16 = 0x0005;
+const TAG_GPS_ALTITUDE: u16 = 0x0006;
+
+#[derive(Default, Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct GpsPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: Option<f64>,
+}
+
+#[derive(Default, Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct ImageMetadata {
+    pub width: u32,
+    pub height: u32,
+    pub format: Option<String>,
+    pub color_type: String,
+    /// EXIF orientation 1-8, 1 means upright
+    pub orientation: Option<u16>,
+    pub gps: Option<GpsPosition>,
+}
+
+/// Minimal reader for the TIFF structure EXIF is stored in.
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let data = data.strip_prefix(b"Exif\0\0").unwrap_or(data);
+        let little_endian = match data.get(0..2)? {
+            b"II" => true,
+            b"MM" => false,
+            _ => return None,
+        };
+        Some(Tiff {
+            data,
+            little_endian,
+        })
+    }
+
+    fn u16_at(&self, offset: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32_at(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    /// Offset of the value field of the entry with `tag` in the IFD at `ifd`.
+    fn entry(&self, ifd: usize, tag: u16) -> Option<usize> {
+        let count = self.u16_at(ifd)? as usize;
+        (0..count)
+            .map(|i| ifd + 2 + i * 12)
+            .find(|&entry| self.u16_at(entry) == Some(tag))
+            .map(|entry| entry + 8)
+    }
+
+    fn first_ifd(&self) -> Option<usize> {
+        Some(self.u32_at(4)? as usize)
+    }
+
+    fn rational(&self, offset: usize) -> Option<f64> {
+        let numerator = self.u32_at(offset)? as f64;
+        let denominator = self.u32_at(offset + 4)? as f64;
+        if denominator == 0.0 {
+            return None;
+        }
+        Some(numerator / denominator)
+    }
+
+    /// Degrees, minutes and seconds stored as three rationals.
+    fn degrees(&self, value: usize) -> Option<f64> {
+        let offset = self.u32_at(value)? as usize;
+        Some(
+            self.rational(offset)?
+                + self.rational(offset + 8)? / 60.0
+                + self.rational(offset + 16)? / 3600.0,
+        )
+    }
+
+    fn orientation(&self) -> Option<u16> {
+        let value = self.entry(self.first_ifd()?, TAG_ORIENTATION)?;
+        self.u16_at(value).filter(|o| (1..=8).contains(o))
+    }
+
+    fn gps(&self) -> Option<GpsPosition> {
+        let gps_ifd = self.u32_at(self.entry(self.first_ifd()?, TAG_GPS_IFD)?)? as usize;
+
+        let reference = |tag| {
+            self.entry(gps_ifd, tag)
+                .and_then(|value| self.data.get(value).copied())
+        };
+        let latitude = self.degrees(self.entry(gps_ifd, TAG_GPS_LATITUDE)?)?;
+        let longitude = self.degrees(self.entry(gps_ifd, TAG_GPS_LONGITUDE)?)?;
+        let latitude = if reference(TAG_GPS_LATITUDE_REF) == Some(b'S') {
+            -latitude
+        } else {
+            latitude
+        };
+        let longitude = if reference(TAG_GPS_LONGITUDE_REF) == Some(b'W') {
+            -longitude
+        } else {
+            longitude
+        };
+
+        let altitude = self
+            .entry(gps_ifd, TAG_GPS_ALTITUDE)
+            .and_then(|value| self.rational(self.u32_at(value)? as usize))
+            .map(|altitude| {
+                if reference(TAG_GPS_ALTITUDE_REF) == Some(1) {
+                    -altitude
+                } else {
+                    altitude
+                }
+            });
+
+        Some(GpsPosition {
+            latitude,
+            longitude,
+            altitude,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct ReadImageMetadataNode {}
+
+impl ReadImageMetadataNode {
+    pub fn new() -> Self {
+        ReadImageMetadataNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ReadImageMetadataNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "read_image_metadata",
+            "Read Metadata",
+            "Reads dimensions, format, color type and EXIF orientation/GPS from an image file without decoding the pixels",
+            "Image/Metadata",
+        );
+        node.add_icon("/flow/icons/dir.svg");
+
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+        node.add_input_pin("path", "Path", "FlowPath", VariableType::Struct)
+            .set_schema::<FlowPath>()
+            .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "metadata",
+            "Metadata",
+            "Image Metadata, EXIF fields are null if missing",
+            VariableType::Struct,
+        )
+        .set_schema::<ImageMetadata>();
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+        let path: FlowPath = context.evaluate_pin("path").await?;
+        let bytes = path.get(context, false).await?;
+
+        let metadata = {
+            let reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+            let format = reader.format().map(|format| format!("{:?}", format));
+            let mut decoder = reader.into_decoder()?;
+            let (width, height) = decoder.dimensions();
+            let color_type = format!("{:?}", decoder.color_type());
+            let exif = decoder.exif_metadata().ok().flatten().unwrap_or_default();
+            let tiff = Tiff::new(&exif);
+
+            ImageMetadata {
+                width,
+                height,
+                format,
+                color_type,
+                orientation: tiff.as_ref().and_then(Tiff::orientation),
+                gps: tiff.as_ref().and_then(Tiff::gps),
+            }
+        };
+
+        context.set_pin_value("metadata", json!(metadata)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tiff;
+
+    fn entry(tag: u16, typ: u16, count: u32, value: u32) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend(tag.to_le_bytes());
+        bytes.extend(typ.to_le_bytes());
+        bytes.extend(count.to_le_bytes());
+        bytes.extend(value.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn reads_orientation_and_gps() {
+        // header (8) + IFD0 with 2 entries (2 + 24 + 4) = 38, GPS IFD with 4 entries at 38
+        let mut exif = b"II*\0".to_vec();
+        exif.extend(8u32.to_le_bytes());
+        exif.extend(2u16.to_le_bytes());
+        exif.extend(entry(0x0112, 3, 1, 6));
+        exif.extend(entry(0x8825, 4, 1, 38));
+        exif.extend(0u32.to_le_bytes());
+
+        // GPS IFD (2 + 48 + 4) = 54 bytes, rationals start at 92
+        exif.extend(4u16.to_le_bytes());
+        exif.extend(entry(0x0001, 2, 2, b'S' as u32));
+        exif.extend(entry(0x0002, 5, 3, 92));
+        exif.extend(entry(0x0003, 2, 2, b'E' as u32));
+        exif.extend(entry(0x0004, 5, 3, 116));
+        exif.extend(0u32.to_le_bytes());
+        for (numerator, denominator) in [(33, 1), (30, 1), (0, 1), (151, 1), (12, 1), (36, 1)] {
+            exif.extend((numerator as u32).to_le_bytes());
+            exif.extend((denominator as u32).to_le_bytes());
+        }
+
+        let tiff = Tiff::new(&exif).unwrap();
+        assert_eq!(tiff.orientation(), Some(6));
+        let gps = tiff.gps().unwrap();
+        assert!((gps.latitude + 33.5).abs() < 1e-9);
+        assert!((gps.longitude - 151.21).abs() < 1e-9);
+        assert_eq!(gps.altitude, None);
+    }
+
+    #[test]
+    fn malformed_exif_yields_nothing() {
+        assert!(Tiff::new(b"garbage").is_none());
+        let tiff = Tiff::new(b"II*\0\xff\xff\0\0").unwrap();
+        assert_eq!(tiff.orientation(), None);
+        assert_eq!(tiff.gps(), None);
+    }
+}
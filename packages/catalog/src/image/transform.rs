@@ -4,6 +4,8 @@ use std::sync::Arc;
 pub mod contrast;
 pub mod convert;
 pub mod crop;
+pub mod crop_boxes;
+pub mod encode;
 pub mod resize;
 
 /// Image-Transform Nodes
@@ -12,6 +14,8 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(convert::ConvertImageNode::default()),
         Arc::new(contrast::ContrastImageNode::default()),
         Arc::new(crop::CropImageNode::default()),
+        Arc::new(crop_boxes::CropImageBoxesNode::default()),
+        Arc::new(encode::EncodeImageNode::default()),
         Arc::new(resize::ResizeImageNode::default()),
     ];
     nodes
@@ -9,7 +9,7 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::{async_trait, json::json};
+use flow_like_types::{anyhow, async_trait, image::GenericImageView, json::json};
 
 #[derive(Default)]
 pub struct CropImageNode {}
@@ -37,9 +37,14 @@ impl NodeLogic for CropImageNode {
             .set_schema::<NodeImage>()
             .set_options(PinOptions::new().set_enforce_schema(true).build());
 
-        node.add_input_pin("bbox", "Box", "Bounding Box", VariableType::Struct)
-            .set_schema::<BoundingBox>()
-            .set_options(PinOptions::new().set_enforce_schema(true).build());
+        node.add_input_pin(
+            "bbox",
+            "Box",
+            "Bounding Box, clamped to the image bounds",
+            VariableType::Struct,
+        )
+        .set_schema::<BoundingBox>()
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
 
         node.add_input_pin(
             "use_ref",
@@ -83,7 +88,18 @@ impl NodeLogic for CropImageNode {
         // crop image
         {
             let mut img_guard = img.lock().await;
-            let (x, y, w, h) = bbox.x1y1wh();
+            let (width, height) = img_guard.dimensions();
+            let (x, y, w, h) = bbox.clamped_x1y1wh(width, height).ok_or_else(|| {
+                anyhow!(
+                    "Bounding box ({}, {}, {}, {}) does not overlap the {}x{} image",
+                    bbox.x1,
+                    bbox.y1,
+                    bbox.x2,
+                    bbox.y2,
+                    width,
+                    height
+                )
+            })?;
             let img_cropped = img_guard.crop_imm(x, y, w, h);
             *img_guard = img_cropped;
         }
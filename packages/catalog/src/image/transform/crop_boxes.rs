@@ -0,0 +1,118 @@
+use crate::{ai::onnx::detection::BoundingBox, image::NodeImage};
+
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, image::GenericImageView, json::json};
+
+#[derive(Default)]
+pub struct CropImageBoxesNode {}
+
+impl CropImageBoxesNode {
+    pub fn new() -> Self {
+        CropImageBoxesNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for CropImageBoxesNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "crop_image_boxes",
+            "Crop Image Boxes",
+            "Crops one sub-image per Bounding Box, e.g. to extract detected regions",
+            "Image/Transform",
+        );
+        node.add_icon("/flow/icons/image.svg");
+
+        // inputs
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+        node.add_input_pin("image_in", "Image", "Image object", VariableType::Struct)
+            .set_schema::<NodeImage>()
+            .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin(
+            "bboxes",
+            "Boxes",
+            "Bounding Boxes, clamped to the image bounds",
+            VariableType::Struct,
+        )
+        .set_schema::<BoundingBox>()
+        .set_value_type(ValueType::Array)
+        .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        // outputs
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "images_out",
+            "Cropped",
+            "Cropped Image objects, boxes outside of the image are skipped",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeImage>()
+        .set_value_type(ValueType::Array);
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        // fetch inputs
+        let node_img: NodeImage = context.evaluate_pin("image_in").await?;
+        let bboxes: Vec<BoundingBox> = context.evaluate_pin("bboxes").await?;
+        let img = node_img.get_image(context).await?;
+
+        // crop regions
+        let mut crops = Vec::with_capacity(bboxes.len());
+        let mut skipped = Vec::new();
+        {
+            let img_guard = img.lock().await;
+            let (width, height) = img_guard.dimensions();
+            for (i, bbox) in bboxes.iter().enumerate() {
+                match bbox.clamped_x1y1wh(width, height) {
+                    Some((x, y, w, h)) => crops.push(img_guard.crop_imm(x, y, w, h)),
+                    None => skipped.push(i),
+                }
+            }
+        }
+
+        for i in skipped {
+            let bbox = &bboxes[i];
+            context.log_message(
+                &format!(
+                    "Skipping bounding box {} ({}, {}, {}, {}): empty or outside of the image",
+                    i, bbox.x1, bbox.y1, bbox.x2, bbox.y2
+                ),
+                LogLevel::Warn,
+            );
+        }
+
+        let mut images = Vec::with_capacity(crops.len());
+        for crop in crops {
+            images.push(NodeImage::new(context, crop).await);
+        }
+
+        // set outputs
+        context.set_pin_value("images_out", json!(images)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::image::NodeImage;
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    anyhow, async_trait,
+    image::{
+        self, DynamicImage, ImageFormat,
+        codecs::{jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+    },
+    json::json,
+};
+
+#[derive(Default)]
+pub struct EncodeImageNode {}
+
+impl EncodeImageNode {
+    pub fn new() -> Self {
+        EncodeImageNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for EncodeImageNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "encode_image",
+            "Convert Format",
+            "Re-encodes an image in the target file format, returning the decoded result and the encoded bytes",
+            "Image/Transform",
+        );
+        node.add_icon("/flow/icons/image.svg");
+
+        // inputs
+        node.add_input_pin(
+            "exec_in",
+            "Input",
+            "Initiate Execution",
+            VariableType::Execution,
+        );
+        node.add_input_pin("image_in", "Image", "Image object", VariableType::Struct)
+            .set_schema::<NodeImage>()
+            .set_options(PinOptions::new().set_enforce_schema(true).build());
+
+        node.add_input_pin("format", "Format", "Target Format", VariableType::String)
+            .set_options(
+                PinOptions::new()
+                    .set_valid_values(vec![
+                        "png".to_string(),
+                        "jpeg".to_string(),
+                        "webp".to_string(),
+                    ])
+                    .build(),
+            )
+            .set_default_value(Some(json!("png")));
+
+        node.add_input_pin(
+            "quality",
+            "Quality",
+            "Encoding Quality (jpeg only, png and webp are lossless)",
+            VariableType::Integer,
+        )
+        .set_options(PinOptions::new().set_range((1., 100.)).build())
+        .set_default_value(Some(json!(90)));
+
+        node.add_input_pin(
+            "use_ref",
+            "Use Reference",
+            "Use Reference of the image, transforming the original instead of a copy",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(true)));
+
+        // outputs
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Done with the Execution",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "image_out",
+            "Image",
+            "Image after the encoding round trip",
+            VariableType::Struct,
+        )
+        .set_schema::<NodeImage>();
+        node.add_output_pin("bytes", "Bytes", "Encoded image file", VariableType::Byte);
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        // fetch inputs
+        let mut node_img: NodeImage = context.evaluate_pin("image_in").await?;
+        let format: String = context.evaluate_pin("format").await?;
+        let quality: i64 = context.evaluate_pin("quality").await?;
+        let use_ref: bool = context.evaluate_pin("use_ref").await?;
+        if !use_ref {
+            node_img = node_img.copy_image(context).await?;
+        }
+        let img = node_img.get_image(context).await?;
+
+        // encode and decode again, so downstream nodes see the format's artifacts and channels
+        let encoded = {
+            let mut img_guard = img.lock().await;
+            let mut encoded = Vec::new();
+            let image_format = match format.as_str() {
+                "png" => {
+                    img_guard.write_with_encoder(PngEncoder::new(&mut encoded))?;
+                    ImageFormat::Png
+                }
+                "jpeg" => {
+                    let quality = quality.clamp(1, 100) as u8;
+                    let encoder = JpegEncoder::new_with_quality(&mut encoded, quality);
+                    DynamicImage::ImageRgb8(img_guard.to_rgb8()).write_with_encoder(encoder)?;
+                    ImageFormat::Jpeg
+                }
+                "webp" => {
+                    let rgba = DynamicImage::ImageRgba8(img_guard.to_rgba8());
+                    rgba.write_with_encoder(WebPEncoder::new_lossless(&mut encoded))?;
+                    ImageFormat::WebP
+                }
+                _ => return Err(anyhow!("Unsupported image format: {}", format)),
+            };
+            *img_guard = image::load_from_memory_with_format(&encoded, image_format)
+                .map_err(|e| anyhow!("Failed to decode {} image: {}", format, e))?;
+            encoded
+        };
+
+        // set outputs
+        context.set_pin_value("image_out", json!(node_img)).await?;
+        context.set_pin_value("bytes", json!(encoded)).await?;
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
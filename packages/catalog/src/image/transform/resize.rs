@@ -9,7 +9,7 @@ use flow_like::{
     state::FlowLikeState,
 };
 use flow_like_types::{
-    Ok, async_trait,
+    Ok, anyhow, async_trait,
     image::{GenericImageView, imageops::FilterType},
     json::json,
 };
@@ -60,6 +60,7 @@ impl NodeLogic for ResizeImageNode {
                         "keep_aspect".to_string(),
                         "exact".to_string(),
                         "to_fill".to_string(),
+                        "scale".to_string(),
                     ])
                     .build(),
             )
@@ -100,6 +101,14 @@ impl NodeLogic for ResizeImageNode {
         )
         .set_default_value(Some(json!(512)));
 
+        node.add_input_pin(
+            "scale",
+            "Scale",
+            "Scale Factor, only used in scale mode",
+            VariableType::Float,
+        )
+        .set_default_value(Some(json!(1.0)));
+
         // outputs
         node.add_output_pin(
             "exec_out",
@@ -150,6 +159,14 @@ impl NodeLogic for ResizeImageNode {
             }
         }?;
 
+        let scale: f64 = context.evaluate_pin("scale").await?;
+        if mode == "scale" && !(scale.is_finite() && scale > 0.0) {
+            return Err(anyhow!(
+                "Scale factor must be a positive number, got {}",
+                scale
+            ));
+        }
+
         let img = node_img.get_image(context).await?;
 
         let (result_width, result_height) = {
@@ -157,6 +174,12 @@ impl NodeLogic for ResizeImageNode {
 
             let resized_img = match mode.as_str() {
                 "exact" => img_guard.resize_exact(target_width, target_height, filter),
+                "scale" => {
+                    let (width, height) = img_guard.dimensions();
+                    let scaled_width = ((width as f64 * scale).round() as u32).max(1);
+                    let scaled_height = ((height as f64 * scale).round() as u32).max(1);
+                    img_guard.resize_exact(scaled_width, scaled_height, filter)
+                }
                 "to_fill" => img_guard.resize_to_fill(target_width, target_height, filter),
                 _ => img_guard.resize(target_width, target_height, filter),
             };
@@ -1,5 +1,7 @@
 pub mod error;
 pub mod info;
+pub mod inspect;
+pub mod log;
 pub mod trace;
 pub mod warning;
 
@@ -11,5 +13,7 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(error::ErrorNode::default()) as Arc<dyn NodeLogic>,
         Arc::new(info::InfoNode::default()) as Arc<dyn NodeLogic>,
         Arc::new(warning::WarningNode::default()) as Arc<dyn NodeLogic>,
+        Arc::new(log::LogNode::default()) as Arc<dyn NodeLogic>,
+        Arc::new(inspect::InspectNode::default()) as Arc<dyn NodeLogic>,
     ]
 }
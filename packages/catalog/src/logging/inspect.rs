@@ -0,0 +1,227 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::{LogLevel, context::ExecutionContext, context::REDACTED_PIN_VALUE},
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::Map, json::json};
+use std::sync::Arc;
+
+/// Limits keeping inspected values readable in the run trace.
+struct InspectLimits {
+    max_items: usize,
+    max_string: usize,
+    max_depth: usize,
+}
+
+/// Copy of `value` with long arrays, structs and strings cut and a marker saying how much
+/// was left out. Anything nested deeper than `max_depth` is summarized.
+fn truncate(value: &Value, limits: &InspectLimits, depth: usize) -> Value {
+    match value {
+        Value::String(text) if text.chars().count() > limits.max_string => {
+            let kept: String = text.chars().take(limits.max_string).collect();
+            let left = text.chars().count() - limits.max_string;
+            Value::String(format!("{}… ({} more chars)", kept, left))
+        }
+        Value::Array(items) if depth >= limits.max_depth => {
+            Value::String(format!("[… {} items]", items.len()))
+        }
+        Value::Object(map) if depth >= limits.max_depth => {
+            Value::String(format!("{{… {} fields}}", map.len()))
+        }
+        Value::Array(items) => {
+            let mut kept: Vec<Value> = items
+                .iter()
+                .take(limits.max_items)
+                .map(|item| truncate(item, limits, depth + 1))
+                .collect();
+            if items.len() > limits.max_items {
+                kept.push(json!(format!(
+                    "… {} more items",
+                    items.len() - limits.max_items
+                )));
+            }
+            Value::Array(kept)
+        }
+        Value::Object(map) => {
+            let mut kept: Map<String, Value> = map
+                .iter()
+                .take(limits.max_items)
+                .map(|(key, value)| (key.clone(), truncate(value, limits, depth + 1)))
+                .collect();
+            if map.len() > limits.max_items {
+                kept.insert(
+                    "…".to_string(),
+                    json!(format!("{} more fields", map.len() - limits.max_items)),
+                );
+            }
+            Value::Object(kept)
+        }
+        other => other.clone(),
+    }
+}
+
+#[derive(Default)]
+pub struct InspectNode {}
+
+impl InspectNode {
+    pub fn new() -> Self {
+        InspectNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for InspectNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "log_inspect",
+            "Inspect",
+            "Logs a pretty-printed, shortened view of any value and passes it on unchanged. Values from sensitive pins are redacted",
+            "Logging",
+        );
+        node.add_icon("/flow/icons/log-info.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        node.add_input_pin("value", "Value", "Value to inspect", VariableType::Generic);
+
+        node.add_input_pin(
+            "label",
+            "Label",
+            "Prefix of the log message, e.g. where in the flow the value is",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("Inspect")));
+
+        node.add_input_pin("level", "Level", "Log level", VariableType::String)
+            .set_options(
+                PinOptions::new()
+                    .set_valid_values(vec![
+                        "Debug".to_string(),
+                        "Info".to_string(),
+                        "Warn".to_string(),
+                        "Error".to_string(),
+                    ])
+                    .build(),
+            )
+            .set_default_value(Some(json!("Debug")));
+
+        node.add_input_pin(
+            "max_items",
+            "Max Items",
+            "Array items and struct fields shown per level",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(10)));
+
+        node.add_input_pin(
+            "max_string",
+            "Max String Length",
+            "Characters shown per string",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(200)));
+
+        node.add_input_pin(
+            "max_depth",
+            "Max Depth",
+            "Nesting levels shown, deeper values are summarized",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(5)));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Continues after logging",
+            VariableType::Execution,
+        );
+
+        node.add_output_pin(
+            "value_out",
+            "Value",
+            "The unchanged value",
+            VariableType::Generic,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let value: Value = context.evaluate_pin("value").await?;
+        let label: String = context.evaluate_pin("label").await?;
+        let level: String = context.evaluate_pin("level").await?;
+        let max_items: i64 = context.evaluate_pin("max_items").await?;
+        let max_string: i64 = context.evaluate_pin("max_string").await?;
+        let max_depth: i64 = context.evaluate_pin("max_depth").await?;
+
+        let level = match level.as_str() {
+            "Info" => LogLevel::Info,
+            "Warn" => LogLevel::Warn,
+            "Error" => LogLevel::Error,
+            _ => LogLevel::Debug,
+        };
+
+        let value_pin = context.get_pin_by_name("value").await?;
+        let sensitive = value_pin.lock().await.is_sensitive().await;
+        let shown = if sensitive {
+            REDACTED_PIN_VALUE.to_string()
+        } else {
+            let limits = InspectLimits {
+                max_items: max_items.max(0) as usize,
+                max_string: max_string.max(0) as usize,
+                max_depth: max_depth.max(0) as usize,
+            };
+            flow_like_types::json::to_string_pretty(&truncate(&value, &limits, 0))?
+        };
+
+        context.log_message(&format!("{}: {}", label, shown), level);
+        context.set_pin_value("value_out", value).await?;
+        context.activate_exec_pin("exec_out").await?;
+        return Ok(());
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("value", board.clone(), None, None);
+        let _ = node.match_type("value_out", board, None, None);
+        node.harmonize_type(vec!["value", "value_out"], true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_large_values() {
+        let limits = InspectLimits {
+            max_items: 2,
+            max_string: 3,
+            max_depth: 2,
+        };
+        let value = json!({
+            "text": "abcdef",
+            "list": [1, 2, 3, 4],
+            "nested": {"deep": {"deeper": 1}},
+        });
+
+        assert_eq!(
+            truncate(&value, &limits, 0),
+            json!({
+                "text": "abc… (3 more chars)",
+                "list": [1, 2, "… 2 more items"],
+                "…": "1 more fields",
+            })
+        );
+        assert_eq!(
+            truncate(&json!({"a": {"b": {"c": 1}}}), &limits, 0),
+            json!({"a": {"b": "{… 1 fields}"}})
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+
+#[derive(Default)]
+pub struct LogNode {}
+
+impl LogNode {
+    pub fn new() -> Self {
+        LogNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for LogNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "log_message",
+            "Log",
+            "Writes a value to the run trace with the selected log level",
+            "Logging",
+        );
+        node.add_icon("/flow/icons/log-info.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+
+        node.add_input_pin(
+            "message",
+            "Message",
+            "The value to log, non-string values are serialized",
+            VariableType::Generic,
+        );
+
+        node.add_input_pin("level", "Level", "Log level", VariableType::String)
+            .set_options(
+                PinOptions::new()
+                    .set_valid_values(vec![
+                        "Debug".to_string(),
+                        "Info".to_string(),
+                        "Warn".to_string(),
+                        "Error".to_string(),
+                    ])
+                    .build(),
+            )
+            .set_default_value(Some(json!("Info")));
+
+        node.add_output_pin(
+            "exec_out",
+            "Output",
+            "Continues after logging",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let output = context.get_pin_by_name("exec_out").await?;
+        context.deactivate_exec_pin_ref(&output).await?;
+
+        let message: Value = context.evaluate_pin("message").await?;
+        let level: String = context.evaluate_pin("level").await?;
+
+        let level = match level.as_str() {
+            "Debug" => LogLevel::Debug,
+            "Warn" => LogLevel::Warn,
+            "Error" => LogLevel::Error,
+            _ => LogLevel::Info,
+        };
+
+        let message = match message {
+            Value::String(message) => message,
+            other => flow_like_types::json::to_string_pretty(&other)?,
+        };
+
+        context.log_message(&message, level);
+        context.activate_exec_pin_ref(&output).await?;
+
+        return Ok(());
+    }
+}
@@ -1,5 +1,9 @@
+pub mod explode;
 pub mod fields;
+pub mod flatten;
 pub mod make;
+pub mod patch;
+pub mod transpose;
 
 use flow_like::flow::node::NodeLogic;
 use std::sync::Arc;
@@ -10,5 +14,12 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(fields::has_field::HasStructFieldNode::default()),
         Arc::new(fields::get_field::GetStructFieldNode::default()),
         Arc::new(fields::set_field::SetStructFieldNode::default()),
+        Arc::new(flatten::FlattenStructNode::default()),
+        Arc::new(flatten::UnflattenStructNode::default()),
+        Arc::new(explode::ExplodeNode::default()),
+        Arc::new(patch::DiffStructsNode::default()),
+        Arc::new(patch::ApplyPatchNode::default()),
+        Arc::new(transpose::TransposeRecordsNode::default()),
+        Arc::new(transpose::RecordsFromColumnsNode::default()),
     ]
 }
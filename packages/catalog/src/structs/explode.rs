@@ -0,0 +1,128 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+
+/// One row per element of the array in `field`, the other fields are copied. Like SQL's
+/// UNNEST an empty array yields no rows. Rows where the field is missing or not an array
+/// are kept as they are if `keep_other`, otherwise dropped.
+fn explode(rows: Vec<Value>, field: &str, keep_other: bool) -> Vec<Value> {
+    let mut exploded = vec![];
+    for row in rows {
+        let Some(Value::Array(items)) = row.get(field) else {
+            if keep_other {
+                exploded.push(row);
+            }
+            continue;
+        };
+
+        for item in items {
+            let mut copy = row.clone();
+            copy[field] = item.clone();
+            exploded.push(copy);
+        }
+    }
+    exploded
+}
+
+#[derive(Default)]
+pub struct ExplodeNode {}
+
+impl ExplodeNode {
+    pub fn new() -> Self {
+        ExplodeNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ExplodeNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_explode",
+            "Explode",
+            "Turns every struct into one struct per element of an array field, copying the other fields (like SQL UNNEST). Empty arrays yield no structs",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin(
+            "array_in",
+            "Structs",
+            "Structs to explode",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "field",
+            "Field",
+            "Field holding the array to explode",
+            VariableType::String,
+        );
+
+        node.add_input_pin(
+            "other_rows",
+            "Without Array",
+            "What happens to structs where the field is missing or not an array",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Keep".to_string(), "Drop".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Keep")));
+
+        node.add_output_pin(
+            "array_out",
+            "Structs",
+            "One struct per array element",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let rows: Vec<Value> = context.evaluate_pin("array_in").await?;
+        let field: String = context.evaluate_pin("field").await?;
+        let other_rows: String = context.evaluate_pin("other_rows").await?;
+
+        let exploded = explode(rows, &field, other_rows != "Drop");
+        context.set_pin_value("array_out", json!(exploded)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explodes_array_fields() {
+        let rows = vec![
+            json!({"id": 1, "tags": ["a", "b"]}),
+            json!({"id": 2, "tags": []}),
+            json!({"id": 3, "tags": "c"}),
+            json!({"id": 4}),
+        ];
+
+        assert_eq!(
+            explode(rows.clone(), "tags", true),
+            vec![
+                json!({"id": 1, "tags": "a"}),
+                json!({"id": 1, "tags": "b"}),
+                json!({"id": 3, "tags": "c"}),
+                json!({"id": 4}),
+            ]
+        );
+        assert_eq!(explode(rows, "tags", false).len(), 2);
+    }
+}
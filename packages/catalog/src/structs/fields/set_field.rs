@@ -7,8 +7,8 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::async_trait;
-use std::{collections::HashMap, sync::Arc};
+use flow_like_types::{async_trait, json::Map};
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct SetStructFieldNode {}
@@ -57,7 +57,7 @@ impl NodeLogic for SetStructFieldNode {
         context.deactivate_exec_pin("exec_out").await?;
 
         let mut old_struct = context
-            .evaluate_pin::<HashMap<String, flow_like_types::Value>>("struct_in")
+            .evaluate_pin::<Map<String, flow_like_types::Value>>("struct_in")
             .await?;
         let field = context.evaluate_pin::<String>("field").await?;
         let value = context
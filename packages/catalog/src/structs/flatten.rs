@@ -0,0 +1,290 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::Map, json::json};
+
+/// Joins nested keys with `separator`, up to `max_depth` key segments (0 = unlimited).
+/// Empty structs and arrays are kept as values.
+fn flatten(
+    value: Value,
+    separator: &str,
+    max_depth: usize,
+    index_arrays: bool,
+) -> flow_like_types::Result<Map<String, Value>> {
+    let root = match value {
+        Value::Object(root) => root,
+        other => return Err(anyhow!("Only structs can be flattened, got {}", other)),
+    };
+
+    let mut flat = Map::new();
+    for (key, value) in root {
+        flatten_into(&mut flat, key, value, 1, separator, max_depth, index_arrays);
+    }
+    Ok(flat)
+}
+
+fn flatten_into(
+    flat: &mut Map<String, Value>,
+    key: String,
+    value: Value,
+    depth: usize,
+    separator: &str,
+    max_depth: usize,
+    index_arrays: bool,
+) {
+    let can_descend = max_depth == 0 || depth < max_depth;
+    match value {
+        Value::Object(map) if can_descend && !map.is_empty() => {
+            for (child, value) in map {
+                let key = format!("{}{}{}", key, separator, child);
+                flatten_into(
+                    flat,
+                    key,
+                    value,
+                    depth + 1,
+                    separator,
+                    max_depth,
+                    index_arrays,
+                );
+            }
+        }
+        Value::Array(items) if can_descend && index_arrays && !items.is_empty() => {
+            for (index, value) in items.into_iter().enumerate() {
+                let key = format!("{}{}{}", key, separator, index);
+                flatten_into(
+                    flat,
+                    key,
+                    value,
+                    depth + 1,
+                    separator,
+                    max_depth,
+                    index_arrays,
+                );
+            }
+        }
+        value => {
+            flat.insert(key, value);
+        }
+    }
+}
+
+/// Splits keys on `separator` into nested structs. With `rebuild_arrays`, structs whose keys
+/// are exactly `0..n` become arrays again.
+fn unflatten(
+    flat: Map<String, Value>,
+    separator: &str,
+    rebuild_arrays: bool,
+) -> flow_like_types::Result<Value> {
+    if separator.is_empty() {
+        return Err(anyhow!("Separator must not be empty"));
+    }
+
+    let mut root = Map::new();
+    for (key, value) in flat {
+        let segments: Vec<&str> = key.split(separator).collect();
+        let (last, parents) = segments.split_last().expect("split yields a segment");
+
+        let mut current = &mut root;
+        for (depth, segment) in parents.iter().enumerate() {
+            let entry = current
+                .entry(segment.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            current = entry.as_object_mut().ok_or_else(|| {
+                anyhow!(
+                    "Key '{}' conflicts with the value at '{}'",
+                    key,
+                    parents[..=depth].join(separator)
+                )
+            })?;
+        }
+
+        if current.contains_key(*last) {
+            return Err(anyhow!("Key '{}' conflicts with a nested key", key));
+        }
+        current.insert(last.to_string(), value);
+    }
+
+    let root = Value::Object(root);
+    Ok(if rebuild_arrays { rebuild(root) } else { root })
+}
+
+fn rebuild(value: Value) -> Value {
+    let map = match value {
+        Value::Object(map) => map,
+        other => return other,
+    };
+
+    let is_array =
+        !map.is_empty() && (0..map.len()).all(|index| map.contains_key(&index.to_string()));
+    let mut map: Map<String, Value> = map.into_iter().map(|(k, v)| (k, rebuild(v))).collect();
+
+    if is_array {
+        let items = (0..map.len())
+            .map(|index| map.remove(&index.to_string()).unwrap_or(Value::Null))
+            .collect();
+        return Value::Array(items);
+    }
+    Value::Object(map)
+}
+
+#[derive(Default)]
+pub struct FlattenStructNode {}
+
+impl FlattenStructNode {
+    pub fn new() -> Self {
+        FlattenStructNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for FlattenStructNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_flatten",
+            "Flatten Struct",
+            "Turns nested fields into flat keys, e.g. {a:{b:1}} into {\"a.b\":1}. Useful before writing to a database",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin(
+            "struct",
+            "Struct",
+            "Struct to flatten",
+            VariableType::Struct,
+        );
+        node.add_input_pin(
+            "separator",
+            "Separator",
+            "Joins the nested keys",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!(".")));
+        node.add_input_pin(
+            "max_depth",
+            "Max Depth",
+            "Maximum number of key segments, deeper values stay nested. 0 is unlimited",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(0)));
+        node.add_input_pin(
+            "index_arrays",
+            "Index Arrays",
+            "Flatten arrays into indexed keys (a.0.b) instead of keeping them intact",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin("flat", "Flat", "Flattened struct", VariableType::Struct);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let value: Value = context.evaluate_pin("struct").await?;
+        let separator: String = context.evaluate_pin("separator").await?;
+        let max_depth: i64 = context.evaluate_pin("max_depth").await?;
+        let index_arrays: bool = context.evaluate_pin("index_arrays").await?;
+
+        let flat = flatten(value, &separator, max_depth.max(0) as usize, index_arrays)?;
+        context.set_pin_value("flat", Value::Object(flat)).await?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct UnflattenStructNode {}
+
+impl UnflattenStructNode {
+    pub fn new() -> Self {
+        UnflattenStructNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for UnflattenStructNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_unflatten",
+            "Unflatten Struct",
+            "Turns flat keys into nested fields, e.g. {\"a.b\":1} into {a:{b:1}}",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin("flat", "Flat", "Flat struct", VariableType::Struct);
+        node.add_input_pin(
+            "separator",
+            "Separator",
+            "Splits the keys",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!(".")));
+        node.add_input_pin(
+            "rebuild_arrays",
+            "Rebuild Arrays",
+            "Turn nested structs with the keys 0..n back into arrays",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(true)));
+
+        node.add_output_pin("struct", "Struct", "Nested struct", VariableType::Struct);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let flat: Map<String, Value> = context.evaluate_pin("flat").await?;
+        let separator: String = context.evaluate_pin("separator").await?;
+        let rebuild_arrays: bool = context.evaluate_pin("rebuild_arrays").await?;
+
+        let nested = unflatten(flat, &separator, rebuild_arrays)?;
+        context.set_pin_value("struct", nested).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten, unflatten};
+    use flow_like_types::{Value, json::json};
+
+    #[test]
+    fn flattens_with_depth_and_arrays() {
+        let value = json!({"a": {"b": 1, "c": {"d": [1, {"e": 2}]}}, "f": {}});
+
+        let flat = flatten(value.clone(), ".", 0, false).unwrap();
+        assert_eq!(
+            Value::Object(flat),
+            json!({"a.b": 1, "a.c.d": [1, {"e": 2}], "f": {}})
+        );
+
+        let flat = flatten(value.clone(), "_", 2, false).unwrap();
+        assert_eq!(
+            Value::Object(flat),
+            json!({"a_b": 1, "a_c": {"d": [1, {"e": 2}]}, "f": {}})
+        );
+
+        let flat = flatten(value.clone(), ".", 0, true).unwrap();
+        assert_eq!(
+            Value::Object(flat.clone()),
+            json!({"a.b": 1, "a.c.d.0": 1, "a.c.d.1.e": 2, "f": {}})
+        );
+
+        assert_eq!(unflatten(flat, ".", true).unwrap(), value);
+    }
+
+    #[test]
+    fn unflatten_rejects_conflicts() {
+        let flat = json!({"a": 1, "a.b": 2});
+        let Value::Object(flat) = flat else {
+            unreachable!()
+        };
+        assert!(unflatten(flat, ".", false).is_err());
+    }
+}
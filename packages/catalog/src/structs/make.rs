@@ -6,8 +6,7 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::async_trait;
-use std::collections::HashMap;
+use flow_like_types::{async_trait, json::Map};
 
 #[derive(Default)]
 pub struct MakeStructNode {}
@@ -35,7 +34,7 @@ impl NodeLogic for MakeStructNode {
     }
 
     async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
-        let empty_struct: HashMap<String, flow_like_types::Value> = HashMap::new();
+        let empty_struct: Map<String, flow_like_types::Value> = Map::new();
         context
             .set_pin_value("struct", flow_like_types::json::json!(empty_struct))
             .await?;
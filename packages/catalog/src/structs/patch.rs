@@ -0,0 +1,609 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, bail, json::Map, json::json};
+use std::collections::HashMap;
+
+/// How arrays are compared when building a JSON Patch.
+#[derive(Clone, Copy)]
+enum ArrayDiff<'a> {
+    /// Items at the same position are compared.
+    Index,
+    /// Objects with the same value at the key are compared. Matched items keep their old
+    /// order, new items are appended.
+    Key(&'a str),
+}
+
+/// Escapes a key for use as a JSON Pointer segment (RFC 6901).
+fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// RFC 6902 JSON Patch turning `old` into `new`, empty if both are equal.
+fn json_patch(old: &Value, new: &Value, arrays: ArrayDiff) -> Vec<Value> {
+    let mut ops = vec![];
+    diff_into(&mut ops, "", old, new, arrays);
+    ops
+}
+
+fn diff_into(ops: &mut Vec<Value>, path: &str, old: &Value, new: &Value, arrays: ArrayDiff) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = format!("{}/{}", path, escape_pointer(key));
+                match new.get(key) {
+                    Some(new_value) => diff_into(ops, &child, old_value, new_value, arrays),
+                    None => ops.push(json!({"op": "remove", "path": child})),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let child = format!("{}/{}", path, escape_pointer(key));
+                    ops.push(json!({"op": "add", "path": child, "value": new_value}));
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => match arrays {
+            ArrayDiff::Key(key) => match (key_index(old, key), key_index(new, key)) {
+                (Some(old_keys), Some(new_keys)) => {
+                    diff_arrays_by_key(ops, path, old, new, &old_keys, &new_keys, arrays)
+                }
+                _ => diff_arrays_by_index(ops, path, old, new, arrays),
+            },
+            ArrayDiff::Index => diff_arrays_by_index(ops, path, old, new, arrays),
+        },
+        _ => ops.push(json!({"op": "replace", "path": path, "value": new})),
+    }
+}
+
+fn diff_arrays_by_index(
+    ops: &mut Vec<Value>,
+    path: &str,
+    old: &[Value],
+    new: &[Value],
+    arrays: ArrayDiff,
+) {
+    let shared = old.len().min(new.len());
+    for index in 0..shared {
+        let child = format!("{}/{}", path, index);
+        diff_into(ops, &child, &old[index], &new[index], arrays);
+    }
+    // remove from the back so earlier indices stay valid
+    for index in (shared..old.len()).rev() {
+        ops.push(json!({"op": "remove", "path": format!("{}/{}", path, index)}));
+    }
+    for value in &new[shared..] {
+        ops.push(json!({"op": "add", "path": format!("{}/-", path), "value": value}));
+    }
+}
+
+/// Key value of every item, `None` if an item isn't an object with the key or keys repeat.
+fn key_index(items: &[Value], key: &str) -> Option<Vec<String>> {
+    let keys = items
+        .iter()
+        .map(|item| item.as_object()?.get(key).map(Value::to_string))
+        .collect::<Option<Vec<_>>>()?;
+    let mut seen = std::collections::HashSet::new();
+    keys.iter().all(|key| seen.insert(key)).then_some(keys)
+}
+
+fn diff_arrays_by_key(
+    ops: &mut Vec<Value>,
+    path: &str,
+    old: &[Value],
+    new: &[Value],
+    old_keys: &[String],
+    new_keys: &[String],
+    arrays: ArrayDiff,
+) {
+    let new_positions: HashMap<&String, usize> = new_keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (key, index))
+        .collect();
+
+    for index in (0..old.len()).rev() {
+        if !new_positions.contains_key(&old_keys[index]) {
+            ops.push(json!({"op": "remove", "path": format!("{}/{}", path, index)}));
+        }
+    }
+
+    let kept = old_keys
+        .iter()
+        .zip(old)
+        .filter_map(|(key, value)| new_positions.get(key).map(|position| (*position, value)));
+    for (index, (position, old_value)) in kept.enumerate() {
+        let child = format!("{}/{}", path, index);
+        diff_into(ops, &child, old_value, &new[position], arrays);
+    }
+
+    let old_positions: std::collections::HashSet<&String> = old_keys.iter().collect();
+    for (key, value) in new_keys.iter().zip(new) {
+        if !old_positions.contains(key) {
+            ops.push(json!({"op": "add", "path": format!("{}/-", path), "value": value}));
+        }
+    }
+}
+
+/// RFC 7386 JSON Merge Patch turning `old` into `new`, `{}` if both are equal.
+/// Arrays are replaced as a whole, and values can't be set to null since null removes a key.
+fn merge_patch(old: &Value, new: &Value) -> Value {
+    let (Value::Object(old), Value::Object(new)) = (old, new) else {
+        return new.clone();
+    };
+
+    let mut patch = Map::new();
+    for key in old.keys() {
+        if !new.contains_key(key) {
+            patch.insert(key.clone(), Value::Null);
+        }
+    }
+    for (key, new_value) in new {
+        match old.get(key) {
+            Some(old_value) if old_value == new_value => {}
+            Some(old_value @ Value::Object(_)) if new_value.is_object() => {
+                patch.insert(key.clone(), merge_patch(old_value, new_value));
+            }
+            _ => {
+                patch.insert(key.clone(), new_value.clone());
+            }
+        }
+    }
+    Value::Object(patch)
+}
+
+fn unescape_pointer(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// Splits a JSON Pointer into its unescaped segments, the empty pointer is the root.
+fn pointer_segments(pointer: &str) -> flow_like_types::Result<Vec<String>> {
+    if pointer.is_empty() {
+        return Ok(vec![]);
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        bail!("Path '{}' has to start with '/'", pointer);
+    };
+    Ok(rest.split('/').map(unescape_pointer).collect())
+}
+
+fn array_index(segment: &str, len: usize, allow_end: bool) -> Option<usize> {
+    if allow_end && segment == "-" {
+        return Some(len);
+    }
+    let index = segment.parse::<usize>().ok()?;
+    let max = if allow_end {
+        len
+    } else {
+        len.saturating_sub(1)
+    };
+    (index <= max && (allow_end || len > 0)).then_some(index)
+}
+
+/// The value holding the last segment of `segments`.
+fn parent_mut<'a>(
+    doc: &'a mut Value,
+    segments: &[String],
+) -> flow_like_types::Result<&'a mut Value> {
+    let mut current = doc;
+    for segment in segments {
+        current = match current {
+            Value::Object(map) => map.get_mut(segment),
+            Value::Array(items) => {
+                array_index(segment, items.len(), false).and_then(move |index| items.get_mut(index))
+            }
+            _ => None,
+        }
+        .ok_or_else(|| anyhow!("'{}' does not exist", segment))?;
+    }
+    Ok(current)
+}
+
+fn get_pointer(doc: &Value, pointer: &str) -> flow_like_types::Result<Value> {
+    doc.pointer(pointer)
+        .cloned()
+        .ok_or_else(|| anyhow!("Nothing found at '{}'", pointer))
+}
+
+fn add_pointer(doc: &mut Value, pointer: &str, value: Value) -> flow_like_types::Result<()> {
+    let segments = pointer_segments(pointer)?;
+    let Some((last, parents)) = segments.split_last() else {
+        *doc = value;
+        return Ok(());
+    };
+
+    match parent_mut(doc, parents)? {
+        Value::Object(map) => {
+            map.insert(last.clone(), value);
+        }
+        Value::Array(items) => {
+            let index = array_index(last, items.len(), true)
+                .ok_or_else(|| anyhow!("Index '{}' is out of bounds", last))?;
+            items.insert(index, value);
+        }
+        _ => bail!("Parent of '{}' is not a struct or array", pointer),
+    }
+    Ok(())
+}
+
+fn remove_pointer(doc: &mut Value, pointer: &str) -> flow_like_types::Result<Value> {
+    let segments = pointer_segments(pointer)?;
+    let Some((last, parents)) = segments.split_last() else {
+        bail!("The root can't be removed");
+    };
+
+    match parent_mut(doc, parents)? {
+        Value::Object(map) => map
+            .remove(last)
+            .ok_or_else(|| anyhow!("Nothing found at '{}'", pointer)),
+        Value::Array(items) => {
+            let index = array_index(last, items.len(), false)
+                .ok_or_else(|| anyhow!("Index '{}' is out of bounds", last))?;
+            Ok(items.remove(index))
+        }
+        _ => bail!("Parent of '{}' is not a struct or array", pointer),
+    }
+}
+
+fn apply_operation(doc: &mut Value, operation: &Value) -> flow_like_types::Result<()> {
+    let field = |name: &str| {
+        operation
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("Missing '{}'", name))
+    };
+    let value = || {
+        operation
+            .get("value")
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing 'value'"))
+    };
+
+    let path = field("path")?;
+    match field("op")? {
+        "add" => add_pointer(doc, path, value()?),
+        "remove" => remove_pointer(doc, path).map(|_| ()),
+        "replace" => {
+            let target = doc
+                .pointer_mut(path)
+                .ok_or_else(|| anyhow!("Nothing found at '{}'", path))?;
+            *target = value()?;
+            Ok(())
+        }
+        "move" => {
+            let from = field("from")?;
+            if path.starts_with(&format!("{}/", from)) {
+                bail!("'{}' can't be moved into itself", from);
+            }
+            let moved = remove_pointer(doc, from)?;
+            add_pointer(doc, path, moved)
+        }
+        "copy" => {
+            let copied = get_pointer(doc, field("from")?)?;
+            add_pointer(doc, path, copied)
+        }
+        "test" => {
+            if get_pointer(doc, path)? != value()? {
+                bail!("Value at '{}' doesn't match", path);
+            }
+            Ok(())
+        }
+        op => bail!("Unknown operation '{}'", op),
+    }
+}
+
+/// Applies an RFC 6902 JSON Patch. Operations are atomic one by one: a failing operation leaves
+/// the document untouched and either aborts with its index and path, or is skipped if `lenient`.
+/// Returns the number of skipped operations.
+fn apply_json_patch(
+    doc: &mut Value,
+    operations: &[Value],
+    lenient: bool,
+) -> flow_like_types::Result<usize> {
+    let mut skipped = 0;
+    for (index, operation) in operations.iter().enumerate() {
+        let mut patched = doc.clone();
+        match apply_operation(&mut patched, operation) {
+            Ok(()) => *doc = patched,
+            Err(_) if lenient => skipped += 1,
+            Err(error) => {
+                let path = operation.get("path").and_then(Value::as_str).unwrap_or("");
+                bail!("Patch operation {} at '{}' failed: {}", index, path, error);
+            }
+        }
+    }
+    Ok(skipped)
+}
+
+/// Applies an RFC 7386 JSON Merge Patch, null values remove keys.
+fn apply_merge_patch(doc: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *doc = patch.clone();
+        return;
+    };
+
+    if !doc.is_object() {
+        *doc = Value::Object(Map::new());
+    }
+    let Value::Object(map) = doc else {
+        return;
+    };
+
+    for (key, value) in patch {
+        if value.is_null() {
+            map.remove(key);
+            continue;
+        }
+        apply_merge_patch(map.entry(key.clone()).or_insert(Value::Null), value);
+    }
+}
+
+#[derive(Default)]
+pub struct DiffStructsNode {}
+
+impl DiffStructsNode {
+    pub fn new() -> Self {
+        DiffStructsNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for DiffStructsNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_diff",
+            "Diff Structs",
+            "Describes the changes from Old to New as a JSON Patch or JSON Merge Patch",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin("old", "Old", "Original struct", VariableType::Struct);
+        node.add_input_pin("new", "New", "Changed struct", VariableType::Struct);
+
+        node.add_input_pin(
+            "format",
+            "Format",
+            "JSON Patch (RFC 6902) lists operations, JSON Merge Patch (RFC 7386) is a partial struct that replaces arrays as a whole",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["JSON Patch".to_string(), "Merge Patch".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("JSON Patch")));
+
+        node.add_input_pin(
+            "array_key",
+            "Array Key",
+            "JSON Patch only: match array items by this key instead of by position, e.g. id. Empty compares by position",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_output_pin(
+            "patch",
+            "Patch",
+            "Patch from Old to New",
+            VariableType::Struct,
+        );
+        node.add_output_pin(
+            "changed",
+            "Changed",
+            "False if Old and New are equal",
+            VariableType::Boolean,
+        );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let old: Value = context.evaluate_pin("old").await?;
+        let new: Value = context.evaluate_pin("new").await?;
+        let format: String = context.evaluate_pin("format").await?;
+        let array_key: String = context.evaluate_pin("array_key").await?;
+
+        let arrays = if array_key.is_empty() {
+            ArrayDiff::Index
+        } else {
+            ArrayDiff::Key(&array_key)
+        };
+
+        let patch = match format.as_str() {
+            "Merge Patch" => merge_patch(&old, &new),
+            _ => Value::Array(json_patch(&old, &new, arrays)),
+        };
+
+        context.set_pin_value("patch", patch).await?;
+        context.set_pin_value("changed", json!(old != new)).await?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct ApplyPatchNode {}
+
+impl ApplyPatchNode {
+    pub fn new() -> Self {
+        ApplyPatchNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ApplyPatchNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_apply_patch",
+            "Apply Patch",
+            "Applies a JSON Patch (array of operations) or JSON Merge Patch (partial struct) without touching unrelated fields",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin(
+            "struct_in",
+            "Struct",
+            "Struct to patch",
+            VariableType::Struct,
+        );
+        node.add_input_pin(
+            "patch",
+            "Patch",
+            "JSON Patch or JSON Merge Patch, e.g. from Diff Structs",
+            VariableType::Struct,
+        );
+        node.add_input_pin(
+            "lenient",
+            "Lenient",
+            "Skip JSON Patch operations that can't be applied instead of failing",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "struct_out",
+            "Struct",
+            "Patched struct",
+            VariableType::Struct,
+        );
+        node.add_output_pin(
+            "skipped",
+            "Skipped",
+            "Number of operations skipped in lenient mode",
+            VariableType::Integer,
+        );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let mut value: Value = context.evaluate_pin("struct_in").await?;
+        let patch: Value = context.evaluate_pin("patch").await?;
+        let lenient: bool = context.evaluate_pin("lenient").await?;
+
+        let skipped = match &patch {
+            Value::Array(operations) => apply_json_patch(&mut value, operations, lenient)?,
+            Value::Object(_) => {
+                apply_merge_patch(&mut value, &patch);
+                0
+            }
+            other => bail!("Patch has to be an array or a struct, got {}", other),
+        };
+
+        context.set_pin_value("struct_out", value).await?;
+        context.set_pin_value("skipped", json!(skipped)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_values_produce_empty_patches() {
+        let value = json!({"a": 1, "b": [1, 2]});
+        assert!(json_patch(&value, &value, ArrayDiff::Index).is_empty());
+        assert_eq!(merge_patch(&value, &value), json!({}));
+    }
+
+    #[test]
+    fn diffs_nested_objects() {
+        let old = json!({"a": {"b": 1, "c": 2}, "d/e": true});
+        let new = json!({"a": {"b": 3}, "f": null});
+        assert_eq!(
+            json_patch(&old, &new, ArrayDiff::Index),
+            vec![
+                json!({"op": "replace", "path": "/a/b", "value": 3}),
+                json!({"op": "remove", "path": "/a/c"}),
+                json!({"op": "remove", "path": "/d~1e"}),
+                json!({"op": "add", "path": "/f", "value": null}),
+            ]
+        );
+        assert_eq!(
+            merge_patch(&old, &new),
+            json!({"a": {"b": 3, "c": null}, "d/e": null, "f": null})
+        );
+    }
+
+    #[test]
+    fn diffs_arrays_by_index() {
+        let old = json!([1, 2, 3]);
+        let new = json!([1, 5]);
+        assert_eq!(
+            json_patch(&old, &new, ArrayDiff::Index),
+            vec![
+                json!({"op": "replace", "path": "/1", "value": 5}),
+                json!({"op": "remove", "path": "/2"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_arrays_by_key() {
+        let old = json!([{"id": 1, "v": "a"}, {"id": 2, "v": "b"}]);
+        let new = json!([{"id": 3, "v": "c"}, {"id": 2, "v": "x"}]);
+        assert_eq!(
+            json_patch(&old, &new, ArrayDiff::Key("id")),
+            vec![
+                json!({"op": "remove", "path": "/0"}),
+                json!({"op": "replace", "path": "/0/v", "value": "x"}),
+                json!({"op": "add", "path": "/-", "value": {"id": 3, "v": "c"}}),
+            ]
+        );
+    }
+
+    #[test]
+    fn applying_a_diff_yields_the_new_value() {
+        let old = json!({"a": {"b": 1, "c": [1, 2, 3]}, "d": "x"});
+        let new = json!({"a": {"b": 2, "c": [1, 4]}, "e": true});
+
+        let mut patched = old.clone();
+        let ops = json_patch(&old, &new, ArrayDiff::Index);
+        assert_eq!(apply_json_patch(&mut patched, &ops, false).unwrap(), 0);
+        assert_eq!(patched, new);
+
+        let mut merged = old.clone();
+        apply_merge_patch(&mut merged, &merge_patch(&old, &new));
+        assert_eq!(merged, new);
+    }
+
+    #[test]
+    fn supports_move_copy_and_test() {
+        let mut doc = json!({"a": 1, "list": [1, 2]});
+        let ops = vec![
+            json!({"op": "test", "path": "/a", "value": 1}),
+            json!({"op": "copy", "from": "/a", "path": "/list/0"}),
+            json!({"op": "move", "from": "/a", "path": "/b"}),
+        ];
+        apply_json_patch(&mut doc, &ops, false).unwrap();
+        assert_eq!(doc, json!({"b": 1, "list": [1, 1, 2]}));
+    }
+
+    #[test]
+    fn failing_operations_report_the_path_or_are_skipped() {
+        let ops = vec![
+            json!({"op": "remove", "path": "/missing"}),
+            json!({"op": "add", "path": "/b", "value": 2}),
+        ];
+
+        let mut doc = json!({"a": 1});
+        let error = apply_json_patch(&mut doc, &ops, false).unwrap_err();
+        assert!(error.to_string().contains("/missing"));
+        assert_eq!(doc, json!({"a": 1}));
+
+        assert_eq!(apply_json_patch(&mut doc, &ops, true).unwrap(), 1);
+        assert_eq!(doc, json!({"a": 1, "b": 2}));
+    }
+}
@@ -0,0 +1,187 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::Map, json::json};
+
+/// Turns rows into one array per key, in order of first appearance. Rows lacking a key
+/// get null in its column, so every column has one entry per row.
+fn rows_to_columns(rows: &[Value]) -> flow_like_types::Result<Map<String, Value>> {
+    let mut columns: Map<String, Value> = Map::new();
+    for (index, row) in rows.iter().enumerate() {
+        let row = row
+            .as_object()
+            .ok_or_else(|| anyhow!("Row {} is not a struct", index))?;
+        for key in row.keys() {
+            if !columns.contains_key(key) {
+                columns.insert(key.clone(), Value::Array(vec![Value::Null; index]));
+            }
+        }
+        for (key, column) in columns.iter_mut() {
+            if let Value::Array(column) = column {
+                column.push(row.get(key).cloned().unwrap_or(Value::Null));
+            }
+        }
+    }
+    Ok(columns)
+}
+
+/// Turns one array per key back into rows. Shorter columns are padded with null.
+fn columns_to_rows(columns: &Map<String, Value>) -> flow_like_types::Result<Vec<Value>> {
+    let mut arrays = vec![];
+    for (key, column) in columns {
+        let column = column
+            .as_array()
+            .ok_or_else(|| anyhow!("Column '{}' is not an array", key))?;
+        arrays.push((key, column));
+    }
+
+    let len = arrays
+        .iter()
+        .map(|(_, column)| column.len())
+        .max()
+        .unwrap_or(0);
+    let rows = (0..len)
+        .map(|index| {
+            let row: Map<String, Value> = arrays
+                .iter()
+                .map(|(key, column)| {
+                    let value = column.get(index).cloned().unwrap_or(Value::Null);
+                    ((*key).clone(), value)
+                })
+                .collect();
+            Value::Object(row)
+        })
+        .collect();
+    Ok(rows)
+}
+
+#[derive(Default)]
+pub struct TransposeRecordsNode {}
+
+impl TransposeRecordsNode {
+    pub fn new() -> Self {
+        TransposeRecordsNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for TransposeRecordsNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_records_to_columns",
+            "Records To Columns",
+            "Turns an array of rows like {a: 1} into a struct of columns like {a: [1, ...]}, e.g. to feed search results into array math or charts. Missing keys become null",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin(
+            "records",
+            "Records",
+            "Rows to transpose",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "columns",
+            "Columns",
+            "One array per key, with an entry for every row",
+            VariableType::Struct,
+        );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let records: Vec<Value> = context.evaluate_pin("records").await?;
+        let columns = rows_to_columns(&records)?;
+        context
+            .set_pin_value("columns", Value::Object(columns))
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct RecordsFromColumnsNode {}
+
+impl RecordsFromColumnsNode {
+    pub fn new() -> Self {
+        RecordsFromColumnsNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for RecordsFromColumnsNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "struct_columns_to_records",
+            "Columns To Records",
+            "Turns a struct of columns like {a: [1, ...]} back into an array of rows like {a: 1}. Shorter columns are padded with null",
+            "Structs",
+        );
+        node.add_icon("/flow/icons/struct.svg");
+
+        node.add_input_pin(
+            "columns",
+            "Columns",
+            "Struct with an array per key",
+            VariableType::Struct,
+        );
+
+        node.add_output_pin("records", "Records", "Rows", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let columns: Value = context.evaluate_pin("columns").await?;
+        let columns = columns
+            .as_object()
+            .ok_or_else(|| anyhow!("Columns have to be a struct"))?;
+        let records = columns_to_rows(columns)?;
+        context.set_pin_value("records", json!(records)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_missing_keys_with_null() {
+        let rows = vec![
+            json!({"a": 1}),
+            json!({"a": 2, "b": "x"}),
+            json!({"b": "y"}),
+        ];
+        let columns = rows_to_columns(&rows).unwrap();
+        assert_eq!(
+            Value::Object(columns),
+            json!({"a": [1, 2, null], "b": [null, "x", "y"]})
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let rows = vec![json!({"a": 1, "b": true}), json!({"a": 2, "b": false})];
+        let columns = rows_to_columns(&rows).unwrap();
+        assert_eq!(columns_to_rows(&columns).unwrap(), rows);
+
+        let uneven = json!({"a": [1, 2], "b": [3]});
+        assert_eq!(
+            columns_to_rows(uneven.as_object().unwrap()).unwrap(),
+            vec![json!({"a": 1, "b": 3}), json!({"a": 2, "b": null})]
+        );
+        assert!(columns_to_rows(json!({"a": 1}).as_object().unwrap()).is_err());
+    }
+}
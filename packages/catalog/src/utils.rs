@@ -2,13 +2,16 @@ pub mod array;
 pub mod bool;
 pub mod csv;
 pub mod cuid;
+pub mod encoding;
 pub mod env;
 pub mod float;
 pub mod hash;
 pub mod int;
 pub mod json;
+pub mod map;
 pub mod math;
 pub mod md;
+pub mod number;
 pub mod set;
 pub mod string;
 pub mod types;
@@ -29,12 +32,15 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
     registry.append(&mut string::register_functions().await);
     registry.append(&mut array::register_functions().await);
     registry.append(&mut set::register_functions().await);
+    registry.append(&mut map::register_functions().await);
     registry.append(&mut vector::register_functions().await);
     registry.append(&mut float::register_functions().await);
     registry.append(&mut int::register_functions().await);
+    registry.append(&mut number::register_functions().await);
     registry.append(&mut csv::register_functions().await);
     registry.append(&mut md::register_functions().await);
     registry.append(&mut hash::register_functions().await);
+    registry.append(&mut encoding::register_functions().await);
     registry.push(Arc::new(math::eval::EvalNode::default()));
     registry
 }
@@ -1,7 +1,9 @@
-use flow_like::flow::node::NodeLogic;
+use flow_like::flow::{execution::context::ExecutionContext, node::NodeLogic};
+use flow_like_types::rand::{SeedableRng, rngs::StdRng};
 use std::sync::Arc;
 
 pub mod clear;
+pub mod diff_by_key;
 pub mod extend;
 pub mod find_item;
 pub mod get;
@@ -11,8 +13,12 @@ pub mod make;
 pub mod pop;
 pub mod push;
 pub mod remove_index;
+pub mod sample;
 pub mod set;
 pub mod shuffle;
+pub mod split;
+pub mod top_k;
+pub mod weighted_sample;
 
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
     vec![
@@ -28,5 +34,19 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(clear::ClearArrayNode::default()),
         Arc::new(find_item::FindItemInArrayNode::default()),
         Arc::new(shuffle::ShuffleArrayNode::default()),
+        Arc::new(diff_by_key::DiffArraysByKeyNode::default()),
+        Arc::new(top_k::TopKNode::default()),
+        Arc::new(sample::SampleArrayNode::default()),
+        Arc::new(split::SplitArrayNode::default()),
+        Arc::new(weighted_sample::WeightedSampleNode::default()),
     ]
 }
+
+/// Generator for nodes with an optional `seed` pin. A negative seed falls back to the run's
+/// generator, which is reproducible if the run itself is seeded.
+pub(crate) fn seeded_rng(context: &ExecutionContext, seed: i64) -> StdRng {
+    if seed < 0 {
+        return context.rng();
+    }
+    StdRng::seed_from_u64(seed as u64)
+}
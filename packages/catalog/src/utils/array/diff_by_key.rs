@@ -0,0 +1,172 @@
+use ahash::AHashMap;
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+
+#[derive(Default)]
+pub struct DiffArraysByKeyNode {}
+
+impl DiffArraysByKeyNode {
+    pub fn new() -> Self {
+        DiffArraysByKeyNode {}
+    }
+}
+
+type RecordDiff = (Vec<Value>, Vec<Value>, Vec<Value>);
+
+fn record_key(record: &Value, key: &str, side: &str) -> flow_like_types::Result<String> {
+    let value = record
+        .get(key)
+        .ok_or_else(|| anyhow!("{} record is missing key '{}': {}", side, key, record))?;
+    Ok(value.to_string())
+}
+
+/// Returns (to insert, to update, to delete). Records are compared by their JSON content.
+fn diff_by_key(
+    desired: &[Value],
+    existing: &[Value],
+    key: &str,
+) -> flow_like_types::Result<RecordDiff> {
+    let mut existing_by_key = AHashMap::with_capacity(existing.len());
+    for record in existing {
+        existing_by_key.insert(record_key(record, key, "Existing")?, record);
+    }
+
+    let mut to_insert = vec![];
+    let mut to_update = vec![];
+    for record in desired {
+        match existing_by_key.remove(&record_key(record, key, "Desired")?) {
+            None => to_insert.push(record.clone()),
+            Some(current) if current != record => to_update.push(record.clone()),
+            Some(_) => {}
+        }
+    }
+
+    // keep the order of the existing array for deletions
+    let mut to_delete = vec![];
+    for record in existing {
+        if existing_by_key
+            .remove(&record_key(record, key, "Existing")?)
+            .is_some()
+        {
+            to_delete.push(record.clone());
+        }
+    }
+
+    Ok((to_insert, to_update, to_delete))
+}
+
+#[async_trait]
+impl NodeLogic for DiffArraysByKeyNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "array_diff_by_key",
+            "Diff By Key",
+            "Compares desired records against existing ones by a key, e.g. to reconcile a table with upsert and delete",
+            "Utils/Array",
+        );
+
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin(
+            "desired",
+            "Desired",
+            "Records that should exist",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "existing",
+            "Existing",
+            "Records that currently exist",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "key",
+            "Key",
+            "Field identifying a record",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("id")));
+
+        node.add_output_pin(
+            "to_insert",
+            "To Insert",
+            "Desired records whose key does not exist yet",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "to_update",
+            "To Update",
+            "Desired records whose key exists with different content",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_output_pin(
+            "to_delete",
+            "To Delete",
+            "Existing records whose key is not desired anymore",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let desired: Vec<Value> = context.evaluate_pin("desired").await?;
+        let existing: Vec<Value> = context.evaluate_pin("existing").await?;
+        let key: String = context.evaluate_pin("key").await?;
+
+        let (to_insert, to_update, to_delete) = diff_by_key(&desired, &existing, &key)?;
+
+        context.set_pin_value("to_insert", json!(to_insert)).await?;
+        context.set_pin_value("to_update", json!(to_update)).await?;
+        context.set_pin_value("to_delete", json!(to_delete)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_by_key;
+    use flow_like_types::json::json;
+
+    #[test]
+    fn splits_insert_update_delete() {
+        let desired = vec![
+            json!({"id": 1, "text": "same"}),
+            json!({"id": 2, "text": "changed"}),
+            json!({"id": 3, "text": "new"}),
+        ];
+        let existing = vec![
+            json!({"id": 1, "text": "same"}),
+            json!({"id": 2, "text": "old"}),
+            json!({"id": 4, "text": "gone"}),
+        ];
+
+        let (insert, update, delete) = diff_by_key(&desired, &existing, "id").unwrap();
+        assert_eq!(insert, vec![json!({"id": 3, "text": "new"})]);
+        assert_eq!(update, vec![json!({"id": 2, "text": "changed"})]);
+        assert_eq!(delete, vec![json!({"id": 4, "text": "gone"})]);
+    }
+
+    #[test]
+    fn missing_key_is_an_error() {
+        let desired = vec![json!({"name": "no id"})];
+        assert!(diff_by_key(&desired, &[], "id").is_err());
+    }
+}
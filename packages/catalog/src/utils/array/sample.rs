@@ -0,0 +1,179 @@
+/// # Sample Node
+/// Randomly pick a subset of array elements
+use super::seeded_rng;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Value, async_trait,
+    json::json,
+    rand::{Rng, seq::SliceRandom},
+};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SampleArrayNode {}
+
+impl SampleArrayNode {
+    pub fn new() -> Self {
+        SampleArrayNode {}
+    }
+}
+
+/// Picks `count` items in random order. Without replacement the count is capped at the
+/// array length, so asking for more returns the whole array shuffled.
+fn sample<T: Clone, R: Rng>(
+    mut items: Vec<T>,
+    count: usize,
+    with_replacement: bool,
+    rng: &mut R,
+) -> Vec<T> {
+    if items.is_empty() {
+        return items;
+    }
+
+    if with_replacement {
+        return (0..count)
+            .map(|_| items[rng.random_range(0..items.len())].clone())
+            .collect();
+    }
+
+    let count = count.min(items.len());
+    let (sampled, _) = items.partial_shuffle(rng, count);
+    sampled.to_vec()
+}
+
+#[async_trait]
+impl NodeLogic for SampleArrayNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "array_sample",
+            "Sample",
+            "Randomly picks a number or fraction of array items, reproducible with a seed",
+            "Utils/Array",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin("array_in", "Array", "Your Array", VariableType::Generic)
+            .set_value_type(ValueType::Array)
+            .set_options(
+                PinOptions::new()
+                    .set_enforce_generic_value_type(true)
+                    .build(),
+            );
+
+        node.add_input_pin(
+            "count",
+            "Count",
+            "Number of items to pick, used if Fraction is 0",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(1)));
+
+        node.add_input_pin(
+            "fraction",
+            "Fraction",
+            "Share of the array to pick, e.g. 0.2 for 20%, takes precedence over Count if above 0",
+            VariableType::Float,
+        )
+        .set_default_value(Some(json!(0.0)));
+
+        node.add_input_pin(
+            "with_replacement",
+            "With Replacement",
+            "Allow picking the same item multiple times",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_input_pin(
+            "seed",
+            "Seed",
+            "Fixed seed, negative values use the run's seed",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(-1)));
+
+        node.add_output_pin("array_out", "Array", "Sampled items", VariableType::Generic)
+            .set_value_type(ValueType::Array)
+            .set_options(
+                PinOptions::new()
+                    .set_enforce_generic_value_type(true)
+                    .build(),
+            );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let array: Vec<Value> = context.evaluate_pin("array_in").await?;
+        let count: i64 = context.evaluate_pin("count").await?;
+        let fraction: f64 = context.evaluate_pin("fraction").await?;
+        let with_replacement: bool = context.evaluate_pin("with_replacement").await?;
+        let seed: i64 = context.evaluate_pin("seed").await?;
+
+        let count = if fraction > 0.0 {
+            (array.len() as f64 * fraction).round() as usize
+        } else {
+            count.max(0) as usize
+        };
+
+        let mut rng = seeded_rng(context, seed);
+        let sampled = sample(array, count, with_replacement, &mut rng);
+        context.set_pin_value("array_out", json!(sampled)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("array_out", board.clone(), Some(ValueType::Array), None);
+        let _ = node.match_type("array_in", board.clone(), Some(ValueType::Array), None);
+        node.harmonize_type(vec!["array_in", "array_out"], true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flow_like_types::rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn oversampling_without_replacement_returns_everything() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut sampled = sample((0..5).collect(), 10, false, &mut rng);
+        sampled.sort();
+        assert_eq!(sampled, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn sampling_is_reproducible_with_a_seed() {
+        let first = sample(
+            (0..100).collect::<Vec<_>>(),
+            10,
+            false,
+            &mut StdRng::seed_from_u64(1),
+        );
+        let second = sample(
+            (0..100).collect::<Vec<_>>(),
+            10,
+            false,
+            &mut StdRng::seed_from_u64(1),
+        );
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 10);
+    }
+
+    #[test]
+    fn with_replacement_can_exceed_length() {
+        let sampled = sample(vec![1, 2], 5, true, &mut StdRng::seed_from_u64(3));
+        assert_eq!(sampled.len(), 5);
+        assert!(sampled.iter().all(|item| *item == 1 || *item == 2));
+        assert!(sample(Vec::<i32>::new(), 5, true, &mut StdRng::seed_from_u64(3)).is_empty());
+    }
+}
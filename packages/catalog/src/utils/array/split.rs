@@ -0,0 +1,216 @@
+/// # Split Node
+/// Randomly partition an array into two parts, e.g. train and test
+use super::seeded_rng;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Value, async_trait,
+    json::json,
+    rand::{Rng, seq::SliceRandom},
+};
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(Default)]
+pub struct SplitArrayNode {}
+
+impl SplitArrayNode {
+    pub fn new() -> Self {
+        SplitArrayNode {}
+    }
+}
+
+/// Shuffles `items` and puts `ratio` of them into the first part. With a `stratify_key` every
+/// value of that key is split on its own, so both parts keep the class proportions.
+/// Items without the key form their own class.
+fn split<R: Rng>(
+    items: Vec<Value>,
+    ratio: f64,
+    stratify_key: Option<&str>,
+    rng: &mut R,
+) -> (Vec<Value>, Vec<Value>) {
+    let ratio = ratio.clamp(0.0, 1.0);
+
+    let Some(key) = stratify_key else {
+        return split_group(items, ratio, rng);
+    };
+
+    let mut class_index: HashMap<String, usize> = HashMap::new();
+    let mut classes: Vec<Vec<Value>> = vec![];
+    for item in items {
+        let label = item.get(key).cloned().unwrap_or(Value::Null).to_string();
+        let index = *class_index.entry(label).or_insert_with(|| {
+            classes.push(vec![]);
+            classes.len() - 1
+        });
+        classes[index].push(item);
+    }
+
+    let mut first = vec![];
+    let mut second = vec![];
+    for class in classes {
+        let (class_first, class_second) = split_group(class, ratio, rng);
+        first.extend(class_first);
+        second.extend(class_second);
+    }
+
+    first.shuffle(rng);
+    second.shuffle(rng);
+    (first, second)
+}
+
+fn split_group<R: Rng>(mut items: Vec<Value>, ratio: f64, rng: &mut R) -> (Vec<Value>, Vec<Value>) {
+    items.shuffle(rng);
+    let at = ((items.len() as f64) * ratio).round() as usize;
+    let second = items.split_off(at.min(items.len()));
+    (items, second)
+}
+
+#[async_trait]
+impl NodeLogic for SplitArrayNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "array_split",
+            "Split",
+            "Randomly splits an array into two parts by ratio, optionally stratified by a label key",
+            "Utils/Array",
+        );
+        node.add_icon("/flow/icons/split.svg");
+
+        node.add_input_pin("array_in", "Array", "Your Array", VariableType::Generic)
+            .set_value_type(ValueType::Array)
+            .set_options(
+                PinOptions::new()
+                    .set_enforce_generic_value_type(true)
+                    .build(),
+            );
+
+        node.add_input_pin(
+            "ratio",
+            "Ratio",
+            "Share of items in the first part, e.g. 0.8 for an 80/20 train/test split",
+            VariableType::Float,
+        )
+        .set_options(PinOptions::new().set_range((0.0, 1.0)).build())
+        .set_default_value(Some(json!(0.8)));
+
+        node.add_input_pin(
+            "stratify_key",
+            "Stratify Key",
+            "Object key holding the class label, both parts keep the class proportions. Empty disables stratification",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_input_pin(
+            "seed",
+            "Seed",
+            "Fixed seed, negative values use the run's seed",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(-1)));
+
+        node.add_output_pin(
+            "first",
+            "Train",
+            "First part, Ratio of the items",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array)
+        .set_options(
+            PinOptions::new()
+                .set_enforce_generic_value_type(true)
+                .build(),
+        );
+
+        node.add_output_pin(
+            "second",
+            "Test",
+            "Second part, the remaining items",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array)
+        .set_options(
+            PinOptions::new()
+                .set_enforce_generic_value_type(true)
+                .build(),
+        );
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let array: Vec<Value> = context.evaluate_pin("array_in").await?;
+        let ratio: f64 = context.evaluate_pin("ratio").await?;
+        let stratify_key: String = context.evaluate_pin("stratify_key").await?;
+        let seed: i64 = context.evaluate_pin("seed").await?;
+
+        let stratify_key = Some(stratify_key.as_str()).filter(|key| !key.is_empty());
+        let mut rng = seeded_rng(context, seed);
+        let (first, second) = split(array, ratio, stratify_key, &mut rng);
+
+        context.set_pin_value("first", json!(first)).await?;
+        context.set_pin_value("second", json!(second)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("array_in", board.clone(), Some(ValueType::Array), None);
+        let _ = node.match_type("first", board.clone(), Some(ValueType::Array), None);
+        let _ = node.match_type("second", board.clone(), Some(ValueType::Array), None);
+        node.harmonize_type(vec!["array_in", "first", "second"], true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flow_like_types::rand::{SeedableRng, rngs::StdRng};
+
+    fn labelled(label: &str, count: usize) -> Vec<Value> {
+        (0..count)
+            .map(|id| json!({"id": id, "label": label}))
+            .collect()
+    }
+
+    fn count_label(items: &[Value], label: &str) -> usize {
+        items.iter().filter(|item| item["label"] == label).count()
+    }
+
+    #[test]
+    fn splits_by_ratio() {
+        let items = (0..10).map(|id| json!(id)).collect();
+        let (first, second) = split(items, 0.8, None, &mut StdRng::seed_from_u64(1));
+        assert_eq!(first.len(), 8);
+        assert_eq!(second.len(), 2);
+    }
+
+    #[test]
+    fn stratification_keeps_class_proportions() {
+        let mut items = labelled("a", 80);
+        items.extend(labelled("b", 20));
+        let (first, second) = split(items, 0.5, Some("label"), &mut StdRng::seed_from_u64(2));
+        assert_eq!(count_label(&first, "a"), 40);
+        assert_eq!(count_label(&first, "b"), 10);
+        assert_eq!(count_label(&second, "a"), 40);
+        assert_eq!(count_label(&second, "b"), 10);
+    }
+
+    #[test]
+    fn handles_empty_input_and_missing_labels() {
+        let (first, second) = split(vec![], 0.8, Some("label"), &mut StdRng::seed_from_u64(3));
+        assert!(first.is_empty() && second.is_empty());
+
+        let items = vec![json!({"id": 1}), json!(2), json!({"label": "a"})];
+        let (first, second) = split(items, 1.0, Some("label"), &mut StdRng::seed_from_u64(3));
+        assert_eq!(first.len(), 3);
+        assert!(second.is_empty());
+    }
+}
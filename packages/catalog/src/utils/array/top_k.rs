@@ -0,0 +1,192 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct TopKNode {}
+
+impl TopKNode {
+    pub fn new() -> Self {
+        TopKNode {}
+    }
+}
+
+fn compare_tie(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+/// Selects the `k` best items by `score_field`. Items without a numeric score sort last,
+/// ties are broken by `tie_field` (ascending) and then by the original index.
+fn top_k(
+    items: &[Value],
+    score_field: &str,
+    tie_field: &str,
+    k: usize,
+    highest: bool,
+) -> Vec<Value> {
+    let k = k.min(items.len());
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut ranked: Vec<(usize, Option<f64>)> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let score = item
+                .get(score_field)
+                .and_then(Value::as_f64)
+                .filter(|score| !score.is_nan());
+            (index, score)
+        })
+        .collect();
+
+    let compare = |a: &(usize, Option<f64>), b: &(usize, Option<f64>)| {
+        let by_score = match (a.1, b.1) {
+            (Some(x), Some(y)) if highest => y.total_cmp(&x),
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        let by_tie = || {
+            if tie_field.is_empty() {
+                return Ordering::Equal;
+            }
+            compare_tie(items[a.0].get(tie_field), items[b.0].get(tie_field))
+        };
+        by_score.then_with(by_tie).then(a.0.cmp(&b.0))
+    };
+
+    if k < ranked.len() {
+        ranked.select_nth_unstable_by(k - 1, compare);
+        ranked.truncate(k);
+    }
+    ranked.sort_unstable_by(compare);
+
+    ranked
+        .into_iter()
+        .map(|(index, _)| items[index].clone())
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for TopKNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "array_top_k",
+            "Top K",
+            "Returns the K highest or lowest scored items, sorted. Cheaper than sorting the whole array",
+            "Utils/Array",
+        );
+
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin("array_in", "Array", "Scored items", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "score_field",
+            "Score Field",
+            "Numeric field to rank by, items without it sort last",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("score")));
+
+        node.add_input_pin("k", "K", "Number of items to return", VariableType::Integer)
+            .set_default_value(Some(json!(10)));
+
+        node.add_input_pin(
+            "direction",
+            "Direction",
+            "Return the highest or the lowest scores",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Highest".to_string(), "Lowest".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Highest")));
+
+        node.add_input_pin(
+            "tie_field",
+            "Tie Breaker",
+            "Optional field to order equal scores by, otherwise the original order is kept",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("")));
+
+        node.add_output_pin("array_out", "Array", "Selected items", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let items: Vec<Value> = context.evaluate_pin("array_in").await?;
+        let score_field: String = context.evaluate_pin("score_field").await?;
+        let k: i64 = context.evaluate_pin("k").await?;
+        let direction: String = context.evaluate_pin("direction").await?;
+        let tie_field: String = context.evaluate_pin("tie_field").await?;
+
+        let selected = top_k(
+            &items,
+            &score_field,
+            &tie_field,
+            k.max(0) as usize,
+            direction != "Lowest",
+        );
+
+        context.set_pin_value("array_out", json!(selected)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::top_k;
+    use flow_like_types::json::json;
+
+    #[test]
+    fn selects_and_sorts() {
+        let items = vec![
+            json!({"id": "a", "score": 0.2}),
+            json!({"id": "b"}),
+            json!({"id": "c", "score": 0.9}),
+            json!({"id": "d", "score": 0.5}),
+            json!({"id": "e", "score": 0.9}),
+        ];
+
+        let ids = |items: Vec<flow_like_types::Value>| {
+            items
+                .iter()
+                .map(|i| i["id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(top_k(&items, "score", "", 3, true)), ["c", "e", "d"]);
+        assert_eq!(ids(top_k(&items, "score", "", 2, false)), ["a", "d"]);
+        assert_eq!(
+            ids(top_k(&items, "score", "", 10, false)),
+            ["a", "d", "c", "e", "b"]
+        );
+    }
+}
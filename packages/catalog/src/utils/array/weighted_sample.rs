@@ -0,0 +1,148 @@
+/// # Weighted Sample Node
+/// Picks scored items at random, favoring high scores while keeping some diversity
+use super::seeded_rng;
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json, rand::Rng};
+
+/// Weight of items with a missing, zero, negative or NaN score, so they stay pickable.
+const MIN_WEIGHT: f64 = 1e-9;
+
+#[derive(Default)]
+pub struct WeightedSampleNode {}
+
+impl WeightedSampleNode {
+    pub fn new() -> Self {
+        WeightedSampleNode {}
+    }
+}
+
+/// Picks `k` items without replacement, each with a probability proportional to its score
+/// (Efraimidis-Spirakis). Items are returned in the order they were drawn.
+fn weighted_sample<R: Rng>(
+    items: &[Value],
+    score_field: &str,
+    k: usize,
+    rng: &mut R,
+) -> Vec<Value> {
+    let mut keyed: Vec<(f64, usize)> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let weight = item
+                .get(score_field)
+                .and_then(Value::as_f64)
+                .filter(|weight| *weight > MIN_WEIGHT)
+                .unwrap_or(MIN_WEIGHT);
+            // ln(u) / w orders like u^(1/w) without underflowing for small weights
+            let u: f64 = rng.random_range(f64::MIN_POSITIVE..1.0);
+            (u.ln() / weight, index)
+        })
+        .collect();
+
+    keyed.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+    keyed
+        .into_iter()
+        .take(k)
+        .map(|(_, index)| items[index].clone())
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for WeightedSampleNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "array_weighted_sample",
+            "Weighted Sample",
+            "Randomly picks K items without replacement, weighted by their score. Unlike Top K, lower scored items still get a chance, e.g. for diverse RAG context",
+            "Utils/Array",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin("array_in", "Array", "Scored items", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "score_field",
+            "Score Field",
+            "Numeric field used as weight, missing or non-positive scores are nearly never picked",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("score")));
+
+        node.add_input_pin("k", "K", "Number of items to pick", VariableType::Integer)
+            .set_default_value(Some(json!(5)));
+
+        node.add_input_pin(
+            "seed",
+            "Seed",
+            "Fixed seed, negative values use the run's seed",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(-1)));
+
+        node.add_output_pin("array_out", "Array", "Picked items", VariableType::Struct)
+            .set_value_type(ValueType::Array);
+
+        node
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let items: Vec<Value> = context.evaluate_pin("array_in").await?;
+        let score_field: String = context.evaluate_pin("score_field").await?;
+        let k: i64 = context.evaluate_pin("k").await?;
+        let seed: i64 = context.evaluate_pin("seed").await?;
+
+        let mut rng = seeded_rng(context, seed);
+        let picked = weighted_sample(&items, &score_field, k.max(0) as usize, &mut rng);
+        context.set_pin_value("array_out", json!(picked)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flow_like_types::rand::{SeedableRng, rngs::StdRng};
+
+    fn items() -> Vec<Value> {
+        vec![
+            json!({"id": 0, "score": 100.0}),
+            json!({"id": 1, "score": 0.0}),
+            json!({"id": 2, "score": -3.0}),
+            json!({"id": 3}),
+            json!({"id": 4, "score": 1.0}),
+        ]
+    }
+
+    #[test]
+    fn picks_without_replacement() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut ids: Vec<i64> = weighted_sample(&items(), "score", 10, &mut rng)
+            .iter()
+            .filter_map(|item| item["id"].as_i64())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn favors_high_scores_reproducibly() {
+        let first = weighted_sample(&items(), "score", 2, &mut StdRng::seed_from_u64(1));
+        let second = weighted_sample(&items(), "score", 2, &mut StdRng::seed_from_u64(1));
+        assert_eq!(first, second);
+
+        let mut rng = StdRng::seed_from_u64(2);
+        let hits = (0..200)
+            .filter(|_| weighted_sample(&items(), "score", 1, &mut rng)[0]["id"] == 0)
+            .count();
+        assert!(hits > 180, "{}", hits);
+    }
+}
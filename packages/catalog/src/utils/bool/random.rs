@@ -7,11 +7,7 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::{
-    async_trait,
-    json::json,
-    rand::{self, Rng},
-};
+use flow_like_types::{async_trait, json::json, rand::Rng};
 
 #[derive(Default)]
 pub struct RandomBoolNode {}
@@ -55,7 +51,7 @@ impl NodeLogic for RandomBoolNode {
     async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
         let probability: f64 = context.evaluate_pin("probability").await?;
         let random_bool = {
-            let mut rng = rand::rng();
+            let mut rng = context.rng();
             rng.random_bool(probability)
         };
 
@@ -1,6 +1,10 @@
 use flow_like::{
     flow::{
-        execution::{LogLevel, context::ExecutionContext, internal_node::InternalNode},
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, LoopSignal},
+            internal_node::InternalNode,
+        },
         node::{Node, NodeLogic},
         pin::PinOptions,
         variable::VariableType,
@@ -112,6 +116,7 @@ impl NodeLogic for BufferedCsvReaderNode {
         let mut records = rdr.byte_records();
         let mut chunk = Vec::with_capacity(chunk_size as usize);
         let flow = exec_item.lock().await.get_connected_nodes().await;
+        let signal = LoopSignal::new();
 
         while let Some(element) = records.next().await {
             let record = match element {
@@ -134,8 +139,10 @@ impl NodeLogic for BufferedCsvReaderNode {
             if chunk.len() as u64 == chunk_size {
                 value.lock().await.set_value(to_value(&chunk)?).await;
                 chunk = Vec::with_capacity(chunk_size as usize);
+                signal.next_pass();
                 for node in &flow {
                     let mut sub_context = context.create_sub_context(node).await;
+                    sub_context.set_loop_signal(signal.clone());
                     let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
                     sub_context.end_trace();
                     context.push_sub_context(&mut sub_context);
@@ -144,14 +151,25 @@ impl NodeLogic for BufferedCsvReaderNode {
                         let error = run.err().unwrap();
                         context.log_message(&format!("Error: {:?}", error), LogLevel::Error);
                     }
+
+                    if signal.pass_ended() {
+                        break;
+                    }
+                }
+
+                if signal.is_broken() {
+                    context.log_message("Loop broken, skipping remaining chunks", LogLevel::Debug);
+                    break;
                 }
             }
         }
 
-        if !chunk.is_empty() {
+        if !chunk.is_empty() && !signal.is_broken() {
             value.lock().await.set_value(to_value(&chunk)?).await;
+            signal.next_pass();
             for node in &flow {
                 let mut sub_context = context.create_sub_context(node).await;
+                sub_context.set_loop_signal(signal.clone());
                 let run = InternalNode::trigger(&mut sub_context, &mut None, true).await;
                 sub_context.end_trace();
                 context.push_sub_context(&mut sub_context);
@@ -160,6 +178,10 @@ impl NodeLogic for BufferedCsvReaderNode {
                     let error = run.err().unwrap();
                     context.log_message(&format!("Error: {:?}", error), LogLevel::Error);
                 }
+
+                if signal.pass_ended() {
+                    break;
+                }
             }
         }
 
@@ -23,7 +23,7 @@ impl NodeLogic for CuidNode {
         let mut node = Node::new(
             "cuid",
             "CUID v2",
-            "Generates a Collision Resistant Unique Identifier",
+            "Generates a Collision Resistant Unique Identifier. Always unique, not affected by the run seed",
             "Utils",
         );
         node.add_icon("/flow/icons/random.svg");
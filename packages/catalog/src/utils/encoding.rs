@@ -0,0 +1,87 @@
+use flow_like::flow::{
+    node::{Node, NodeLogic, remove_pin_by_name},
+    pin::{PinOptions, ValueType},
+    variable::VariableType,
+};
+use flow_like_types::{Value, json::json};
+use std::sync::Arc;
+
+pub mod base64;
+pub mod compress;
+pub mod hex;
+
+pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
+    vec![
+        Arc::new(base64::Base64Node::default()),
+        Arc::new(hex::HexNode::default()),
+        Arc::new(compress::CompressNode::default()),
+    ]
+}
+
+/// Adds the mode pin shared by the encoding nodes, defaulting to Encode.
+fn add_mode_pin(node: &mut Node) {
+    node.add_input_pin(
+        "mode",
+        "Mode",
+        "Encode bytes or decode text",
+        VariableType::String,
+    )
+    .set_options(
+        PinOptions::new()
+            .set_valid_values(vec!["Encode".to_string(), "Decode".to_string()])
+            .build(),
+    )
+    .set_default_value(Some(json!("Encode")));
+}
+
+fn is_decode(node: &Node) -> bool {
+    node.get_pin_by_name("mode")
+        .and_then(|pin| pin.default_value.clone())
+        .and_then(|bytes| flow_like_types::json::from_slice::<Value>(&bytes).ok())
+        .is_some_and(|json| json.as_str() == Some("Decode"))
+}
+
+/// Lays out the data pins for the mode: Encode takes bytes and outputs text, Decode the
+/// other way around. Pins of the wrong type are replaced, dropping their connections.
+fn add_data_pins(node: &mut Node, format: &str) {
+    let decode = is_decode(node);
+    let wrong_type = |node: &Node, name: &str, bytes: bool| {
+        node.get_pin_by_name(name)
+            .is_some_and(|pin| (pin.data_type == VariableType::Byte) != bytes)
+    };
+
+    if wrong_type(node, "input", !decode) {
+        remove_pin_by_name(node, "input");
+    }
+    if wrong_type(node, "output", decode) {
+        remove_pin_by_name(node, "output");
+    }
+
+    if node.get_pin_by_name("input").is_none() {
+        if decode {
+            node.add_input_pin(
+                "input",
+                "Text",
+                &format!("{} text to decode", format),
+                VariableType::String,
+            );
+        } else {
+            node.add_input_pin("input", "Bytes", "Bytes to encode", VariableType::Byte)
+                .set_value_type(ValueType::Array);
+        }
+    }
+
+    if node.get_pin_by_name("output").is_none() {
+        if decode {
+            node.add_output_pin("output", "Bytes", "Decoded bytes", VariableType::Byte)
+                .set_value_type(ValueType::Array);
+        } else {
+            node.add_output_pin(
+                "output",
+                "Text",
+                &format!("{} encoded text", format),
+                VariableType::String,
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use base64::{
+    Engine, alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+use std::sync::Arc;
+
+/// Standard alphabet, padded output, accepts input with or without padding.
+const STANDARD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// URL and filename safe alphabet, unpadded output, accepts input with or without padding.
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Default)]
+pub struct Base64Node {}
+
+impl Base64Node {
+    pub fn new() -> Self {
+        Base64Node {}
+    }
+}
+
+fn engine(url_safe: bool) -> &'static GeneralPurpose {
+    if url_safe { &URL_SAFE } else { &STANDARD }
+}
+
+#[async_trait]
+impl NodeLogic for Base64Node {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_encoding_base64",
+            "Base64",
+            "Encodes bytes as Base64 text or decodes Base64 text into bytes, e.g. to embed binary data in JSON",
+            "Utils/Encoding",
+        );
+        node.add_icon("/flow/icons/convert.svg");
+
+        super::add_mode_pin(&mut node);
+        node.add_input_pin(
+            "url_safe",
+            "URL Safe",
+            "Use the URL safe alphabet (- and _) without padding",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+        super::add_data_pins(&mut node, "Base64");
+
+        node.add_output_pin(
+            "ok",
+            "Ok",
+            "False if the text could not be decoded, the output is empty then",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let mode: String = context.evaluate_pin("mode").await?;
+        let url_safe: bool = context.evaluate_pin("url_safe").await?;
+        let engine = engine(url_safe);
+
+        if mode == "Decode" {
+            let text: String = context.evaluate_pin("input").await?;
+            let decoded = engine.decode(text.trim()).ok();
+            context
+                .set_pin_value("ok", json!(decoded.is_some()))
+                .await?;
+            context
+                .set_pin_value("output", json!(decoded.unwrap_or_default()))
+                .await?;
+            return Ok(());
+        }
+
+        let bytes: Vec<u8> = context.evaluate_pin("input").await?;
+        context
+            .set_pin_value("output", json!(engine.encode(bytes)))
+            .await?;
+        context.set_pin_value("ok", json!(true)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, _board: Arc<Board>) {
+        super::add_data_pins(node, "Base64");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_safe_variant_is_unpadded() {
+        let bytes = [0xfb, 0xff];
+        assert_eq!(engine(false).encode(bytes), "+/8=");
+        assert_eq!(engine(true).encode(bytes), "-_8");
+        assert_eq!(engine(true).decode("-_8=").unwrap(), bytes);
+        assert!(engine(false).decode("-_8").is_err());
+    }
+}
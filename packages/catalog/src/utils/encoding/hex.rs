@@ -0,0 +1,103 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct HexNode {}
+
+impl HexNode {
+    pub fn new() -> Self {
+        HexNode {}
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes upper or lower case hex, `None` for odd lengths or other characters.
+fn decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for HexNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_encoding_hex",
+            "Hex",
+            "Encodes bytes as hexadecimal text or decodes hexadecimal text into bytes",
+            "Utils/Encoding",
+        );
+        node.add_icon("/flow/icons/convert.svg");
+
+        super::add_mode_pin(&mut node);
+        super::add_data_pins(&mut node, "Hex");
+
+        node.add_output_pin(
+            "ok",
+            "Ok",
+            "False if the text could not be decoded, the output is empty then",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let mode: String = context.evaluate_pin("mode").await?;
+
+        if mode == "Decode" {
+            let text: String = context.evaluate_pin("input").await?;
+            let decoded = decode(&text);
+            context
+                .set_pin_value("ok", json!(decoded.is_some()))
+                .await?;
+            context
+                .set_pin_value("output", json!(decoded.unwrap_or_default()))
+                .await?;
+            return Ok(());
+        }
+
+        let bytes: Vec<u8> = context.evaluate_pin("input").await?;
+        context
+            .set_pin_value("output", json!(encode(&bytes)))
+            .await?;
+        context.set_pin_value("ok", json!(true)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, _board: Arc<Board>) {
+        super::add_data_pins(node, "Hex");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_rejects_invalid() {
+        assert_eq!(encode(&[0, 15, 255]), "000fff");
+        assert_eq!(decode("000FfF"), Some(vec![0, 15, 255]));
+        assert_eq!(decode("abc"), None);
+        assert_eq!(decode("zz"), None);
+        assert_eq!(decode("é0"), None);
+    }
+}
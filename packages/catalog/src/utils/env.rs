@@ -2,7 +2,13 @@ use flow_like::flow::node::NodeLogic;
 use std::sync::Arc;
 
 pub mod get_env;
+pub mod get_secret;
+pub mod runtime_info;
 
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
-    vec![Arc::new(get_env::GetEnvVariableNode::default())]
+    vec![
+        Arc::new(get_env::GetEnvVariableNode::default()),
+        Arc::new(get_secret::SecretNode::default()),
+        Arc::new(runtime_info::RuntimeInfoNode::default()),
+    ]
 }
@@ -0,0 +1,56 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{anyhow, async_trait, json::json};
+
+#[derive(Default)]
+pub struct SecretNode {}
+
+impl SecretNode {
+    pub fn new() -> Self {
+        SecretNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for SecretNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "get_secret",
+            "Get Secret",
+            "Resolves a named secret from the runtime configuration",
+            "Utils/Env",
+        );
+        node.add_icon("/flow/icons/env.svg");
+
+        node.add_input_pin("name", "Name", "Name of the secret", VariableType::String);
+
+        node.add_output_pin("secret", "Secret", "Secret value", VariableType::String)
+            .set_options(PinOptions::new().set_sensitive(true).build());
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let name: String = context.evaluate_pin("name").await?;
+
+        let secret = context
+            .app_state
+            .lock()
+            .await
+            .config
+            .read()
+            .await
+            .get_secret(&name)
+            .ok_or_else(|| anyhow!("Secret '{}' not found", name))?;
+
+        context.set_pin_value("secret", json!(secret)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_storage::files::store::FlowLikeStore;
+use flow_like_types::{async_trait, json::json};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Capabilities of the runtime a flow executes in. Fields that can't be determined are omitted.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct RuntimeInfo {
+    pub os: String,
+    pub arch: String,
+    /// Whether local models run with GPU offloading
+    pub gpu_mode: bool,
+    pub max_context_size: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_backend: Option<String>,
+    /// Configured remote model providers, e.g. "openai" or "bedrock"
+    pub model_providers: Vec<String>,
+    pub has_project_database: bool,
+    pub has_credentials: bool,
+}
+
+fn storage_backend(store: &FlowLikeStore) -> &'static str {
+    match store {
+        FlowLikeStore::Local(_) => "local",
+        FlowLikeStore::AWS(_) => "aws",
+        FlowLikeStore::Azure(_) => "azure",
+        FlowLikeStore::Google(_) => "google",
+        FlowLikeStore::Memory(_) => "memory",
+        FlowLikeStore::Other(_) => "other",
+    }
+}
+
+#[derive(Default)]
+pub struct RuntimeInfoNode {}
+
+impl RuntimeInfoNode {
+    pub fn new() -> Self {
+        RuntimeInfoNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for RuntimeInfoNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "runtime_info",
+            "Runtime Info",
+            "Capabilities of the runtime, e.g. to pick a smaller model without a GPU",
+            "Utils/Env",
+        );
+        node.add_icon("/flow/icons/env.svg");
+
+        node.add_output_pin(
+            "info",
+            "Info",
+            "Runtime capabilities, unknown ones are omitted",
+            VariableType::Struct,
+        )
+        .set_schema::<RuntimeInfo>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let (model_factory, provider_config, config) = {
+            let state = context.app_state.lock().await;
+            (
+                state.model_factory.clone(),
+                state.model_provider_config.clone(),
+                state.config.clone(),
+            )
+        };
+
+        let settings = model_factory.lock().await.execution_settings.clone();
+        let (storage_backend, has_project_database) = {
+            let config = config.read().await;
+            (
+                config
+                    .stores
+                    .app_storage_store
+                    .as_ref()
+                    .map(|store| storage_backend(store).to_string()),
+                config.callbacks.build_project_database.is_some(),
+            )
+        };
+
+        let mut model_providers = vec![];
+        if !provider_config.openai_config.is_empty() {
+            model_providers.push("openai".to_string());
+        }
+        if !provider_config.bedrock_config.is_empty() {
+            model_providers.push("bedrock".to_string());
+        }
+
+        let info = RuntimeInfo {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            gpu_mode: settings.gpu_mode,
+            max_context_size: settings.max_context_size,
+            storage_backend,
+            model_providers,
+            has_project_database,
+            has_credentials: context.credentials.is_some(),
+        };
+
+        context.set_pin_value("info", json!(info)).await?;
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@ pub mod pow;
 pub mod random_range;
 pub mod root;
 pub mod round;
+pub mod stats;
 pub mod subtract;
 pub mod unequal;
 
@@ -44,6 +45,7 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(abs::AbsFloatNode::default()),
         Arc::new(pow::PowerFloatNode::default()),
         Arc::new(root::RootFloatNode::default()),
+        Arc::new(stats::StatsNode::default()),
     ];
 
     items
@@ -0,0 +1,166 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    JsonSchema, Value, async_trait,
+    json::{Deserialize, Serialize, json},
+};
+
+/// Summary of a numeric array. An empty array (or one with only skipped NaNs) has a count
+/// and sum of 0 and null for everything else. Propagated NaNs make every statistic but the
+/// count null, since JSON has no NaN.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    /// Population standard deviation.
+    pub stddev: Option<f64>,
+}
+
+/// Non-numeric items like null count as NaN.
+fn stats(values: &[Value], skip_nan: bool) -> Stats {
+    let mut numbers: Vec<f64> = values
+        .iter()
+        .map(|value| value.as_f64().unwrap_or(f64::NAN))
+        .collect();
+
+    if skip_nan {
+        numbers.retain(|number| !number.is_nan());
+    } else if numbers.iter().any(|number| number.is_nan()) {
+        return Stats {
+            count: numbers.len(),
+            ..Stats::default()
+        };
+    }
+
+    let count = numbers.len();
+    if count == 0 {
+        return Stats {
+            sum: Some(0.0),
+            ..Stats::default()
+        };
+    }
+
+    numbers.sort_by(f64::total_cmp);
+    let sum: f64 = numbers.iter().sum();
+    let mean = sum / count as f64;
+    let median = if count % 2 == 0 {
+        (numbers[count / 2 - 1] + numbers[count / 2]) / 2.0
+    } else {
+        numbers[count / 2]
+    };
+    let variance = numbers
+        .iter()
+        .map(|number| (number - mean).powi(2))
+        .sum::<f64>()
+        / count as f64;
+
+    Stats {
+        count,
+        sum: Some(sum),
+        min: Some(numbers[0]),
+        max: Some(numbers[count - 1]),
+        mean: Some(mean),
+        median: Some(median),
+        stddev: Some(variance.sqrt()),
+    }
+}
+
+#[derive(Default)]
+pub struct StatsNode {}
+
+impl StatsNode {
+    pub fn new() -> Self {
+        StatsNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for StatsNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "float_stats",
+            "Statistics",
+            "Count, sum, min, max, mean, median and standard deviation of an array of numbers. An empty array yields a count and sum of 0 and null otherwise",
+            "Math/Float",
+        );
+        node.add_icon("/flow/icons/sigma.svg");
+
+        node.add_input_pin(
+            "values",
+            "Values",
+            "Numbers to summarize",
+            VariableType::Float,
+        )
+        .set_value_type(ValueType::Array);
+
+        node.add_input_pin(
+            "nan_handling",
+            "NaN Handling",
+            "Skip ignores null and non-numeric items, Propagate makes every statistic but Count null if there is one",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec!["Skip".to_string(), "Propagate".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!("Skip")));
+
+        node.add_output_pin("stats", "Statistics", "Summary", VariableType::Struct)
+            .set_schema::<Stats>();
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let values: Vec<Value> = context.evaluate_pin("values").await?;
+        let nan_handling: String = context.evaluate_pin("nan_handling").await?;
+
+        let stats = stats(&values, nan_handling != "Propagate");
+        context.set_pin_value("stats", json!(stats)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_numbers() {
+        let stats = stats(&[json!(4), json!(1.0), json!(3), json!(2)], true);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.sum, Some(10.0));
+        assert_eq!(stats.min, Some(1.0));
+        assert_eq!(stats.max, Some(4.0));
+        assert_eq!(stats.mean, Some(2.5));
+        assert_eq!(stats.median, Some(2.5));
+        assert!((stats.stddev.unwrap() - 1.25f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn handles_nan_and_empty_arrays() {
+        let values = [json!(1), json!(null), json!(3)];
+        assert_eq!(stats(&values, true).median, Some(2.0));
+
+        let propagated = stats(&values, false);
+        assert_eq!(propagated.count, 3);
+        assert_eq!(propagated.mean, None);
+
+        let empty = stats(&[], true);
+        assert_eq!(empty.count, 0);
+        assert_eq!(empty.sum, Some(0.0));
+        assert_eq!(empty.median, None);
+    }
+}
@@ -0,0 +1,67 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        utils::stable_hash,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+
+#[derive(Default)]
+pub struct StableHashNode {}
+
+impl StableHashNode {
+    pub fn new() -> Self {
+        StableHashNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for StableHashNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_hash_stable",
+            "Stable Hash",
+            "Computes a deterministic hash of the input, equal objects hash the same regardless of key order. Useful as a cache key or to detect changed records",
+            "Utils/Hash",
+        );
+        node.add_icon("/flow/icons/hash.svg");
+
+        node.add_input_pin("exec_in", "Execute", "", VariableType::Execution);
+
+        node.add_input_pin(
+            "input",
+            "Input",
+            "Input data to hash",
+            VariableType::Generic,
+        );
+
+        node.add_output_pin(
+            "exec_out",
+            "Done",
+            "Execution output pin",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "hash",
+            "Hash (hex)",
+            "HighwayHash of the canonical JSON of the input",
+            VariableType::String,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        context.deactivate_exec_pin("exec_out").await?;
+
+        let value = context.evaluate_pin_to_ref("input").await?;
+        let hash = stable_hash(&*value.lock().await)?;
+        context.set_pin_value("hash", json!(hash)).await?;
+
+        context.activate_exec_pin("exec_out").await?;
+        Ok(())
+    }
+}
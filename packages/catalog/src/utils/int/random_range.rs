@@ -6,11 +6,7 @@ use flow_like::{
     },
     state::FlowLikeState,
 };
-use flow_like_types::{
-    anyhow, async_trait,
-    json::json,
-    rand::{self, Rng},
-};
+use flow_like_types::{anyhow, async_trait, json::json, rand::Rng};
 
 #[derive(Default)]
 pub struct RandomIntegerInRangeNode {}
@@ -54,7 +50,7 @@ impl NodeLogic for RandomIntegerInRangeNode {
         }
 
         let random_integer = {
-            let mut rng = rand::rng();
+            let mut rng = context.rng();
             rng.random_range(min..=max) // Inclusive range for integers
         };
 
@@ -0,0 +1,22 @@
+use flow_like::flow::node::NodeLogic;
+use flow_like_types::{Value, anyhow, json::Map};
+use std::sync::Arc;
+
+pub mod get;
+pub mod set;
+
+/// Reads a HashMap pin value, a null map is treated as empty.
+pub(crate) fn as_map(value: Value) -> flow_like_types::Result<Map<String, Value>> {
+    match value {
+        Value::Object(map) => Ok(map),
+        Value::Null => Ok(Map::new()),
+        other => Err(anyhow!("Expected a map, got {}", other)),
+    }
+}
+
+pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
+    vec![
+        Arc::new(get::MapGetNode::default()),
+        Arc::new(set::MapSetNode::default()),
+    ]
+}
@@ -0,0 +1,76 @@
+use super::as_map;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct MapGetNode {}
+
+impl MapGetNode {
+    pub fn new() -> Self {
+        MapGetNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for MapGetNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "map_get",
+            "Get",
+            "Looks up the value stored under a key",
+            "Utils/Map",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin("map", "Map", "Map to read from", VariableType::Generic)
+            .set_value_type(ValueType::HashMap);
+
+        node.add_input_pin("key", "Key", "Key to look up", VariableType::String);
+
+        node.add_output_pin(
+            "value",
+            "Value",
+            "Value stored under the key, null if not found",
+            VariableType::Generic,
+        );
+
+        node.add_output_pin(
+            "found",
+            "Found?",
+            "Is the key present in the map?",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let mut map = as_map(context.evaluate_pin("map").await?)?;
+        let key: String = context.evaluate_pin("key").await?;
+
+        let value = map.remove(&key);
+        context
+            .set_pin_value("found", json!(value.is_some()))
+            .await?;
+        context
+            .set_pin_value("value", value.unwrap_or(Value::Null))
+            .await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("map", board.clone(), Some(ValueType::HashMap), None);
+        let _ = node.match_type("value", board, Some(ValueType::Normal), None);
+        node.harmonize_type(vec!["map", "value"], true);
+    }
+}
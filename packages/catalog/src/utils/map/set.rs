@@ -0,0 +1,64 @@
+use super::as_map;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct MapSetNode {}
+
+impl MapSetNode {
+    pub fn new() -> Self {
+        MapSetNode {}
+    }
+}
+
+#[async_trait]
+impl NodeLogic for MapSetNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "map_set",
+            "Set",
+            "Stores a value under a key, returning the updated map. A null map starts empty",
+            "Utils/Map",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin("map_in", "Map", "Map to update", VariableType::Generic)
+            .set_value_type(ValueType::HashMap);
+
+        node.add_input_pin("key", "Key", "Key to store under", VariableType::String);
+
+        node.add_input_pin("value", "Value", "Value to store", VariableType::Generic);
+
+        node.add_output_pin("map_out", "Map", "Updated map", VariableType::Generic)
+            .set_value_type(ValueType::HashMap);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let mut map = as_map(context.evaluate_pin("map_in").await?)?;
+        let key: String = context.evaluate_pin("key").await?;
+        let value: Value = context.evaluate_pin("value").await?;
+
+        map.insert(key, value);
+        context.set_pin_value("map_out", Value::Object(map)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("map_in", board.clone(), Some(ValueType::HashMap), None);
+        let _ = node.match_type("map_out", board.clone(), Some(ValueType::HashMap), None);
+        let _ = node.match_type("value", board, Some(ValueType::Normal), None);
+        node.harmonize_type(vec!["map_in", "map_out", "value"], true);
+    }
+}
@@ -0,0 +1,12 @@
+use flow_like::flow::node::NodeLogic;
+use std::sync::Arc;
+
+pub mod format;
+pub mod parse;
+
+pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
+    vec![
+        Arc::new(parse::ParseNumberNode::default()),
+        Arc::new(format::FormatNumberNode::default()),
+    ]
+}
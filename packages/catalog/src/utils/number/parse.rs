@@ -0,0 +1,162 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+
+#[derive(Default)]
+pub struct ParseNumberNode {}
+
+impl ParseNumberNode {
+    pub fn new() -> Self {
+        ParseNumberNode {}
+    }
+}
+
+/// Strips thousands separators and normalizes the decimal separator to ".". Thousands
+/// separators (the other separator, `'` or whitespace) are only accepted between 3 digit
+/// groups left of the decimal separator, anything else yields `None`.
+fn normalize(input: &str, decimal_separator: char) -> Option<String> {
+    let thousands_separator = if decimal_separator == ',' { '.' } else { ',' };
+    let is_grouping = |c: char| c == thousands_separator || c == '\'' || c.is_whitespace();
+
+    let input = input.trim();
+    let (integer, fraction) = match input.split_once(decimal_separator) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (input, None),
+    };
+    if fraction.is_some_and(|fraction| fraction.contains(is_grouping)) {
+        return None;
+    }
+
+    let (sign, digits) = match integer.strip_prefix(['-', '+']) {
+        Some(digits) => (&integer[..1], digits),
+        None => ("", integer),
+    };
+
+    let mut normalized = sign.to_string();
+    if digits.contains(is_grouping) {
+        for (i, group) in digits.split(is_grouping).enumerate() {
+            let valid_length = if i == 0 {
+                (1..=3).contains(&group.len())
+            } else {
+                group.len() == 3
+            };
+            if !valid_length || !group.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            normalized.push_str(group);
+        }
+    } else {
+        normalized.push_str(digits);
+    }
+
+    if let Some(fraction) = fraction {
+        normalized.push('.');
+        normalized.push_str(fraction);
+    }
+    Some(normalized)
+}
+
+fn parse_normalized(normalized: &str) -> (i64, f64, bool) {
+    if let Ok(integer) = normalized.parse::<i64>() {
+        return (integer, integer as f64, true);
+    }
+
+    match normalized.parse::<f64>() {
+        Ok(float) if float.is_finite() => (float.trunc() as i64, float, true),
+        _ => (0, 0.0, false),
+    }
+}
+
+#[async_trait]
+impl NodeLogic for ParseNumberNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "parse_number",
+            "Parse Number",
+            "Parses a string into an integer and a float. Never fails, check the OK pin instead",
+            "Utils/Conversions",
+        );
+        node.add_icon("/flow/icons/convert.svg");
+
+        node.add_input_pin("string", "String", "String to parse", VariableType::String);
+        node.add_input_pin(
+            "decimal_separator",
+            "Decimal Separator",
+            "Decimal separator used by the input, the other one is treated as thousands separator",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![".".to_string(), ",".to_string()])
+                .build(),
+        )
+        .set_default_value(Some(json!(".")));
+
+        node.add_output_pin(
+            "integer",
+            "Integer",
+            "Parsed value truncated to an integer, 0 on failure",
+            VariableType::Integer,
+        );
+        node.add_output_pin(
+            "float",
+            "Float",
+            "Parsed value as float, 0.0 on failure",
+            VariableType::Float,
+        );
+        node.add_output_pin(
+            "ok",
+            "OK",
+            "True if the string could be parsed",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let string: String = context.evaluate_pin("string").await?;
+        let decimal_separator: String = context.evaluate_pin("decimal_separator").await?;
+        let decimal_separator = decimal_separator.chars().next().unwrap_or('.');
+
+        let (integer, float, ok) = match normalize(&string, decimal_separator) {
+            Some(normalized) => parse_normalized(&normalized),
+            None => (0, 0.0, false),
+        };
+
+        context.set_pin_value("integer", json!(integer)).await?;
+        context.set_pin_value("float", json!(float)).await?;
+        context.set_pin_value("ok", json!(ok)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn accepts_grouped_thousands() {
+        assert_eq!(normalize("1,234,567.5", '.').as_deref(), Some("1234567.5"));
+        assert_eq!(normalize("-1.234,5", ',').as_deref(), Some("-1234.5"));
+        assert_eq!(normalize("1'000", '.').as_deref(), Some("1000"));
+        assert_eq!(normalize(" 12 345 ", '.').as_deref(), Some("12345"));
+        assert_eq!(normalize("1,5", ',').as_deref(), Some("1.5"));
+    }
+
+    #[test]
+    fn rejects_misplaced_separators() {
+        assert_eq!(normalize("1,5", '.'), None);
+        assert_eq!(normalize("1,2,3", '.'), None);
+        assert_eq!(normalize("1234,567", '.'), None);
+        assert_eq!(normalize(",123", '.'), None);
+        assert_eq!(normalize("1.000,5", '.'), None);
+    }
+}
@@ -7,6 +7,7 @@ pub mod discard;
 pub mod from_array;
 pub mod has;
 pub mod insert;
+pub mod intersection;
 pub mod is_empty;
 pub mod is_subset;
 pub mod is_superset;
@@ -34,6 +35,6 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(is_subset::SetIsSubsetNode::default()),
         Arc::new(is_empty::SetIsEmptyNode::default()),
         Arc::new(mutual::IsMutualSetNode::default()),
-        Arc::new(mutual::IsMutualSetNode::default()),
+        Arc::new(intersection::IntersectionSetNode::default()),
     ]
 }
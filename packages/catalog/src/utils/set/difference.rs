@@ -62,7 +62,7 @@ impl NodeLogic for DifferenceSetNode {
 
     async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
         let set_in_1: HashSet<Value> = context.evaluate_pin("set_in_1").await?;
-        let set_in_2: HashSet<Value> = context.evaluate_pin("set_in_1").await?;
+        let set_in_2: HashSet<Value> = context.evaluate_pin("set_in_2").await?;
         let diff: Difference<Value, RandomState> = set_in_1.difference(&set_in_2);
         let result: HashSet<&Value> = HashSet::from_iter(diff);
         context.set_pin_value("set_out", json!(result)).await?;
@@ -54,7 +54,7 @@ impl NodeLogic for IsMutualSetNode {
 
     async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
         let set_in_1: HashSet<Value> = context.evaluate_pin("set_in_1").await?;
-        let set_in_2: HashSet<Value> = context.evaluate_pin("set_in_1").await?;
+        let set_in_2: HashSet<Value> = context.evaluate_pin("set_in_2").await?;
         let is_mutual: bool = !set_in_1.is_disjoint(&set_in_2);
         context.set_pin_value("is_mutual", json!(is_mutual)).await?;
         context.activate_exec_pin("exec_out").await?;
@@ -7,6 +7,7 @@ pub mod equal;
 pub mod format;
 pub mod join;
 pub mod length;
+pub mod regex;
 pub mod replace;
 pub mod similarity;
 pub mod split;
@@ -14,6 +15,7 @@ pub mod starts_with;
 pub mod template;
 pub mod to_lowercase;
 pub mod to_uppercase;
+pub mod transform;
 pub mod trim;
 pub mod unequal;
 pub mod utf_8_lossy;
@@ -35,6 +37,8 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(utf_8_lossy::ParseUtf8LossyNode::default()),
         Arc::new(contains::StringContainsNode::default()),
         Arc::new(template::TemplateStringNode::default()),
+        Arc::new(regex::RegexNode::default()),
+        Arc::new(transform::StringTransformNode::default()),
     ];
 
     items.append(&mut similarity::register_functions().await);
@@ -0,0 +1,180 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic, remove_pin_by_name},
+        pin::{PinOptions, ValueType},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{
+    Value, anyhow, async_trait,
+    json::{Map, json},
+};
+use regex::Regex;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct RegexNode {}
+
+impl RegexNode {
+    pub fn new() -> Self {
+        RegexNode {}
+    }
+}
+
+/// Collects every match of `regex` in `input` as a struct.
+/// Named groups are keyed by their name, unnamed groups by their index ("0" is the full match).
+fn extract_captures(regex: &Regex, input: &str) -> Vec<Value> {
+    let names: Vec<Option<&str>> = regex.capture_names().collect();
+
+    regex
+        .captures_iter(input)
+        .map(|captures| {
+            let mut item = Map::with_capacity(names.len());
+            for (index, name) in names.iter().enumerate() {
+                let key = name.map_or_else(|| index.to_string(), ToOwned::to_owned);
+                let value = captures
+                    .get(index)
+                    .map_or(Value::Null, |m| Value::String(m.as_str().to_string()));
+                item.insert(key, value);
+            }
+            Value::Object(item)
+        })
+        .collect()
+}
+
+#[async_trait]
+impl NodeLogic for RegexNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "string_regex",
+            "Regex",
+            "Matches, extracts or replaces parts of a string using a regular expression",
+            "Utils/String",
+        );
+        node.add_icon("/flow/icons/string.svg");
+
+        node.add_input_pin("input", "Input", "Input String", VariableType::String);
+        node.add_input_pin(
+            "pattern",
+            "Pattern",
+            "Regular Expression",
+            VariableType::String,
+        );
+        node.add_input_pin(
+            "mode",
+            "Mode",
+            "What to do with the pattern",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "Match".to_string(),
+                    "Extract".to_string(),
+                    "Replace".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("Match")));
+
+        node.add_output_pin(
+            "is_match",
+            "Is Match",
+            "True if the pattern matches the input",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let input: String = context.evaluate_pin("input").await?;
+        let pattern: String = context.evaluate_pin("pattern").await?;
+        let mode: String = context.evaluate_pin("mode").await?;
+
+        let regex = Regex::new(&pattern)
+            .map_err(|err| anyhow!("Invalid regex pattern '{}': {}", pattern, err))?;
+
+        match mode.as_str() {
+            "Match" => {
+                context
+                    .set_pin_value("is_match", json!(regex.is_match(&input)))
+                    .await?;
+            }
+            "Extract" => {
+                let captures = extract_captures(&regex, &input);
+                context.set_pin_value("captures", json!(captures)).await?;
+            }
+            "Replace" => {
+                let replacement: String = context.evaluate_pin("replacement").await?;
+                let result = regex.replace_all(&input, replacement.as_str());
+                context.set_pin_value("result", json!(result)).await?;
+            }
+            _ => return Err(anyhow!("Unknown regex mode: {}", mode)),
+        }
+
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, _board: Arc<Board>) {
+        let mode: String = node
+            .get_pin_by_name("mode")
+            .and_then(|pin| pin.default_value.clone())
+            .and_then(|bytes| flow_like_types::json::from_slice::<Value>(&bytes).ok())
+            .and_then(|json| json.as_str().map(ToOwned::to_owned))
+            .unwrap_or_default();
+
+        match mode.as_str() {
+            "Extract" => {
+                if node.get_pin_by_name("captures").is_none() {
+                    node.add_output_pin(
+                        "captures",
+                        "Captures",
+                        "One struct per match, keyed by group name or index",
+                        VariableType::Struct,
+                    )
+                    .set_value_type(ValueType::Array);
+                }
+                remove_pin_by_name(node, "is_match");
+                remove_pin_by_name(node, "replacement");
+                remove_pin_by_name(node, "result");
+            }
+            "Replace" => {
+                if node.get_pin_by_name("replacement").is_none() {
+                    node.add_input_pin(
+                        "replacement",
+                        "Replacement",
+                        "Replacement string, supports $1 / $name references",
+                        VariableType::String,
+                    );
+                }
+                if node.get_pin_by_name("result").is_none() {
+                    node.add_output_pin(
+                        "result",
+                        "Result",
+                        "String with all matches replaced",
+                        VariableType::String,
+                    );
+                }
+                remove_pin_by_name(node, "is_match");
+                remove_pin_by_name(node, "captures");
+            }
+            _ => {
+                if node.get_pin_by_name("is_match").is_none() {
+                    node.add_output_pin(
+                        "is_match",
+                        "Is Match",
+                        "True if the pattern matches the input",
+                        VariableType::Boolean,
+                    );
+                }
+                remove_pin_by_name(node, "captures");
+                remove_pin_by_name(node, "replacement");
+                remove_pin_by_name(node, "result");
+            }
+        }
+    }
+}
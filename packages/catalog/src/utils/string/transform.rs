@@ -0,0 +1,136 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{anyhow, async_trait, json::json};
+
+#[derive(Default)]
+pub struct StringTransformNode {}
+
+impl StringTransformNode {
+    pub fn new() -> Self {
+        StringTransformNode {}
+    }
+}
+
+fn title_case(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut word_start = true;
+
+    for c in input.chars() {
+        if c.is_whitespace() {
+            word_start = true;
+            result.push(c);
+        } else if word_start {
+            word_start = false;
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+
+    result
+}
+
+/// Pads `input` up to `width` characters. Strings that are already wider are returned unchanged.
+fn pad(input: &str, width: usize, fill: char, left: bool) -> String {
+    let len = input.chars().count();
+    if len >= width {
+        return input.to_string();
+    }
+
+    let padding: String = std::iter::repeat_n(fill, width - len).collect();
+    if left {
+        format!("{}{}", padding, input)
+    } else {
+        format!("{}{}", input, padding)
+    }
+}
+
+#[async_trait]
+impl NodeLogic for StringTransformNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "string_transform",
+            "Transform String",
+            "Changes the case of a string, trims or pads it",
+            "Utils/String",
+        );
+        node.add_icon("/flow/icons/string.svg");
+
+        node.add_input_pin("string", "String", "Input String", VariableType::String);
+        node.add_input_pin(
+            "op",
+            "Operation",
+            "Transformation to apply",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "Uppercase".to_string(),
+                    "Lowercase".to_string(),
+                    "Title Case".to_string(),
+                    "Trim".to_string(),
+                    "Trim Start".to_string(),
+                    "Trim End".to_string(),
+                    "Pad Left".to_string(),
+                    "Pad Right".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("Trim")));
+        node.add_input_pin(
+            "width",
+            "Width",
+            "Target width in characters (padding only)",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(0)));
+        node.add_input_pin(
+            "fill",
+            "Fill",
+            "Fill character (padding only)",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!(" ")));
+
+        node.add_output_pin(
+            "result",
+            "Result",
+            "Transformed String",
+            VariableType::String,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let string: String = context.evaluate_pin("string").await?;
+        let op: String = context.evaluate_pin("op").await?;
+
+        let result = match op.as_str() {
+            "Uppercase" => string.to_uppercase(),
+            "Lowercase" => string.to_lowercase(),
+            "Title Case" => title_case(&string),
+            "Trim" => string.trim().to_string(),
+            "Trim Start" => string.trim_start().to_string(),
+            "Trim End" => string.trim_end().to_string(),
+            "Pad Left" | "Pad Right" => {
+                let width: i64 = context.evaluate_pin("width").await?;
+                let fill: String = context.evaluate_pin("fill").await?;
+                let fill = fill.chars().next().unwrap_or(' ');
+                pad(&string, width.max(0) as usize, fill, op == "Pad Left")
+            }
+            _ => return Err(anyhow!("Unknown string operation: {}", op)),
+        };
+
+        context.set_pin_value("result", json!(result)).await?;
+        Ok(())
+    }
+}
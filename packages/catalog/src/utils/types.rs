@@ -1,17 +1,23 @@
+pub mod cast;
 pub mod from_bytes;
 pub mod from_string;
 pub mod to_bytes;
 pub mod to_string;
 pub mod try_transform;
+pub mod type_of;
+pub mod with_default;
 use flow_like::flow::node::NodeLogic;
 use std::sync::Arc;
 
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
     vec![
         Arc::new(try_transform::TryTransformNode::default()),
+        Arc::new(cast::CastNode::default()),
         Arc::new(from_bytes::FromBytesNode::default()),
         Arc::new(from_string::FromStringNode::default()),
         Arc::new(to_bytes::ToBytesNode::default()),
         Arc::new(to_string::ToStringNode::default()),
+        Arc::new(with_default::WithDefaultNode::default()),
+        Arc::new(type_of::TypeOfNode::default()),
     ]
 }
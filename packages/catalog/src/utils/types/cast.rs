@@ -0,0 +1,192 @@
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::PinOptions,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, anyhow, async_trait, json::json};
+use std::sync::Arc;
+
+/// Explicit conversion between the primitive VariableTypes.
+///
+/// | from \ to | String        | Integer              | Float          | Boolean              |
+/// |-----------|---------------|----------------------|----------------|----------------------|
+/// | String    | identity      | parse (`ok`)         | parse (`ok`)   | "true"/"false" (`ok`)|
+/// | Integer   | decimal       | identity             | widen          | `!= 0`               |
+/// | Float     | shortest repr | truncate toward zero | identity       | `!= 0.0`             |
+/// | Boolean   | "true"/"false"| 1 / 0                | 1.0 / 0.0      | identity             |
+///
+/// Parses that fail set `ok` to false and output the zero value of the target type.
+/// Everything else (Null, Arrays, Structs, non-finite Floats to Integer) is an error.
+#[derive(Default)]
+pub struct CastNode {}
+
+impl CastNode {
+    pub fn new() -> Self {
+        CastNode {}
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "Null",
+        Value::Bool(_) => "Boolean",
+        Value::Number(number) if number.is_f64() => "Float",
+        Value::Number(_) => "Integer",
+        Value::String(_) => "String",
+        Value::Array(_) => "Array",
+        Value::Object(_) => "Struct",
+    }
+}
+
+fn zero_value(target: &str) -> Value {
+    match target {
+        "Integer" => json!(0),
+        "Float" => json!(0.0),
+        "Boolean" => json!(false),
+        _ => json!(""),
+    }
+}
+
+/// Returns `Ok(None)` if a String could not be parsed into the target type.
+pub(crate) fn cast(value: &Value, target: &str) -> flow_like_types::Result<Option<Value>> {
+    let unsupported = || anyhow!("Cannot cast {} to {}", type_name(value), target);
+
+    let result = match (value, target) {
+        (Value::String(s), "String") => json!(s),
+        (Value::String(s), "Integer") => match s.trim().parse::<i64>() {
+            Ok(parsed) => json!(parsed),
+            Err(_) => return Ok(None),
+        },
+        (Value::String(s), "Float") => match s.trim().parse::<f64>() {
+            Ok(parsed) if parsed.is_finite() => json!(parsed),
+            _ => return Ok(None),
+        },
+        (Value::String(s), "Boolean") => match s.trim().to_lowercase().as_str() {
+            "true" => json!(true),
+            "false" => json!(false),
+            _ => return Ok(None),
+        },
+        (Value::Number(n), "String") => json!(n.to_string()),
+        (Value::Number(n), "Integer") => match n.as_i64() {
+            Some(int) => json!(int),
+            None => match n.as_f64() {
+                Some(float) if float.is_finite() => json!(float.trunc() as i64),
+                _ => return Err(unsupported()),
+            },
+        },
+        (Value::Number(n), "Float") => json!(n.as_f64().ok_or_else(unsupported)?),
+        (Value::Number(n), "Boolean") => json!(n.as_f64().ok_or_else(unsupported)? != 0.0),
+        (Value::Bool(b), "String") => json!(b.to_string()),
+        (Value::Bool(b), "Integer") => json!(*b as i64),
+        (Value::Bool(b), "Float") => json!(if *b { 1.0 } else { 0.0 }),
+        (Value::Bool(b), "Boolean") => json!(b),
+        _ => return Err(unsupported()),
+    };
+
+    Ok(Some(result))
+}
+
+#[async_trait]
+impl NodeLogic for CastNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_types_cast",
+            "Cast",
+            "Explicitly converts a value to another type. Floats are truncated to Integers, Strings are parsed",
+            "Utils/Types",
+        );
+        node.add_icon("/flow/icons/convert.svg");
+
+        node.add_input_pin("value", "Value", "Value to cast", VariableType::Generic);
+
+        node.add_input_pin(
+            "target_type",
+            "Target Type",
+            "Type to cast to",
+            VariableType::String,
+        )
+        .set_options(
+            PinOptions::new()
+                .set_valid_values(vec![
+                    "String".to_string(),
+                    "Integer".to_string(),
+                    "Float".to_string(),
+                    "Boolean".to_string(),
+                ])
+                .build(),
+        )
+        .set_default_value(Some(json!("String")));
+
+        node.add_output_pin("result", "Result", "Cast value", VariableType::String);
+
+        node.add_output_pin(
+            "ok",
+            "Ok",
+            "False if a String could not be parsed into the target type",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let value: Value = context.evaluate_pin("value").await?;
+        let target_type: String = context.evaluate_pin("target_type").await?;
+
+        let (result, ok) = match cast(&value, &target_type)? {
+            Some(result) => (result, true),
+            None => (zero_value(&target_type), false),
+        };
+
+        context.set_pin_value("result", result).await?;
+        context.set_pin_value("ok", json!(ok)).await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, _board: Arc<Board>) {
+        let target_type: String = node
+            .get_pin_by_name("target_type")
+            .and_then(|pin| pin.default_value.clone())
+            .and_then(|bytes| flow_like_types::json::from_slice::<Value>(&bytes).ok())
+            .and_then(|json| json.as_str().map(ToOwned::to_owned))
+            .unwrap_or_default();
+
+        let data_type = match target_type.as_str() {
+            "Integer" => VariableType::Integer,
+            "Float" => VariableType::Float,
+            "Boolean" => VariableType::Boolean,
+            _ => VariableType::String,
+        };
+
+        if let Some(result) = node.get_pin_mut_by_name("result") {
+            result.data_type = data_type;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast;
+    use flow_like_types::json::json;
+
+    #[test]
+    fn truncates_and_parses() {
+        assert_eq!(cast(&json!(2.9), "Integer").unwrap(), Some(json!(2)));
+        assert_eq!(cast(&json!(-2.9), "Integer").unwrap(), Some(json!(-2)));
+        assert_eq!(cast(&json!(" 42 "), "Integer").unwrap(), Some(json!(42)));
+        assert_eq!(cast(&json!("4.2x"), "Float").unwrap(), None);
+        assert_eq!(cast(&json!(true), "Integer").unwrap(), Some(json!(1)));
+        assert_eq!(cast(&json!(7), "String").unwrap(), Some(json!("7")));
+    }
+
+    #[test]
+    fn unsupported_names_both_types() {
+        let err = cast(&json!([1, 2]), "Integer").unwrap_err();
+        assert_eq!(err.to_string(), "Cannot cast Array to Integer");
+    }
+}
@@ -0,0 +1,143 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+
+/// Detects the JSON shape of an untyped value and branches on it.
+#[derive(Default)]
+pub struct TypeOfNode {}
+
+impl TypeOfNode {
+    pub fn new() -> Self {
+        TypeOfNode {}
+    }
+}
+
+const TYPES: [&str; 6] = ["object", "array", "string", "number", "bool", "null"];
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "bool",
+        Value::Null => "null",
+    }
+}
+
+#[async_trait]
+impl NodeLogic for TypeOfNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_types_type_of",
+            "Type Of",
+            "Detects whether a value is an object, array, string, number, bool or null and follows the matching flow",
+            "Utils/Types",
+        );
+        node.add_icon("/flow/icons/split.svg");
+
+        node.add_input_pin("exec_in", "Input", "Trigger Pin", VariableType::Execution);
+        node.add_input_pin(
+            "value",
+            "Value",
+            "Value to inspect, e.g. parsed JSON",
+            VariableType::Struct,
+        );
+
+        node.add_output_pin(
+            "type",
+            "Type",
+            "Detected type: object, array, string, number, bool or null",
+            VariableType::String,
+        );
+        node.add_output_pin(
+            "object",
+            "Object",
+            "Value is an object",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "array",
+            "Array",
+            "Value is an array",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "string",
+            "String",
+            "Value is a string",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "number",
+            "Number",
+            "Value is a number",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "bool",
+            "Bool",
+            "Value is a boolean",
+            VariableType::Execution,
+        );
+        node.add_output_pin(
+            "null",
+            "Null",
+            "Value is null or not connected",
+            VariableType::Execution,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        for pin in TYPES {
+            context.deactivate_exec_pin(pin).await?;
+        }
+
+        let value: Value = context.evaluate_pin("value").await.unwrap_or(Value::Null);
+        let detected = type_of(&value);
+
+        context.set_pin_value("type", json!(detected)).await?;
+        context.activate_exec_pin(detected).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_json_shapes() {
+        assert_eq!(type_of(&json!({"a": 1})), "object");
+        assert_eq!(type_of(&json!([1])), "array");
+        assert_eq!(type_of(&json!("a")), "string");
+        assert_eq!(type_of(&json!(1.5)), "number");
+        assert_eq!(type_of(&json!(false)), "bool");
+        assert_eq!(type_of(&Value::Null), "null");
+    }
+
+    #[tokio::test]
+    async fn activates_matching_branch() {
+        let inputs = std::collections::HashMap::from([("value".to_string(), json!([1, 2]))]);
+        let mut context =
+            ExecutionContext::test_harness(std::sync::Arc::new(TypeOfNode::new()), inputs)
+                .await
+                .unwrap();
+        TypeOfNode::new().run(&mut context).await.unwrap();
+
+        let detected: String = context.evaluate_pin("type").await.unwrap();
+        assert_eq!(detected, "array");
+        let array: bool = context.evaluate_pin("array").await.unwrap();
+        let object: bool = context.evaluate_pin("object").await.unwrap();
+        assert!(array);
+        assert!(!object);
+    }
+}
@@ -0,0 +1,156 @@
+use super::cast::cast;
+use flow_like::{
+    flow::{
+        board::Board,
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        utils::PinError,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use std::sync::Arc;
+
+/// Reads a value as the type of the default pin, substituting the default if the value is
+/// missing, null or can't be parsed. Primitives are parsed like the Cast node does.
+#[derive(Default)]
+pub struct WithDefaultNode {}
+
+impl WithDefaultNode {
+    pub fn new() -> Self {
+        WithDefaultNode {}
+    }
+}
+
+/// Returns `None` if the value can't be read as the target type.
+fn coerce(value: Value, target: &VariableType, value_type: &ValueType) -> Option<Value> {
+    if value.is_null() {
+        return None;
+    }
+
+    if *value_type != ValueType::Normal {
+        let matches = match value_type {
+            ValueType::HashMap => value.is_object(),
+            _ => value.is_array(),
+        };
+        return matches.then_some(value);
+    }
+
+    match target {
+        VariableType::String => cast(&value, "String").ok().flatten(),
+        VariableType::Integer => cast(&value, "Integer").ok().flatten(),
+        VariableType::Float => cast(&value, "Float").ok().flatten(),
+        VariableType::Boolean => cast(&value, "Boolean").ok().flatten(),
+        VariableType::Struct => value.is_object().then_some(value),
+        _ => Some(value),
+    }
+}
+
+#[async_trait]
+impl NodeLogic for WithDefaultNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "utils_types_with_default",
+            "With Default",
+            "Reads a value as the type of the default, falling back to the default if the value is missing, null or can't be parsed",
+            "Utils/Types",
+        );
+        node.add_icon("/flow/icons/convert.svg");
+
+        node.add_input_pin(
+            "value",
+            "Value",
+            "Value to read, e.g. a field of an ingested row",
+            VariableType::Generic,
+        );
+
+        node.add_input_pin(
+            "default",
+            "Default",
+            "Fallback value, its type is the target type",
+            VariableType::Generic,
+        );
+
+        node.add_output_pin(
+            "resolved",
+            "Resolved",
+            "The parsed value or the default",
+            VariableType::Generic,
+        );
+
+        node.add_output_pin(
+            "used_default",
+            "Used Default",
+            "True if the default was substituted",
+            VariableType::Boolean,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let default: Value = context.evaluate_pin("default").await?;
+        let (target, value_type) = {
+            let pin = context.get_pin_by_name("default").await?;
+            let pin = pin.lock().await;
+            let pin = pin.pin.lock().await;
+            (pin.data_type.clone(), pin.value_type.clone())
+        };
+
+        let value = match context.evaluate_pin::<Value>("value").await {
+            Ok(value) => Some(value),
+            Err(error) => match PinError::from_error(&error) {
+                Some(pin_error) => {
+                    context.log_message(&format!("{}", pin_error), LogLevel::Debug);
+                    None
+                }
+                None => return Err(error),
+            },
+        };
+
+        let resolved = value.and_then(|value| coerce(value, &target, &value_type));
+        let used_default = resolved.is_none();
+
+        context
+            .set_pin_value("resolved", resolved.unwrap_or(default))
+            .await?;
+        context
+            .set_pin_value("used_default", json!(used_default))
+            .await?;
+        Ok(())
+    }
+
+    async fn on_update(&self, node: &mut Node, board: Arc<Board>) {
+        let _ = node.match_type("default", board.clone(), None, None);
+        let _ = node.match_type("resolved", board, None, None);
+        node.harmonize_type(vec!["default", "resolved"], true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::coerce;
+    use flow_like::flow::{pin::ValueType, variable::VariableType};
+    use flow_like_types::json::json;
+
+    #[test]
+    fn parses_or_rejects_values() {
+        let normal = ValueType::Normal;
+        assert_eq!(
+            coerce(json!(" 42 "), &VariableType::Integer, &normal),
+            Some(json!(42))
+        );
+        assert_eq!(coerce(json!("n/a"), &VariableType::Integer, &normal), None);
+        assert_eq!(coerce(json!(null), &VariableType::String, &normal), None);
+        assert_eq!(
+            coerce(json!(1.5), &VariableType::String, &normal),
+            Some(json!("1.5"))
+        );
+        assert_eq!(
+            coerce(json!("x"), &VariableType::Integer, &ValueType::Array),
+            None
+        );
+    }
+}
@@ -4,9 +4,12 @@ use std::sync::Arc;
 pub mod addition;
 pub mod cosine_sim;
 pub mod cross_product;
+pub mod dedup_similarity;
 pub mod dot_product;
+pub mod mean_pool;
 pub mod multiplication;
 pub mod normalize;
+pub mod similarity_matrix;
 pub mod subtraction;
 
 pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
@@ -18,5 +21,8 @@ pub async fn register_functions() -> Vec<Arc<dyn NodeLogic>> {
         Arc::new(subtraction::FloatVectorSubtractionNode::default()),
         Arc::new(cosine_sim::FloatVectorCosineSimilarityNode::default()),
         Arc::new(normalize::FloatVectorNormalizeNode::default()),
+        Arc::new(mean_pool::FloatVectorMeanPoolNode::default()),
+        Arc::new(dedup_similarity::DedupBySimilarityNode::default()),
+        Arc::new(similarity_matrix::SimilarityMatrixNode::default()),
     ]
 }
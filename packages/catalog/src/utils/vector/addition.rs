@@ -61,7 +61,9 @@ impl NodeLogic for FloatVectorAdditionNode {
 
         if vector1.len() != vector2.len() {
             return Err(flow_like_types::anyhow!(
-                "Vectors must have the same length"
+                "Vectors must have the same length, got {} and {}",
+                vector1.len(),
+                vector2.len()
             ));
         }
 
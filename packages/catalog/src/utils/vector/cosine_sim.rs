@@ -64,7 +64,9 @@ impl NodeLogic for FloatVectorCosineSimilarityNode {
 
         if v1.len() != v2.len() {
             return Err(flow_like_types::anyhow!(
-                "Vectors must have the same length"
+                "Vectors must have the same length, got {} and {}",
+                v1.len(),
+                v2.len()
             ));
         }
 
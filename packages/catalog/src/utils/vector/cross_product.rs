@@ -64,13 +64,13 @@ impl NodeLogic for FloatVectorCrossProductNode {
         let v2 = DVector::from_vec(vector2);
 
         if v1.len() != v2.len() || v1.len() < 2 || v1.len() > 3 {
-            context.log_message(
-                "Vectors must have the same length and be 2D or 3D",
-                LogLevel::Error,
+            let message = format!(
+                "Vectors must have the same length and be 2D or 3D, got {} and {}",
+                v1.len(),
+                v2.len()
             );
-            return Err(flow_like_types::anyhow!(
-                "Vectors must have the same length and be 2D or 3D"
-            ));
+            context.log_message(&message, LogLevel::Error);
+            return Err(flow_like_types::anyhow!(message));
         }
 
         let result_vector = if v1.len() == 2 {
@@ -0,0 +1,145 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{Value, async_trait, json::json};
+use nalgebra::DVector;
+
+#[derive(Default)]
+pub struct DedupBySimilarityNode {}
+
+impl DedupBySimilarityNode {
+    pub fn new() -> Self {
+        DedupBySimilarityNode {}
+    }
+}
+
+fn embedding(item: &Value, field: &str) -> Option<DVector<f64>> {
+    let values = item.get(field)?.as_array()?;
+    let vector = values
+        .iter()
+        .map(Value::as_f64)
+        .collect::<Option<Vec<f64>>>()?;
+    let vector = DVector::from_vec(vector);
+    if vector.is_empty() || vector.norm() == 0.0 {
+        return None;
+    }
+    Some(vector.normalize())
+}
+
+/// Greedily keeps items in input order and drops those whose cosine similarity to an already
+/// kept item exceeds `threshold`. Items without a usable embedding are always kept.
+fn dedup(items: Vec<Value>, field: &str, threshold: f64) -> (Vec<Value>, usize) {
+    let mut kept_vectors: Vec<DVector<f64>> = vec![];
+    let mut kept = Vec::with_capacity(items.len());
+    let mut removed = 0;
+
+    for item in items {
+        let Some(vector) = embedding(&item, field) else {
+            kept.push(item);
+            continue;
+        };
+
+        let duplicate = kept_vectors
+            .iter()
+            .any(|other| other.len() == vector.len() && other.dot(&vector) > threshold);
+        if duplicate {
+            removed += 1;
+            continue;
+        }
+
+        kept_vectors.push(vector);
+        kept.push(item);
+    }
+
+    (kept, removed)
+}
+
+#[async_trait]
+impl NodeLogic for DedupBySimilarityNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "float_vector_dedup_by_similarity",
+            "Deduplicate by Similarity",
+            "Drops items whose embedding is too similar to an earlier item, e.g. near-duplicate chunks in search results",
+            "Utils/Math/Vector",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin(
+            "items",
+            "Items",
+            "Items ordered by relevance, earlier items win",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+        node.add_input_pin(
+            "embedding_field",
+            "Embedding Field",
+            "Field holding the embedding of an item",
+            VariableType::String,
+        )
+        .set_default_value(Some(json!("vector")));
+        node.add_input_pin(
+            "threshold",
+            "Threshold",
+            "Cosine similarity above which an item counts as duplicate",
+            VariableType::Float,
+        )
+        .set_default_value(Some(json!(0.95)));
+
+        node.add_output_pin(
+            "items_out",
+            "Items",
+            "Items without near-duplicates, in input order",
+            VariableType::Struct,
+        )
+        .set_value_type(ValueType::Array);
+        node.add_output_pin(
+            "removed",
+            "Removed",
+            "Number of dropped items",
+            VariableType::Integer,
+        );
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let items: Vec<Value> = context.evaluate_pin("items").await?;
+        let embedding_field: String = context.evaluate_pin("embedding_field").await?;
+        let threshold: f64 = context.evaluate_pin("threshold").await?;
+
+        let (kept, removed) = dedup(items, &embedding_field, threshold);
+
+        context.set_pin_value("items_out", json!(kept)).await?;
+        context.set_pin_value("removed", json!(removed)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dedup;
+    use flow_like_types::json::json;
+
+    #[test]
+    fn drops_near_duplicates_and_keeps_unembedded() {
+        let items = vec![
+            json!({"id": 1, "vector": [1.0, 0.0]}),
+            json!({"id": 2, "vector": [0.99, 0.01]}),
+            json!({"id": 3}),
+            json!({"id": 4, "vector": [0.0, 1.0]}),
+        ];
+
+        let (kept, removed) = dedup(items, "vector", 0.95);
+        let ids: Vec<i64> = kept.iter().map(|i| i["id"].as_i64().unwrap()).collect();
+        assert_eq!(ids, vec![1, 3, 4]);
+        assert_eq!(removed, 1);
+    }
+}
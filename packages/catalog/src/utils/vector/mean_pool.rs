@@ -0,0 +1,101 @@
+use flow_like::{
+    flow::{
+        execution::context::ExecutionContext,
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+use nalgebra::DVector;
+
+#[derive(Default)]
+pub struct FloatVectorMeanPoolNode {}
+
+impl FloatVectorMeanPoolNode {
+    pub fn new() -> Self {
+        FloatVectorMeanPoolNode {}
+    }
+}
+
+fn mean_pool(vectors: &[Vec<f64>], normalize: bool) -> flow_like_types::Result<Vec<f64>> {
+    let first = vectors
+        .first()
+        .ok_or_else(|| flow_like_types::anyhow!("Cannot mean-pool an empty set of vectors"))?;
+
+    let mut sum = DVector::zeros(first.len());
+    for (index, vector) in vectors.iter().enumerate() {
+        if vector.len() != first.len() {
+            return Err(flow_like_types::anyhow!(
+                "Vectors must have the same length, vector 0 has {} but vector {} has {}",
+                first.len(),
+                index,
+                vector.len()
+            ));
+        }
+        sum += DVector::from_column_slice(vector);
+    }
+
+    let mean = sum / vectors.len() as f64;
+    if !normalize {
+        return Ok(mean.iter().cloned().collect());
+    }
+
+    if mean.norm() == 0.0 {
+        return Err(flow_like_types::anyhow!(
+            "Cannot normalize a zero-length vector"
+        ));
+    }
+    Ok(mean.normalize().iter().cloned().collect())
+}
+
+#[async_trait]
+impl NodeLogic for FloatVectorMeanPoolNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "float_vector_mean_pool",
+            "Mean Pool",
+            "Averages equal-length float vectors element-wise, e.g. chunk embeddings into one document embedding",
+            "Utils/Math/Vector",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin(
+            "vectors",
+            "Vectors",
+            "Array of float vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+        node.add_input_pin(
+            "normalize",
+            "Normalize",
+            "L2-normalize the mean vector",
+            VariableType::Boolean,
+        )
+        .set_default_value(Some(json!(false)));
+
+        node.add_output_pin(
+            "mean_vector",
+            "Mean Vector",
+            "Element-wise mean of the vectors",
+            VariableType::Float,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let vectors: Vec<Vec<f64>> = context.evaluate_pin("vectors").await?;
+        let normalize: bool = context.evaluate_pin("normalize").await?;
+
+        let mean_vector = mean_pool(&vectors, normalize)?;
+
+        context
+            .set_pin_value("mean_vector", json!(mean_vector))
+            .await?;
+        Ok(())
+    }
+}
@@ -54,6 +54,12 @@ impl NodeLogic for FloatVectorNormalizeNode {
 
         let v = DVector::from_vec(vector);
 
+        if v.norm() == 0.0 {
+            return Err(flow_like_types::anyhow!(
+                "Cannot normalize a zero-length vector"
+            ));
+        }
+
         let normalized_vector = v.normalize();
 
         context
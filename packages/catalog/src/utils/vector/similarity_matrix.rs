@@ -0,0 +1,150 @@
+use flow_like::{
+    flow::{
+        execution::{LogLevel, context::ExecutionContext},
+        node::{Node, NodeLogic},
+        pin::ValueType,
+        variable::VariableType,
+    },
+    state::FlowLikeState,
+};
+use flow_like_types::{async_trait, json::json};
+use nalgebra::DVector;
+
+/// Inputs above this size get a warning about the quadratic cost.
+const WARN_VECTORS: usize = 500;
+
+#[derive(Default)]
+pub struct SimilarityMatrixNode {}
+
+impl SimilarityMatrixNode {
+    pub fn new() -> Self {
+        SimilarityMatrixNode {}
+    }
+}
+
+/// Pairwise cosine similarity of equal-length vectors. The diagonal is exactly 1.0,
+/// zero vectors have a similarity of 0.0 to every other vector.
+pub(crate) fn similarity_matrix(vectors: &[Vec<f64>]) -> flow_like_types::Result<Vec<Vec<f64>>> {
+    let Some(first) = vectors.first() else {
+        return Ok(vec![]);
+    };
+
+    let mut normalized = Vec::with_capacity(vectors.len());
+    for (index, vector) in vectors.iter().enumerate() {
+        if vector.len() != first.len() {
+            return Err(flow_like_types::anyhow!(
+                "Vectors must have the same length, vector 0 has {} but vector {} has {}",
+                first.len(),
+                index,
+                vector.len()
+            ));
+        }
+        let vector = DVector::from_column_slice(vector);
+        let norm = vector.norm();
+        normalized.push((norm > 0.0).then(|| vector / norm));
+    }
+
+    let n = normalized.len();
+    let mut matrix = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        matrix[i][i] = 1.0;
+        for j in (i + 1)..n {
+            if let (Some(a), Some(b)) = (&normalized[i], &normalized[j]) {
+                let similarity = a.dot(b).clamp(-1.0, 1.0);
+                matrix[i][j] = similarity;
+                matrix[j][i] = similarity;
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+#[async_trait]
+impl NodeLogic for SimilarityMatrixNode {
+    async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+        let mut node = Node::new(
+            "float_vector_similarity_matrix",
+            "Similarity Matrix",
+            "Pairwise cosine similarity of all vectors, e.g. embeddings for clustering. Cost grows quadratically with the number of vectors",
+            "Utils/Math/Vector",
+        );
+        node.add_icon("/flow/icons/grip.svg");
+
+        node.add_input_pin(
+            "vectors",
+            "Vectors",
+            "Array of float vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+        node.add_input_pin(
+            "max_vectors",
+            "Max Vectors",
+            "Fails for more vectors than this, the matrix has N x N entries",
+            VariableType::Integer,
+        )
+        .set_default_value(Some(json!(2000)));
+
+        node.add_output_pin(
+            "matrix",
+            "Matrix",
+            "Row i holds the similarities of vector i to all vectors",
+            VariableType::Generic,
+        )
+        .set_value_type(ValueType::Array);
+
+        return node;
+    }
+
+    async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+        let vectors: Vec<Vec<f64>> = context.evaluate_pin("vectors").await?;
+        let max_vectors: i64 = context.evaluate_pin("max_vectors").await?;
+
+        if vectors.len() > max_vectors.max(0) as usize {
+            return Err(flow_like_types::anyhow!(
+                "Got {} vectors but Max Vectors is {}, the matrix would have {} entries",
+                vectors.len(),
+                max_vectors,
+                vectors.len() * vectors.len()
+            ));
+        }
+
+        if vectors.len() > WARN_VECTORS {
+            context.log_message(
+                &format!(
+                    "Computing {} pairwise similarities, this grows quadratically with the number of vectors",
+                    vectors.len() * (vectors.len() - 1) / 2
+                ),
+                LogLevel::Warn,
+            );
+        }
+
+        let matrix = similarity_matrix(&vectors)?;
+
+        context.set_pin_value("matrix", json!(matrix)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal_is_one_and_matrix_is_symmetric() {
+        let matrix = similarity_matrix(&[vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 0.0]]).unwrap();
+
+        assert_eq!(matrix[0][0], 1.0);
+        assert_eq!(matrix[1][1], 1.0);
+        assert_eq!(matrix[2][2], 1.0);
+        assert!((matrix[0][1] - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+        assert_eq!(matrix[0][1], matrix[1][0]);
+        assert_eq!(matrix[0][2], 0.0);
+    }
+
+    #[test]
+    fn rejects_mismatched_lengths() {
+        assert!(similarity_matrix(&[vec![1.0], vec![1.0, 2.0]]).is_err());
+    }
+}
@@ -355,6 +355,64 @@ impl Board {
         self.variables.get(variable_id)
     }
 
+    /// Deterministic fingerprint of the executable graph structure.
+    ///
+    /// Combines every node, its pins (including connections and default values) and the layer pins.
+    /// Maps are visited in sorted key order and the hasher uses a fixed key, so the value is stable
+    /// across process runs. Cosmetic data (coordinates, comments, viewport) is ignored, so caches
+    /// keyed on this hash are only invalidated by edits that change execution.
+    pub fn structure_hash(&self) -> u64 {
+        let mut hasher = HighwayHasher::new(highway::Key([
+            0x0123456789abcdef,
+            0xfedcba9876543210,
+            0x0011223344556677,
+            0x8899aabbccddeeff,
+        ]));
+
+        fn hash_pins(hasher: &mut HighwayHasher, pins: &HashMap<String, Pin>) {
+            let mut sorted_pins: Vec<_> = pins.values().collect();
+            sorted_pins.sort_by(|a, b| a.id.cmp(&b.id));
+            for pin in sorted_pins {
+                pin.hash(hasher);
+                for dep in &pin.depends_on {
+                    hasher.append(dep.as_bytes());
+                }
+                for conn in &pin.connected_to {
+                    hasher.append(conn.as_bytes());
+                }
+                if let Some(default_value) = &pin.default_value {
+                    hasher.append(default_value);
+                }
+            }
+        }
+
+        let mut sorted_nodes: Vec<_> = self.nodes.values().collect();
+        sorted_nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        for node in sorted_nodes {
+            hasher.append(node.id.as_bytes());
+            hasher.append(node.name.as_bytes());
+            if let Some(layer) = &node.layer {
+                hasher.append(layer.as_bytes());
+            }
+            hash_pins(&mut hasher, &node.pins);
+        }
+
+        let mut sorted_layers: Vec<_> = self.layers.values().collect();
+        sorted_layers.sort_by(|a, b| a.id.cmp(&b.id));
+        for layer in sorted_layers {
+            hasher.append(layer.id.as_bytes());
+            hash_pins(&mut hasher, &layer.pins);
+        }
+
+        let mut sorted_variables: Vec<_> = self.variables.keys().collect();
+        sorted_variables.sort();
+        for variable_id in sorted_variables {
+            hasher.append(variable_id.as_bytes());
+        }
+
+        hasher.finalize64()
+    }
+
     pub async fn create_version(
         &mut self,
         version_type: VersionType,
@@ -878,4 +936,31 @@ mod tests {
 
         assert_eq!(board.id, deser_board.id);
     }
+
+    #[tokio::test]
+    async fn structure_hash_is_order_independent() {
+        let state = flow_state().await;
+        let mut board = super::Board::new(None, Path::from("boards"), state);
+        let mut first = crate::flow::node::Node::new("a", "A", "", "Test");
+        first.add_input_pin("in", "In", "", crate::flow::variable::VariableType::String);
+        let second = crate::flow::node::Node::new("b", "B", "", "Test");
+
+        board.nodes.insert(first.id.clone(), first.clone());
+        board.nodes.insert(second.id.clone(), second.clone());
+        let hash = board.structure_hash();
+
+        let mut reordered = board.clone();
+        reordered.nodes.clear();
+        reordered.nodes.insert(second.id.clone(), second);
+        reordered.nodes.insert(first.id.clone(), first.clone());
+        assert_eq!(hash, reordered.structure_hash());
+
+        let mut moved = first.clone();
+        moved.coordinates = Some((10.0, 10.0, 0.0));
+        reordered.nodes.insert(moved.id.clone(), moved);
+        assert_eq!(hash, reordered.structure_hash());
+
+        reordered.nodes.remove(&first.id);
+        assert_ne!(hash, reordered.structure_hash());
+    }
 }
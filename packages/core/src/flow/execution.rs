@@ -30,7 +30,7 @@ use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::hash::Hasher;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 use std::{
     sync::{Arc, Weak},
@@ -42,6 +42,8 @@ pub mod context;
 pub mod internal_node;
 pub mod internal_pin;
 pub mod log;
+#[cfg(any(test, feature = "test-harness"))]
+pub mod test_board;
 pub mod trace;
 
 const USE_DEPENDENCY_GRAPH: bool = false;
@@ -203,6 +205,11 @@ impl LogMeta {
     }
 }
 
+/// Observer for node state transitions, called as `(node_id, old_state, new_state, span)`.
+/// It runs inline on the executing task, so it has to return quickly.
+pub type NodeStateCallback =
+    Arc<dyn Fn(&str, &NodeState, &NodeState, &trace::SpanContext) + Send + Sync>;
+
 #[derive(Clone)]
 pub struct Run {
     pub id: String,
@@ -219,6 +226,21 @@ pub struct Run {
     pub log_initialized: bool,
     pub logs: u64,
     pub stream_state: bool,
+    pub structure_hash: u64,
+    /// Record the resolved input pin values of every node execution in its [`Trace::pin_values`].
+    pub capture_pin_values: bool,
+    /// Notified on every node state transition, in execution order.
+    pub state_callback: Option<NodeStateCallback>,
+    /// Abort the run once more nodes than this were executed, `None` means unlimited.
+    pub max_nodes_executed: Option<u64>,
+    /// Node executions so far, counting dependencies, successors and error handlers alike.
+    pub nodes_executed: Arc<AtomicU64>,
+    /// Seed for nodes drawing random values, see [`ExecutionContext::rng`]. `None` draws
+    /// from system entropy, making such nodes nondeterministic.
+    pub seed: Option<u64>,
+    /// Distributed trace id of the request that started the run, inherited by the
+    /// [`trace::SpanContext`] of every node execution.
+    pub trace_id: Option<String>,
 
     pub event_id: Option<String>,
     pub event_version: Option<String>,
@@ -438,6 +460,13 @@ impl InternalRun {
             log_initialized: false,
             logs: 0,
             stream_state,
+            structure_hash: board.structure_hash(),
+            capture_pin_values: false,
+            state_callback: None,
+            max_nodes_executed: None,
+            nodes_executed: Arc::new(AtomicU64::new(0)),
+            seed: None,
+            trace_id: None,
 
             event_id: event.as_ref().map(|e| e.id.clone()),
             event_version: event.as_ref().map(|e| {
@@ -659,6 +688,11 @@ impl InternalRun {
         self.run.lock().await.traces.clear();
         self.run.lock().await.start = SystemTime::now();
         self.run.lock().await.end = SystemTime::now();
+        self.run
+            .lock()
+            .await
+            .nodes_executed
+            .store(0, Ordering::SeqCst);
         for node in self.nodes.values() {
             for (_, pin) in node.pins.iter() {
                 pin.lock().await.reset().await;
@@ -890,6 +924,30 @@ impl InternalRun {
         true
     }
 
+    pub async fn set_state_callback(&self, callback: NodeStateCallback) {
+        self.run.lock().await.state_callback = Some(callback);
+    }
+
+    /// Records the input values of every node execution, see [`Run::capture_pin_values`].
+    pub async fn set_capture_pin_values(&self, capture_pin_values: bool) {
+        self.run.lock().await.capture_pin_values = capture_pin_values;
+    }
+
+    /// Safety valve for user authored flows, see [`Run::max_nodes_executed`].
+    pub async fn set_max_nodes_executed(&self, max_nodes_executed: Option<u64>) {
+        self.run.lock().await.max_nodes_executed = max_nodes_executed;
+    }
+
+    /// Makes the run reproducible, see [`Run::seed`].
+    pub async fn set_seed(&self, seed: Option<u64>) {
+        self.run.lock().await.seed = seed;
+    }
+
+    /// Correlates the run with an incoming request, see [`Run::trace_id`].
+    pub async fn set_trace_id(&self, trace_id: Option<String>) {
+        self.run.lock().await.trace_id = trace_id;
+    }
+
     pub async fn get_run(&self) -> Run {
         self.run.lock().await.clone()
     }
@@ -1069,3 +1127,203 @@ async fn step_core(
 
     Err(anyhow!("Node failed"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::test_board::TestBoard;
+    use crate::flow::{
+        execution::{
+            LogLevel,
+            context::{ExecutionContext, REDACTED_PIN_VALUE},
+        },
+        node::{Node, NodeLogic, NodeState},
+        pin::PinOptions,
+        variable::VariableType,
+    };
+    use crate::state::FlowLikeState;
+    use flow_like_types::{async_trait, json::json, rand::Rng, tokio};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct ReadInputsNode;
+
+    #[async_trait]
+    impl NodeLogic for ReadInputsNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_read_inputs", "Read Inputs", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_input_pin("text", "Text", "", VariableType::String);
+            node.add_input_pin("secret", "Secret", "", VariableType::String)
+                .set_options(PinOptions::new().set_sensitive(true).build());
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            let _: String = context.evaluate_pin("text").await?;
+            let _: String = context.evaluate_pin("secret").await?;
+            Ok(())
+        }
+    }
+
+    async fn read_inputs_board() -> (TestBoard, String) {
+        let logic: Arc<dyn NodeLogic> = Arc::new(ReadInputsNode);
+        let mut board = TestBoard::new(vec![logic.clone()]).await;
+        let node = board.add(logic.as_ref()).await;
+        board.set_input(&node, "text", json!("hello"));
+        board.set_input(&node, "secret", json!("hunter2"));
+        (board, node)
+    }
+
+    #[tokio::test]
+    async fn captured_pin_values_are_stored_in_traces() {
+        let (board, node) = read_inputs_board().await;
+        let mut run = board.prepare(&node).await;
+        run.set_capture_pin_values(true).await;
+        run.execute(board.state.clone()).await;
+
+        let traces = run.get_traces().await;
+        let trace = traces
+            .iter()
+            .find(|trace| trace.node_id == node)
+            .expect("trace of the executed node");
+        let values = trace.pin_values.as_ref().expect("captured pin values");
+        assert_eq!(values.get("text"), Some(&json!("hello")));
+        assert_eq!(values.get("secret"), Some(&json!(REDACTED_PIN_VALUE)));
+    }
+
+    #[tokio::test]
+    async fn pin_values_are_not_captured_by_default() {
+        let (board, node) = read_inputs_board().await;
+        let run = board.run(&node).await;
+        let traces = run.get_traces().await;
+        assert!(!traces.is_empty());
+        assert!(traces.iter().all(|trace| trace.pin_values.is_none()));
+    }
+
+    #[tokio::test]
+    async fn state_callback_fires_on_each_transition() {
+        let (board, node) = read_inputs_board().await;
+        let transitions = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = transitions.clone();
+        let mut run = board.prepare(&node).await;
+        run.set_state_callback(Arc::new(move |node_id, old, new, _span| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((node_id.to_string(), old.clone(), new.clone()));
+        }))
+        .await;
+        run.execute(board.state.clone()).await;
+
+        let transitions = transitions.lock().unwrap();
+        assert_eq!(
+            *transitions,
+            vec![
+                (node.clone(), NodeState::Idle, NodeState::Running),
+                (node.clone(), NodeState::Running, NodeState::Success),
+            ]
+        );
+    }
+
+    /// Exec node that passes execution on and counts how often it ran.
+    #[derive(Default)]
+    struct PassNode {
+        runs: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl NodeLogic for PassNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_pass", "Pass", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_output_pin("exec_out", "Output", "", VariableType::Execution);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            context.activate_exec_pin("exec_out").await
+        }
+    }
+
+    #[tokio::test]
+    async fn looping_board_stops_at_the_node_budget() {
+        let logic = Arc::new(PassNode::default());
+        let mut board = TestBoard::new(vec![logic.clone()]).await;
+        let first = board.add(logic.as_ref()).await;
+        let second = board.add(logic.as_ref()).await;
+        board.connect(&first, "exec_out", &second, "exec_in");
+        board.connect(&second, "exec_out", &first, "exec_in");
+
+        let mut run = board.prepare(&first).await;
+        run.set_max_nodes_executed(Some(5)).await;
+        run.execute(board.state.clone()).await;
+
+        assert_eq!(logic.runs.load(Ordering::SeqCst), 5);
+        let traces = run.get_traces().await;
+        assert!(traces.iter().flat_map(|trace| &trace.logs).any(|log| {
+            log.log_level == LogLevel::Fatal && log.message.contains("limit of 5 nodes per run")
+        }));
+    }
+
+    #[tokio::test]
+    async fn fork_resets_the_node_budget() {
+        let logic = Arc::new(PassNode::default());
+        let mut board = TestBoard::new(vec![logic.clone()]).await;
+        let first = board.add(logic.as_ref()).await;
+        let second = board.add(logic.as_ref()).await;
+        board.connect(&first, "exec_out", &second, "exec_in");
+
+        let mut run = board.prepare(&first).await;
+        run.set_max_nodes_executed(Some(10)).await;
+        run.execute(board.state.clone()).await;
+        let executed = run.run.lock().await.nodes_executed.clone();
+        assert_eq!(executed.load(Ordering::SeqCst), 2);
+
+        run.fork().await.unwrap();
+        assert_eq!(executed.load(Ordering::SeqCst), 0);
+    }
+
+    /// Draws from the context's rng and records the values.
+    #[derive(Default)]
+    struct RandomNode {
+        draws: std::sync::Mutex<Vec<u64>>,
+    }
+
+    #[async_trait]
+    impl NodeLogic for RandomNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_random", "Random", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            let mut rng = context.rng();
+            let draws: Vec<u64> = (0..4).map(|_| rng.random()).collect();
+            self.draws.lock().unwrap().extend(draws);
+            Ok(())
+        }
+    }
+
+    async fn seeded_draws(seed: u64) -> Vec<u64> {
+        let logic = Arc::new(RandomNode::default());
+        let mut board = TestBoard::new(vec![logic.clone()]).await;
+        let node = board.add(logic.as_ref()).await;
+        for _ in 0..2 {
+            let mut run = board.prepare(&node).await;
+            run.set_seed(Some(seed)).await;
+            run.execute(board.state.clone()).await;
+        }
+        let draws = logic.draws.lock().unwrap().clone();
+        assert_eq!(draws.len(), 8);
+        draws
+    }
+
+    #[tokio::test]
+    async fn same_seed_produces_the_same_rng_sequence() {
+        let draws = seeded_draws(42).await;
+        assert_eq!(draws[..4], draws[4..]);
+        assert_ne!(draws, seeded_draws(43).await);
+    }
+}
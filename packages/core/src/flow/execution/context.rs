@@ -1,14 +1,19 @@
 use super::{
-    EventTrigger, InternalNode, LogLevel, Run, RunPayload, internal_pin::InternalPin,
-    log::LogMessage, trace::Trace,
+    EventTrigger, InternalNode, LogLevel, NodeStateCallback, Run, RunPayload,
+    internal_pin::InternalPin,
+    log::LogMessage,
+    trace::{SpanContext, Trace},
 };
 use crate::{
     credentials::SharedCredentials,
     flow::{
         board::ExecutionStage,
         node::{Node, NodeState},
-        pin::PinType,
-        utils::{evaluate_pin_value, evaluate_pin_value_reference},
+        pin::{PinType, ValueType},
+        utils::{
+            deserialize_pin_value, evaluate_pin_value, evaluate_pin_value_reference,
+            is_exec_active, validate_pin_schema,
+        },
         variable::{Variable, VariableType},
     },
     profile::Profile,
@@ -19,17 +24,112 @@ use flow_like_model_provider::provider::ModelProviderConfiguration;
 use flow_like_storage::object_store::path::Path;
 use flow_like_types::Value;
 use flow_like_types::intercom::{InterComCallback, InterComEvent};
+use flow_like_types::rand::{self, SeedableRng, rngs::StdRng};
+use flow_like_types::tokio_util::sync::CancellationToken;
 use flow_like_types::{
     Cacheable,
-    json::from_value,
     sync::{Mutex, RwLock},
 };
+use highway::{HighwayHash, HighwayHasher};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::{
     collections::BTreeMap,
-    sync::{Arc, Weak},
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
 };
 
+/// Stands in for the value of sensitive pins wherever values are shown or recorded.
+pub const REDACTED_PIN_VALUE: &str = "[redacted]";
+
+/// Details of an evaluated pin, read under a single lock.
+struct EvaluatedPin {
+    name: String,
+    friendly_name: String,
+    sensitive: bool,
+    /// Schema and value type, only set if the pin enforces its schema.
+    schema: Option<(String, ValueType)>,
+}
+
+impl EvaluatedPin {
+    async fn read(pin: &Arc<Mutex<InternalPin>>) -> Self {
+        let pin = pin.lock().await;
+        let pin = pin.pin.lock().await;
+        let options = pin.options.as_ref();
+        let enforce = options
+            .and_then(|options| options.enforce_schema)
+            .unwrap_or(false);
+        EvaluatedPin {
+            name: pin.name.clone(),
+            friendly_name: pin.friendly_name.clone(),
+            sensitive: options
+                .and_then(|options| options.sensitive)
+                .unwrap_or(false),
+            schema: pin
+                .schema
+                .clone()
+                .filter(|_| enforce)
+                .map(|schema| (schema, pin.value_type.clone())),
+        }
+    }
+
+    /// Validates the value against the pin schema if the pin sets `enforce_schema`.
+    fn enforce_schema(&self, value: &Value) -> flow_like_types::Result<()> {
+        match &self.schema {
+            Some((schema, value_type)) => {
+                validate_pin_schema(&self.friendly_name, schema, value_type, value)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Mutable per-node state stored in the run cache, see [`ExecutionContext::run_state`].
+struct RunScopedState {
+    value: Arc<Mutex<Value>>,
+}
+
+impl Cacheable for RunScopedState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Break and continue requests raised inside a loop body. A loop node hands a fresh
+/// signal to the sub contexts of its body, which pass it on to every node they start,
+/// so a nested loop shadows the signal of the loop around it.
+#[derive(Default)]
+pub struct LoopSignal {
+    broken: AtomicBool,
+    skipped: AtomicBool,
+}
+
+impl LoopSignal {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Whether the loop should stop iterating.
+    pub fn is_broken(&self) -> bool {
+        self.broken.load(Ordering::SeqCst)
+    }
+
+    /// Whether the rest of the current pass is skipped, after either a break or a continue.
+    pub fn pass_ended(&self) -> bool {
+        self.is_broken() || self.skipped.load(Ordering::SeqCst)
+    }
+
+    /// Clears a pending continue, called by the loop before starting the next pass.
+    pub fn next_pass(&self) {
+        self.skipped.store(false, Ordering::SeqCst);
+    }
+}
+
 #[derive(Clone)]
 pub struct ExecutionContextCache {
     pub stores: FlowLikeStores,
@@ -151,9 +251,17 @@ pub struct ExecutionContext {
     pub credentials: Option<Arc<SharedCredentials>>,
     pub delegated: bool,
     pub context_state: BTreeMap<String, Value>,
+    pub capture_pin_values: bool,
     run_id: String,
     state: NodeState,
     callback: InterComCallback,
+    pin_snapshot: Arc<std::sync::Mutex<BTreeMap<String, Value>>>,
+    error_recovery: Option<Arc<AtomicBool>>,
+    loop_signal: Option<Arc<LoopSignal>>,
+    state_callback: Option<NodeStateCallback>,
+    node_budget: Option<(Arc<AtomicU64>, u64)>,
+    seed: Option<u64>,
+    span: SpanContext,
 }
 
 impl ExecutionContext {
@@ -182,13 +290,26 @@ impl ExecutionContext {
             trace.snapshot_variables(variables).await;
         }
 
-        let (run_id, stream_state) = match run.upgrade() {
-            Some(run) => {
-                let run = run.lock().await;
-                (run.id.clone(), run.stream_state)
-            }
-            None => ("".to_string(), false),
-        };
+        let (run_id, stream_state, capture_pin_values, state_callback, node_budget, seed, trace_id) =
+            match run.upgrade() {
+                Some(run) => {
+                    let run = run.lock().await;
+                    (
+                        run.id.clone(),
+                        run.stream_state,
+                        run.capture_pin_values,
+                        run.state_callback.clone(),
+                        run.max_nodes_executed
+                            .map(|max| (run.nodes_executed.clone(), max)),
+                        run.seed,
+                        run.trace_id.clone(),
+                    )
+                }
+                None => ("".to_string(), false, false, None, None, None, None),
+            };
+
+        let span = SpanContext::root(trace_id);
+        trace.span = Some(span.clone());
 
         ExecutionContext {
             id,
@@ -209,6 +330,14 @@ impl ExecutionContext {
             stream_state,
             state: NodeState::Idle,
             context_state: BTreeMap::new(),
+            capture_pin_values,
+            pin_snapshot: Arc::new(std::sync::Mutex::new(BTreeMap::new())),
+            error_recovery: None,
+            loop_signal: None,
+            state_callback,
+            node_budget,
+            seed,
+            span,
             nodes,
             completion_callbacks,
             credentials,
@@ -221,8 +350,10 @@ impl ExecutionContext {
         self.started_by.as_ref().and_then(|v| v.first().cloned())
     }
 
+    /// Context for running `node` on behalf of this one. Its span is a child of this
+    /// context's span, so the sub execution stays correlated with the parent.
     pub async fn create_sub_context(&self, node: &Arc<InternalNode>) -> ExecutionContext {
-        ExecutionContext::new(
+        let mut sub = ExecutionContext::new(
             self.nodes.clone(),
             &self.run,
             &self.app_state,
@@ -236,7 +367,11 @@ impl ExecutionContext {
             self.completion_callbacks.clone(),
             self.credentials.clone(),
         )
-        .await
+        .await;
+        sub.span = self.span.child();
+        sub.trace.span = Some(sub.span.clone());
+        sub.loop_signal = self.loop_signal.clone();
+        sub
     }
 
     pub async fn get_variable(&self, variable_id: &str) -> flow_like_types::Result<Variable> {
@@ -317,6 +452,7 @@ impl ExecutionContext {
 
         let mut log = log;
         log.node_id = Some(self.trace.node_id.clone());
+        log.put_span(&self.span);
         self.trace.logs.push(log);
     }
 
@@ -327,11 +463,31 @@ impl ExecutionContext {
 
         let mut log = LogMessage::new(message, log_level, None);
         log.node_id = Some(self.trace.node_id.clone());
+        log.put_span(&self.span);
+        self.trace.logs.push(log);
+    }
+
+    /// Like [`ExecutionContext::log_message`], attaching structured `fields` (e.g. pin values
+    /// or timings) that stay queryable instead of being formatted into the message.
+    pub fn log_message_with_fields(&mut self, message: &str, log_level: LogLevel, fields: Value) {
+        if log_level < self.log_level {
+            return;
+        }
+
+        let mut log = LogMessage::new(message, log_level, None);
+        log.node_id = Some(self.trace.node_id.clone());
+        log.put_span(&self.span);
+        log.put_fields(fields);
         self.trace.logs.push(log);
     }
 
     pub async fn set_state(&mut self, state: NodeState) {
-        self.state = state;
+        let old_state = std::mem::replace(&mut self.state, state);
+        if let Some(callback) = &self.state_callback
+            && old_state != self.state
+        {
+            callback(&self.id, &old_state, &self.state, &self.span);
+        }
 
         let method = match self.state {
             NodeState::Running => RunUpdateEventMethod::Add,
@@ -377,14 +533,14 @@ impl ExecutionContext {
         Ok(config)
     }
 
+    /// Evaluates a pin into `T`. Missing, null and mistyped values fail with a
+    /// [`PinError`](crate::flow::utils::PinError) that can be recovered with `PinError::from_error`.
     pub async fn evaluate_pin<T: DeserializeOwned>(
         &self,
         name: &str,
     ) -> flow_like_types::Result<T> {
         let pin = self.get_pin_by_name(name).await?;
-        let value = evaluate_pin_value(pin).await?;
-        let value = from_value(value)?;
-        Ok(value)
+        self.evaluate_pin_ref(pin).await
     }
 
     pub async fn evaluate_pin_to_ref(
@@ -392,7 +548,14 @@ impl ExecutionContext {
         name: &str,
     ) -> flow_like_types::Result<Arc<Mutex<Value>>> {
         let pin = self.get_pin_by_name(name).await?;
-        let value = evaluate_pin_value_reference(pin).await?;
+        let value =
+            evaluate_pin_value_reference(pin.clone(), Some(self.app_state.as_ref())).await?;
+        let details = EvaluatedPin::read(&pin).await;
+        if self.capture_pin_values || details.schema.is_some() {
+            let snapshot = value.lock().await.clone();
+            details.enforce_schema(&snapshot)?;
+            self.record_pin_value(&details, &snapshot);
+        }
         Ok(value)
     }
 
@@ -400,9 +563,152 @@ impl ExecutionContext {
         &self,
         reference: Arc<Mutex<InternalPin>>,
     ) -> flow_like_types::Result<T> {
-        let value = evaluate_pin_value(reference).await?;
-        let value = from_value(value)?;
-        Ok(value)
+        let value = evaluate_pin_value(reference.clone(), Some(self.app_state.as_ref())).await?;
+        let details = EvaluatedPin::read(&reference).await;
+        details.enforce_schema(&value)?;
+        self.record_pin_value(&details, &value);
+        deserialize_pin_value(&details.friendly_name, value)
+    }
+
+    fn record_pin_value(&self, pin: &EvaluatedPin, value: &Value) {
+        if !self.capture_pin_values {
+            return;
+        }
+
+        let value = if pin.sensitive {
+            Value::String(REDACTED_PIN_VALUE.to_string())
+        } else {
+            value.clone()
+        };
+
+        if let Ok(mut snapshot) = self.pin_snapshot.lock() {
+            snapshot.insert(pin.name.clone(), value);
+        }
+    }
+
+    /// Input pin values resolved during this node execution, keyed by pin name.
+    /// Empty unless `capture_pin_values` is enabled; sensitive pins are redacted.
+    /// The snapshot is stored on the node's [`Trace`] once its traces are taken.
+    pub fn pin_snapshot(&self) -> BTreeMap<String, Value> {
+        match self.pin_snapshot.lock() {
+            Ok(snapshot) => snapshot.clone(),
+            Err(_) => BTreeMap::new(),
+        }
+    }
+
+    /// Tracing span of this node execution, see [`SpanContext`].
+    pub fn span(&self) -> &SpanContext {
+        &self.span
+    }
+
+    /// The run's seed, `None` if the run is not meant to be reproducible.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Random number generator for nodes that should honor the run's seed.
+    ///
+    /// With a seed, the generator is derived from the seed, the node id and how often the
+    /// node has run, so a seeded run produces the same values every time while nodes and
+    /// loop iterations still differ. Without a seed it is seeded from system entropy.
+    pub fn rng(&self) -> StdRng {
+        let Some(seed) = self.seed else {
+            return StdRng::from_rng(&mut rand::rng());
+        };
+
+        let mut hasher = HighwayHasher::new(highway::Key([seed, !seed, seed, !seed]));
+        hasher.append(self.id.as_bytes());
+        hasher.append(&self.node.exec_calls.load(Ordering::Relaxed).to_le_bytes());
+        StdRng::seed_from_u64(hasher.finalize64())
+    }
+
+    /// Counts a node execution against the run's `max_nodes_executed`.
+    /// Fails with the number of executed nodes once the limit is exceeded.
+    pub(crate) fn count_node_execution(&self) -> flow_like_types::Result<()> {
+        let Some((executed, max)) = &self.node_budget else {
+            return Ok(());
+        };
+
+        let executed = executed.fetch_add(1, Ordering::Relaxed) + 1;
+        if executed > *max {
+            return Err(flow_like_types::anyhow!(
+                "Run aborted: {} nodes executed, exceeding the limit of {} nodes per run",
+                executed,
+                max
+            ));
+        }
+        Ok(())
+    }
+
+    /// Marks this context as part of an error handler chain, see [`Self::recover_error`].
+    pub(crate) fn set_error_recovery(&mut self, recovery: Arc<AtomicBool>) {
+        self.error_recovery = Some(recovery);
+    }
+
+    /// Signals that the error currently being handled was recovered from. Once the handler
+    /// chain finished, execution resumes with the successors of the failed node instead of
+    /// aborting. Returns false if this node is not running inside an error handler chain.
+    pub fn recover_error(&self) -> bool {
+        match &self.error_recovery {
+            Some(recovery) => {
+                recovery.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Makes this context part of a loop body. Loop nodes call this on the sub contexts
+    /// they create for each pass, see [`LoopSignal`].
+    pub fn set_loop_signal(&mut self, signal: Arc<LoopSignal>) {
+        self.loop_signal = Some(signal);
+    }
+
+    /// Detaches this context from the loop around it. Nodes running subgraphs that are
+    /// not loop bodies, e.g. predicates, call this so a break inside can't reach the loop.
+    pub fn clear_loop_signal(&mut self) {
+        self.loop_signal = None;
+    }
+
+    /// Asks the innermost enclosing loop to stop. The rest of the current pass is skipped
+    /// and no further pass starts. Returns false if this node is not inside a loop body.
+    pub fn break_loop(&self) -> bool {
+        match &self.loop_signal {
+            Some(signal) => {
+                signal.broken.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Skips the rest of the current pass of the innermost enclosing loop, which then
+    /// continues with its next pass. Returns false if this node is not inside a loop body.
+    pub fn continue_loop(&self) -> bool {
+        match &self.loop_signal {
+            Some(signal) => {
+                signal.skipped.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether a break or continue ended the current pass of the enclosing loop.
+    pub fn loop_pass_ended(&self) -> bool {
+        self.loop_signal
+            .as_ref()
+            .is_some_and(|signal| signal.pass_ended())
+    }
+
+    /// Whether the execution pin fired, see [`is_exec_active`] for the exact semantics.
+    pub async fn is_exec_active(&self, name: &str) -> flow_like_types::Result<bool> {
+        let pin = self.get_pin_by_name(name).await?;
+        Ok(is_exec_active(pin).await)
+    }
+
+    pub async fn is_exec_active_ref(&self, pin: &Arc<Mutex<InternalPin>>) -> bool {
+        is_exec_active(pin.clone()).await
     }
 
     pub async fn get_pins_by_name(
@@ -501,11 +807,59 @@ impl ExecutionContext {
 
     pub fn take_traces(&mut self) -> Vec<Trace> {
         let mut traces = self.sub_traces.clone();
-        traces.push(self.trace.clone());
+        let mut trace = self.trace.clone();
+        if self.capture_pin_values {
+            trace.pin_values = Some(self.pin_snapshot());
+        }
+        traces.push(trace);
         traces.sort_by(|a, b| a.start.cmp(&b.start));
         traces
     }
 
+    /// Mutable state of the current node, starting as `Null`.
+    ///
+    /// The state is keyed by node id and lives in the run cache, so it is shared by every
+    /// trigger of the node within one run (e.g. loop iterations) and starts fresh for each new run.
+    pub async fn run_state(&self) -> flow_like_types::Result<Arc<Mutex<Value>>> {
+        let key = format!("run_state_{}", self.id);
+        let entry = self
+            .cache
+            .write()
+            .await
+            .entry(key)
+            .or_insert_with(|| {
+                Arc::new(RunScopedState {
+                    value: Arc::new(Mutex::new(Value::Null)),
+                })
+            })
+            .clone();
+        let state = entry
+            .as_any()
+            .downcast_ref::<RunScopedState>()
+            .ok_or_else(|| flow_like_types::anyhow!("Could not downcast run state"))?;
+        Ok(state.value.clone())
+    }
+
+    /// Structure hash of the board this run executes, see [`crate::flow::board::Board::structure_hash`].
+    pub async fn structure_hash(&self) -> flow_like_types::Result<u64> {
+        let run = self.try_get_run()?;
+        let hash = run.lock().await.structure_hash;
+        Ok(hash)
+    }
+
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Cancellation token of the current run, `None` if the run was not registered in the app state.
+    pub async fn cancellation_token(&self) -> Option<CancellationToken> {
+        let state = self.app_state.lock().await;
+        state
+            .get_run(&self.run_id)
+            .ok()
+            .map(|run| run.cancellation_token.clone())
+    }
+
     pub fn try_get_run(&self) -> flow_like_types::Result<Arc<Mutex<Run>>> {
         if let Some(run) = self.run.upgrade() {
             return Ok(run);
@@ -550,3 +904,82 @@ impl ExecutionContext {
         Ok(())
     }
 }
+
+#[cfg(any(test, feature = "test-harness"))]
+impl ExecutionContext {
+    /// Builds a context for running a single node in isolation, without a board or run.
+    /// Input pins named in `inputs` are preset to the given values, output pins can be read
+    /// back with [`ExecutionContext::evaluate_pin`] after `logic.run(&mut context)`.
+    pub async fn test_harness(
+        logic: Arc<dyn crate::flow::node::NodeLogic>,
+        inputs: std::collections::HashMap<String, Value>,
+    ) -> flow_like_types::Result<Self> {
+        let (http_client, _refetch_rx) = crate::utils::http::HTTPClient::new();
+        let state = FlowLikeState::new(crate::state::FlowLikeConfig::new(), http_client);
+        let node = logic.get_node(&state).await;
+        let state = Arc::new(Mutex::new(state));
+
+        for name in inputs.keys() {
+            let is_input = node
+                .pins
+                .values()
+                .any(|pin| pin.name == *name && pin.pin_type == PinType::Input);
+            if !is_input {
+                return Err(flow_like_types::anyhow!(
+                    "Node '{}' has no input pin named '{}'",
+                    node.name,
+                    name
+                ));
+            }
+        }
+
+        let mut pins = AHashMap::with_capacity(node.pins.len());
+        let mut name_cache: AHashMap<String, Vec<Arc<Mutex<InternalPin>>>> = AHashMap::new();
+        for pin in node.pins.values() {
+            let mut pin = pin.clone();
+            if pin.pin_type == PinType::Input
+                && let Some(value) = inputs.get(&pin.name)
+            {
+                pin.value = Some(Arc::new(Mutex::new(value.clone())));
+            }
+
+            let internal_pin = Arc::new(Mutex::new(InternalPin {
+                pin: Arc::new(Mutex::new(pin.clone())),
+                node: None,
+                connected_to: vec![],
+                depends_on: vec![],
+                layer_pin: false,
+            }));
+            name_cache
+                .entry(pin.name.clone())
+                .or_default()
+                .push(internal_pin.clone());
+            pins.insert(pin.id.clone(), internal_pin);
+        }
+
+        let internal_node = Arc::new(InternalNode::new(node, pins, logic, name_cache));
+        for pin in internal_node.pins.values() {
+            pin.lock().await.node = Some(Arc::downgrade(&internal_node));
+        }
+
+        let node_id = internal_node.node.lock().await.id.clone();
+        let mut nodes = AHashMap::with_capacity(1);
+        nodes.insert(node_id, internal_node.clone());
+
+        Ok(ExecutionContext::new(
+            Arc::new(nodes),
+            &Weak::new(),
+            &state,
+            &internal_node,
+            &Arc::new(Mutex::new(AHashMap::new())),
+            &Arc::new(RwLock::new(AHashMap::new())),
+            LogLevel::Info,
+            ExecutionStage::Dev,
+            Arc::new(Profile::default()),
+            None,
+            Arc::new(RwLock::new(vec![])),
+            None,
+        )
+        .await)
+    }
+}
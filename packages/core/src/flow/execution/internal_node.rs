@@ -1,18 +1,27 @@
 use crate::flow::{
     node::{Node, NodeLogic, NodeState},
     pin::PinType,
-    utils::evaluate_pin_value,
+    utils::is_exec_active,
     variable::VariableType,
 };
 use ahash::{AHashMap, AHashSet};
 use flow_like_types::{Value, json::json, sync::Mutex, utils::ptr_key};
-use std::sync::{Arc, Weak, atomic::AtomicU64};
+use std::sync::{
+    Arc, Weak,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
 
 use super::{LogLevel, context::ExecutionContext, internal_pin::InternalPin, log::LogMessage};
 
 #[derive(Debug)]
 pub enum InternalNodeError {
-    DependencyFailed(String),
+    /// A dependency of `node_id` failed, `failed_dependency_id` is the node that actually
+    /// failed and `cause` its error.
+    DependencyFailed {
+        node_id: String,
+        failed_dependency_id: String,
+        cause: String,
+    },
     ExecutionFailed(String),
     PinNotReady(String),
 }
@@ -23,6 +32,38 @@ pub struct ExecutionTarget {
     pub through_pins: Vec<Arc<Mutex<InternalPin>>>,
 }
 
+impl InternalNodeError {
+    fn dependency_failed(node_id: String, failure: DependencyFailure) -> Self {
+        InternalNodeError::DependencyFailed {
+            node_id,
+            failed_dependency_id: failure.failed_dependency_id,
+            cause: failure.cause,
+        }
+    }
+}
+
+/// The dependency that could not be resolved while preparing a node, with its error.
+#[derive(Debug, Clone)]
+pub struct DependencyFailure {
+    pub failed_dependency_id: String,
+    pub cause: String,
+}
+
+impl DependencyFailure {
+    fn new(failed_dependency_id: impl Into<String>, cause: impl Into<String>) -> Self {
+        DependencyFailure {
+            failed_dependency_id: failed_dependency_id.into(),
+            cause: cause.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for DependencyFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.failed_dependency_id, self.cause)
+    }
+}
+
 impl ExecutionTarget {
     async fn into_sub_context(&self, ctx: &mut ExecutionContext) -> ExecutionContext {
         let mut sub = ctx.create_sub_context(&self.node).await;
@@ -39,7 +80,7 @@ async fn exec_deps_from_map(
     ctx: &mut ExecutionContext,
     recursion_guard: &mut Option<AHashSet<String>>,
     dependencies: &AHashMap<String, Vec<Arc<InternalNode>>>,
-) -> bool {
+) -> Result<(), DependencyFailure> {
     #[derive(Clone, Copy, PartialEq, Eq)]
     enum Phase {
         Enter,
@@ -68,19 +109,22 @@ async fn exec_deps_from_map(
                 if scheduled.contains(&n_ptr) {
                     continue;
                 }
+                let dep_id = {
+                    let g = n.node.lock().await;
+                    g.id.clone()
+                };
                 if !visiting.insert(n_ptr) {
                     ctx.log_message(
                         "Cycle detected while resolving mapped dependencies",
                         LogLevel::Error,
                     );
-                    return false;
+                    return Err(DependencyFailure::new(
+                        dep_id,
+                        "Cycle detected while resolving mapped dependencies",
+                    ));
                 }
                 stack.push((n.clone(), Phase::Exit));
 
-                let dep_id = {
-                    let g = n.node.lock().await;
-                    g.id.clone()
-                };
                 if let Some(children) = dependencies.get(&dep_id) {
                     for c in children.iter() {
                         let c_ptr = ptr_key(c);
@@ -120,18 +164,19 @@ async fn exec_deps_from_map(
                     LogLevel::Debug,
                     None,
                 );
+                log_message.put_fields(json!({ "node_id": dep_id, "node_name": dep_name }));
 
                 // Reuse your non-recursive single-node runner
-                let res = run_node_logic_only(&mut sub, recursion_guard).await;
+                let res = run_node_logic(&mut sub, recursion_guard).await;
 
                 log_message.end();
                 ctx.log(log_message);
                 sub.end_trace();
                 ctx.push_sub_context(&mut sub);
 
-                if res.is_err() {
+                if let Err(cause) = res {
                     ctx.log_message("Failed to trigger mapped dependency", LogLevel::Error);
-                    return false;
+                    return Err(DependencyFailure::new(dep_id, cause));
                 }
 
                 scheduled.insert(n_ptr);
@@ -139,13 +184,23 @@ async fn exec_deps_from_map(
         }
     }
 
-    true
+    Ok(())
 }
 
 async fn run_node_logic_only(
     ctx: &mut ExecutionContext,
     recursion_guard: &mut Option<AHashSet<String>>,
 ) -> flow_like_types::Result<(), InternalNodeError> {
+    run_node_logic(ctx, recursion_guard)
+        .await
+        .map_err(|_| InternalNodeError::ExecutionFailed(ctx.id.clone()))
+}
+
+/// Runs the logic of a single node, on failure returns the error message.
+async fn run_node_logic(
+    ctx: &mut ExecutionContext,
+    recursion_guard: &mut Option<AHashSet<String>>,
+) -> Result<(), String> {
     ctx.set_state(NodeState::Running).await;
     let node = ctx.read_node().await;
 
@@ -164,12 +219,20 @@ async fn run_node_logic_only(
         guard.insert(node.id.clone());
     }
 
+    if let Err(e) = ctx.count_node_execution() {
+        ctx.log_message(&e.to_string(), LogLevel::Fatal);
+        ctx.end_trace();
+        ctx.set_state(NodeState::Error).await;
+        return Err(e.to_string());
+    }
+
     let logic = ctx.node.logic.clone();
     let mut log_message = LogMessage::new(
         &format!("Starting Node Execution: {} [{}]", &node.name, &node.id),
         LogLevel::Debug,
         None,
     );
+    log_message.put_fields(json!({ "node_id": node.id, "node_name": node.name }));
 
     let result = logic.run(ctx).await;
 
@@ -184,7 +247,7 @@ async fn run_node_logic_only(
         ctx.end_trace();
         ctx.set_state(NodeState::Error).await;
         // NO handle_error() HERE — just bubble up
-        return Err(InternalNodeError::ExecutionFailed(node.id));
+        return Err(err_string);
     }
 
     ctx.set_state(NodeState::Success).await;
@@ -272,6 +335,9 @@ pub struct InternalNode {
     pub pins: AHashMap<String, Arc<Mutex<InternalPin>>>,
     pub logic: Arc<dyn NodeLogic>,
     pub exec_calls: AtomicU64,
+    /// Immutable name -> pins index built once at construction, read without locking.
+    pin_name_index: AHashMap<String, Vec<Arc<Mutex<InternalPin>>>>,
+    /// Fallback for names missing from the index, filled lazily by [`InternalNode::ensure_cache`].
     pin_name_cache: Mutex<AHashMap<String, Vec<Arc<Mutex<InternalPin>>>>>,
 }
 
@@ -286,7 +352,8 @@ impl InternalNode {
             node: Arc::new(Mutex::new(node)),
             pins,
             logic,
-            pin_name_cache: Mutex::new(name_cache),
+            pin_name_index: name_cache,
+            pin_name_cache: Mutex::new(AHashMap::new()),
             exec_calls: AtomicU64::new(0),
         }
     }
@@ -319,10 +386,22 @@ impl InternalNode {
         }
     }
 
+    /// Lock-free lookup in the precomputed index, `None` if the name was not known at construction.
+    #[inline]
+    pub fn try_get_pin_by_name(&self, name: &str) -> Option<Arc<Mutex<InternalPin>>> {
+        self.pin_name_index
+            .get(name)
+            .and_then(|pins| pins.first().cloned())
+    }
+
     pub async fn get_pin_by_name(
         &self,
         name: &str,
     ) -> flow_like_types::Result<Arc<Mutex<InternalPin>>> {
+        if let Some(pin) = self.try_get_pin_by_name(name) {
+            return Ok(pin);
+        }
+
         self.ensure_cache(name).await;
 
         let pin = {
@@ -340,6 +419,10 @@ impl InternalNode {
         &self,
         name: &str,
     ) -> flow_like_types::Result<Vec<Arc<Mutex<InternalPin>>>> {
+        if let Some(pins) = self.pin_name_index.get(name) {
+            return Ok(pins.clone());
+        }
+
         self.ensure_cache(name).await;
         let cache = self.pin_name_cache.lock().await;
         if let Some(pins_ref) = cache.get(name) {
@@ -366,7 +449,10 @@ impl InternalNode {
                 continue;
             }
 
-            if pin.depends_on.is_empty() && pin.default_value.is_none() {
+            if pin.depends_on.is_empty()
+                && pin.default_value.is_none()
+                && pin.default_expr.is_none()
+            {
                 return true;
             }
         }
@@ -383,7 +469,10 @@ impl InternalNode {
                 continue;
             }
 
-            if pin.depends_on.is_empty() && pin.default_value.is_none() {
+            if pin.depends_on.is_empty()
+                && pin.default_value.is_none()
+                && pin.default_expr.is_none()
+            {
                 return Ok(false);
             }
 
@@ -495,11 +584,8 @@ impl InternalNode {
         let mut stack: Vec<Weak<Mutex<InternalPin>>> = Vec::with_capacity(64);
 
         for pin in self.pins.values() {
-            if filter_valid {
-                match evaluate_pin_value(pin.clone()).await {
-                    Ok(Value::Bool(true)) => {}
-                    _ => continue,
-                }
+            if filter_valid && !is_exec_active(pin.clone()).await {
+                continue;
             }
 
             let pin_g = pin.lock().await;
@@ -566,12 +652,7 @@ impl InternalNode {
 
     pub async fn get_error_handled_nodes(&self) -> flow_like_types::Result<Vec<Arc<InternalNode>>> {
         let pin = self.get_pin_by_name("auto_handle_error").await?;
-        let active = evaluate_pin_value(pin.clone()).await?;
-        let active = match active {
-            Value::Bool(b) => b,
-            _ => false,
-        };
-        if !active {
+        if !is_exec_active(pin.clone()).await {
             return Err(flow_like_types::anyhow!("Error Pin not active"));
         }
 
@@ -697,7 +778,7 @@ impl InternalNode {
         context: &mut ExecutionContext,
         recursion_guard: &mut Option<AHashSet<String>>,
         _with_successors: bool, // not used here
-    ) -> bool {
+    ) -> Result<(), DependencyFailure> {
         #[derive(Clone, Copy, PartialEq, Eq)]
         enum Phase {
             Enter,
@@ -709,9 +790,12 @@ impl InternalNode {
         // Seed: pure parents of the current node. Dedup by pointer.
         let mut roots = match pure_parents_for_memo(&context.node, &mut parents_memo).await {
             Ok(v) => v,
-            Err(_) => {
+            Err(e) => {
                 context.log_message("Failed to collect dependencies", LogLevel::Error);
-                return false;
+                return Err(DependencyFailure::new(
+                    context.id.clone(),
+                    format!("Failed to collect dependencies: {:?}", e),
+                ));
             }
         };
         if roots.len() > 1 {
@@ -741,7 +825,11 @@ impl InternalNode {
                             "Cycle detected while resolving dependencies",
                             LogLevel::Error,
                         );
-                        return false;
+                        let node_id = node_arc.node.lock().await.id.clone();
+                        return Err(DependencyFailure::new(
+                            node_id,
+                            "Cycle detected while resolving dependencies",
+                        ));
                     }
 
                     // Post-order: revisit on Exit
@@ -764,7 +852,11 @@ impl InternalNode {
                                 &format!("Failed to collect parents: {:?}", e),
                                 LogLevel::Error,
                             );
-                            return false;
+                            let node_id = node_arc.node.lock().await.id.clone();
+                            return Err(DependencyFailure::new(
+                                node_id,
+                                format!("Failed to collect parents: {:?}", e),
+                            ));
                         }
                     }
                 }
@@ -798,18 +890,19 @@ impl InternalNode {
                         LogLevel::Debug,
                         None,
                     );
-                    let res = run_node_logic_only(&mut sub, recursion_guard).await;
+                    log_message.put_fields(json!({ "node_id": node_id, "node_name": node_name }));
+                    let res = run_node_logic(&mut sub, recursion_guard).await;
                     log_message.end();
                     context.log(log_message);
                     sub.end_trace();
                     context.push_sub_context(&mut sub);
 
-                    if res.is_err() {
+                    if let Err(cause) = res {
                         context.log_message(
                             &format!("Failed to trigger dependency: {}", &node_name),
                             LogLevel::Error,
                         );
-                        return false;
+                        return Err(DependencyFailure::new(node_id, cause));
                     }
 
                     scheduled.insert(node_ptr);
@@ -817,14 +910,17 @@ impl InternalNode {
             }
         }
 
-        true
+        Ok(())
     }
 
+    /// Runs the chain connected to the `auto_handle_error` pin of the failed node.
+    /// Returns true if a node in the chain called [`ExecutionContext::recover_error`],
+    /// in which case the caller should resume with the successors of the failed node.
     pub async fn handle_error(
         context: &mut ExecutionContext,
         error: &str,
         recursion_guard: &mut Option<AHashSet<String>>,
-    ) -> Result<(), InternalNodeError> {
+    ) -> Result<bool, InternalNodeError> {
         let _ = context.activate_exec_pin("auto_handle_error").await;
         let _ = context
             .set_pin_value("auto_handle_error_string", json!(error))
@@ -850,14 +946,21 @@ impl InternalNode {
             return Err(InternalNodeError::ExecutionFailed(context.id.clone()));
         }
 
+        let recovery = Arc::new(AtomicBool::new(false));
+
         // Iterate each error handler and walk its successors iteratively (DFS).
         for handler in connected {
             let mut sub = context.create_sub_context(&handler).await;
+            sub.set_error_recovery(recovery.clone());
 
             // Use SAME recursion_guard here (parity with original)
-            if !InternalNode::trigger_missing_dependencies(&mut sub, recursion_guard, false).await {
-                let err_string =
-                    "Failed to trigger missing dependencies for error handler".to_string();
+            if let Err(failure) =
+                InternalNode::trigger_missing_dependencies(&mut sub, recursion_guard, false).await
+            {
+                let err_string = format!(
+                    "Failed to trigger missing dependencies for error handler: {}",
+                    failure
+                );
                 let _ = sub
                     .set_pin_value("auto_handle_error_string", json!(err_string))
                     .await;
@@ -901,12 +1004,16 @@ impl InternalNode {
                 }
 
                 let mut sub2 = next.into_sub_context(context).await;
+                sub2.set_error_recovery(recovery.clone());
 
-                if !InternalNode::trigger_missing_dependencies(&mut sub2, recursion_guard, false)
-                    .await
+                if let Err(failure) =
+                    InternalNode::trigger_missing_dependencies(&mut sub2, recursion_guard, false)
+                        .await
                 {
-                    let err_string =
-                        "Failed to trigger successor dependencies (error chain)".to_string();
+                    let err_string = format!(
+                        "Failed to trigger successor dependencies (error chain): {}",
+                        failure
+                    );
                     let _ = sub2
                         .set_pin_value("auto_handle_error_string", json!(err_string))
                         .await;
@@ -965,8 +1072,101 @@ impl InternalNode {
             context.push_sub_context(&mut sub);
         }
 
-        context.set_state(NodeState::Error).await;
-        Ok(())
+        if !recovery.load(Ordering::SeqCst) {
+            context.set_state(NodeState::Error).await;
+            return Ok(false);
+        }
+
+        context.log_message(
+            &format!("Error recovered by handler chain of: {}", &context.id),
+            LogLevel::Info,
+        );
+        context.set_state(NodeState::Success).await;
+        InternalNode::resume_after_recovery(context).await;
+        Ok(true)
+    }
+
+    /// Like [`Self::handle_error`], but a failing handler chain is logged on the failed node
+    /// and counts as not recovered, as does a node without handlers.
+    async fn try_recover(
+        context: &mut ExecutionContext,
+        error: &str,
+        recursion_guard: &mut Option<AHashSet<String>>,
+    ) -> bool {
+        let has_handlers = context
+            .node
+            .get_error_handled_nodes()
+            .await
+            .is_ok_and(|handlers| !handlers.is_empty());
+
+        match InternalNode::handle_error(context, error, recursion_guard).await {
+            Ok(recovered) => recovered,
+            Err(handler_error) => {
+                if has_handlers {
+                    context.log_message(
+                        &format!("Error handling failed: {:?}", handler_error),
+                        LogLevel::Error,
+                    );
+                }
+                false
+            }
+        }
+    }
+
+    /// Re-routes a recovered node to its regular successors. Exec outputs the node
+    /// activated before failing are kept, otherwise `exec_out` is activated if present.
+    async fn resume_after_recovery(context: &mut ExecutionContext) {
+        let _ = context.deactivate_exec_pin("auto_handle_error").await;
+
+        for pin in context.node.pins.values() {
+            let is_exec_output = {
+                let pin = pin.lock().await;
+                let pin = pin.pin.lock().await;
+                pin.pin_type == PinType::Output && pin.data_type == VariableType::Execution
+            };
+            if is_exec_output && is_exec_active(pin.clone()).await {
+                return;
+            }
+        }
+
+        let _ = context.activate_exec_pin("exec_out").await;
+    }
+
+    /// Runs only this node's logic, never its successors and without error handling.
+    /// With `with_dependencies` the pure nodes feeding its inputs are evaluated first.
+    /// Returns the values of the output pins afterwards, keyed by pin name.
+    pub async fn run_isolated(
+        context: &mut ExecutionContext,
+        recursion_guard: &mut Option<AHashSet<String>>,
+        with_dependencies: bool,
+    ) -> flow_like_types::Result<AHashMap<String, Value>, InternalNodeError> {
+        if with_dependencies
+            && let Err(failure) =
+                InternalNode::trigger_missing_dependencies(context, recursion_guard, false).await
+        {
+            context.log_message("Failed to trigger missing dependencies", LogLevel::Error);
+            let node = context.read_node().await;
+            return Err(InternalNodeError::dependency_failed(node.id, failure));
+        }
+
+        run_node_logic_only(context, recursion_guard).await?;
+
+        let mut outputs = AHashMap::with_capacity(context.node.pins.len());
+        for pin in context.node.pins.values() {
+            let pin = pin.lock().await;
+            let pin = pin.pin.lock().await;
+            if pin.pin_type != PinType::Output {
+                continue;
+            }
+
+            let value = match &pin.value {
+                Some(value) => value.lock().await.clone(),
+                None => Value::Null,
+            };
+            outputs.insert(pin.name.clone(), value);
+        }
+
+        Ok(outputs)
     }
 
     pub async fn trigger(
@@ -975,25 +1175,24 @@ impl InternalNode {
         with_successors: bool,
     ) -> flow_like_types::Result<(), InternalNodeError> {
         // deps
-        if !InternalNode::trigger_missing_dependencies(context, recursion_guard, false).await {
-            context.log_message("Failed to trigger missing dependencies", LogLevel::Error);
+        if let Err(failure) =
+            InternalNode::trigger_missing_dependencies(context, recursion_guard, false).await
+        {
+            let err_string = format!("Failed to trigger missing dependencies: {}", failure);
+            context.log_message(&err_string, LogLevel::Error);
             context.end_trace();
-            InternalNode::handle_error(
-                context,
-                "Failed to trigger missing dependencies",
-                recursion_guard,
-            )
-            .await?;
-            let node = context.read_node().await;
-            return Err(InternalNodeError::DependencyFailed(node.id));
-        }
-
-        // this node
-        if let Err(e) = run_node_logic_only(context, recursion_guard).await {
+            // the dependency failure is reported even if the handler chain itself fails
+            if !InternalNode::try_recover(context, &err_string, recursion_guard).await {
+                let node = context.read_node().await;
+                return Err(InternalNodeError::dependency_failed(node.id, failure));
+            }
+        } else if let Err(e) = run_node_logic_only(context, recursion_guard).await {
+            // this node
             let err_string = format!("{:?}", e);
-            InternalNode::handle_error(context, &err_string, recursion_guard).await?;
-            let node = context.read_node().await;
-            return Err(InternalNodeError::ExecutionFailed(node.id));
+            if !InternalNode::handle_error(context, &err_string, recursion_guard).await? {
+                let node = context.read_node().await;
+                return Err(InternalNodeError::ExecutionFailed(node.id));
+            }
         }
 
         // successors (DFS; fresh guard per successor to mirror old semantics)
@@ -1006,7 +1205,6 @@ impl InternalNode {
                         &format!("Failed to get successors: {}", err_string),
                         LogLevel::Error,
                     );
-                    InternalNode::handle_error(context, &err_string, recursion_guard).await?;
                     let node = context.read_node().await;
                     return Err(InternalNodeError::ExecutionFailed(node.id));
                 }
@@ -1019,6 +1217,11 @@ impl InternalNode {
                 ahash::AHashSet::with_capacity(stack.len().saturating_mul(2));
 
             while let Some(next) = stack.pop() {
+                // a break or continue inside a loop body ends the pass
+                if context.loop_pass_ended() {
+                    break;
+                }
+
                 let key = Arc::as_ptr(&next.node) as usize;
                 if !seen_exec_ptrs.insert(key) {
                     continue;
@@ -1027,27 +1230,31 @@ impl InternalNode {
                 let mut sub = next.into_sub_context(context).await;
                 let mut local_guard: Option<AHashSet<String>> = None;
 
-                if !InternalNode::trigger_missing_dependencies(&mut sub, &mut local_guard, false)
-                    .await
+                if let Err(failure) =
+                    InternalNode::trigger_missing_dependencies(&mut sub, &mut local_guard, false)
+                        .await
                 {
-                    let err_string = "Failed to trigger successor dependencies".to_string();
-                    InternalNode::handle_error(&mut sub, &err_string, &mut local_guard).await?;
-                    sub.end_trace();
-                    context.push_sub_context(&mut sub);
-                    let node = context.read_node().await;
-                    return Err(InternalNodeError::ExecutionFailed(node.id));
-                }
-
-                if let Err(e) = run_node_logic_only(&mut sub, &mut local_guard).await {
+                    let err_string =
+                        format!("Failed to trigger successor dependencies: {}", failure);
+                    sub.log_message(&err_string, LogLevel::Error);
+                    let recovered =
+                        InternalNode::try_recover(&mut sub, &err_string, &mut local_guard).await;
+                    if !recovered {
+                        sub.end_trace();
+                        context.push_sub_context(&mut sub);
+                        let node = context.read_node().await;
+                        return Err(InternalNodeError::ExecutionFailed(node.id));
+                    }
+                } else if let Err(e) = run_node_logic_only(&mut sub, &mut local_guard).await {
                     let err_string = format!("{:?}", e);
-                    let _ = sub.activate_exec_pin("auto_handle_error").await;
-                    let _ = sub
-                        .set_pin_value("auto_handle_error_string", json!(err_string))
-                        .await;
-                    sub.end_trace();
-                    context.push_sub_context(&mut sub);
-                    let node = context.read_node().await;
-                    return Err(InternalNodeError::ExecutionFailed(node.id));
+                    let recovered =
+                        InternalNode::try_recover(&mut sub, &err_string, &mut local_guard).await;
+                    if !recovered {
+                        sub.end_trace();
+                        context.push_sub_context(&mut sub);
+                        let node = context.read_node().await;
+                        return Err(InternalNodeError::ExecutionFailed(node.id));
+                    }
                 }
 
                 match next.node.get_connected_exec(true).await {
@@ -1057,8 +1264,10 @@ impl InternalNode {
                         }
                     }
                     Err(err) => {
-                        let err_string = format!("{:?}", err);
-                        InternalNode::handle_error(&mut sub, &err_string, &mut local_guard).await?;
+                        sub.log_message(
+                            &format!("Failed to get successors: {:?}", err),
+                            LogLevel::Error,
+                        );
                         sub.end_trace();
                         context.push_sub_context(&mut sub);
                         let node = context.read_node().await;
@@ -1100,39 +1309,61 @@ impl InternalNode {
         }
 
         // 1) Execute precomputed dependencies iteratively (no recursion)
-        if !exec_deps_from_map(context, recursion_guard, dependencies).await {
-            let err = "Failed to trigger mapped dependencies".to_string();
-            InternalNode::handle_error(context, &err, recursion_guard).await?;
-            return Err(InternalNodeError::DependencyFailed(node.id.clone()));
-        }
+        let dependencies_ready =
+            match exec_deps_from_map(context, recursion_guard, dependencies).await {
+                Ok(()) => true,
+                Err(failure) => {
+                    let err = format!("Failed to trigger mapped dependencies: {}", failure);
+                    context.log_message(&err, LogLevel::Error);
+                    context.end_trace();
+                    if !InternalNode::try_recover(context, &err, recursion_guard).await {
+                        return Err(InternalNodeError::dependency_failed(
+                            node.id.clone(),
+                            failure,
+                        ));
+                    }
+                    false
+                }
+            };
 
-        // 2) Run this node (no successors here)
-        let logic = context.node.logic.clone();
-        let mut log_message = LogMessage::new(
-            &format!("Starting Node Execution: {} [{}]", &node.name, &node.id),
-            LogLevel::Debug,
-            None,
-        );
-        let result = logic.run(context).await;
+        // 2) Run this node (no successors here), skipped if its failed dependencies were recovered
+        if dependencies_ready {
+            if let Err(e) = context.count_node_execution() {
+                context.log_message(&e.to_string(), LogLevel::Fatal);
+                context.end_trace();
+                context.set_state(NodeState::Error).await;
+                return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
+            }
 
-        if let Err(e) = result {
-            let err_string = format!("{:?}", e);
-            context.log_message(
-                &format!("Failed to execute node: {}", err_string),
-                LogLevel::Error,
+            let logic = context.node.logic.clone();
+            let mut log_message = LogMessage::new(
+                &format!("Starting Node Execution: {} [{}]", &node.name, &node.id),
+                LogLevel::Debug,
+                None,
             );
-            log_message.end();
-            context.log(log_message);
-            context.end_trace();
-            context.set_state(NodeState::Error).await;
-            InternalNode::handle_error(context, &err_string, recursion_guard).await?;
-            return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
-        }
+            log_message.put_fields(json!({ "node_id": node.id, "node_name": node.name }));
+            let result = logic.run(context).await;
 
-        context.set_state(NodeState::Success).await;
-        log_message.end();
-        context.log(log_message);
-        context.end_trace();
+            if let Err(e) = result {
+                let err_string = format!("{:?}", e);
+                context.log_message(
+                    &format!("Failed to execute node: {}", err_string),
+                    LogLevel::Error,
+                );
+                log_message.end();
+                context.log(log_message);
+                context.end_trace();
+                context.set_state(NodeState::Error).await;
+                if !InternalNode::handle_error(context, &err_string, recursion_guard).await? {
+                    return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
+                }
+            } else {
+                context.set_state(NodeState::Success).await;
+                log_message.end();
+                context.log(log_message);
+                context.end_trace();
+            }
+        }
 
         // 3) Walk successors iteratively (DFS), like your non-recursive `trigger`
         if with_successors {
@@ -1141,10 +1372,9 @@ impl InternalNode {
                 Err(err) => {
                     let err_string = format!("{:?}", err);
                     context.log_message(
-                        &format!("Failed to get successors: {}", err_string.clone()),
+                        &format!("Failed to get successors: {}", err_string),
                         LogLevel::Error,
                     );
-                    InternalNode::handle_error(context, &err_string, recursion_guard).await?;
                     return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
                 }
             };
@@ -1156,6 +1386,11 @@ impl InternalNode {
                 ahash::AHashSet::with_capacity(stack.len().saturating_mul(2));
 
             while let Some(next) = stack.pop() {
+                // a break or continue inside a loop body ends the pass
+                if context.loop_pass_ended() {
+                    break;
+                }
+
                 let key = Arc::as_ptr(&next.node) as usize;
                 if !seen_exec_ptrs.insert(key) {
                     continue;
@@ -1167,24 +1402,31 @@ impl InternalNode {
                 let mut local_guard: Option<AHashSet<String>> = None;
 
                 // Execute *its* mapped deps (fresh executed set semantics like before)
-                if !exec_deps_from_map(&mut sub, &mut local_guard, dependencies).await {
-                    let err_string = "Failed to trigger successor mapped dependencies".to_string();
-                    InternalNode::handle_error(&mut sub, &err_string, &mut local_guard).await?;
-                    sub.end_trace();
-                    context.push_sub_context(&mut sub);
-                    return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
-                }
-
-                // Run successor node
-                if let Err(e) = run_node_logic_only(&mut sub, &mut local_guard).await {
+                if let Err(failure) =
+                    exec_deps_from_map(&mut sub, &mut local_guard, dependencies).await
+                {
+                    let err_string = format!(
+                        "Failed to trigger successor mapped dependencies: {}",
+                        failure
+                    );
+                    sub.log_message(&err_string, LogLevel::Error);
+                    let recovered =
+                        InternalNode::try_recover(&mut sub, &err_string, &mut local_guard).await;
+                    if !recovered {
+                        sub.end_trace();
+                        context.push_sub_context(&mut sub);
+                        return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
+                    }
+                } else if let Err(e) = run_node_logic_only(&mut sub, &mut local_guard).await {
+                    // Run successor node
                     let err_string = format!("{:?}", e);
-                    let _ = sub.activate_exec_pin("auto_handle_error").await;
-                    let _ = sub
-                        .set_pin_value("auto_handle_error_string", json!(err_string))
-                        .await;
-                    sub.end_trace();
-                    context.push_sub_context(&mut sub);
-                    return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
+                    let recovered =
+                        InternalNode::try_recover(&mut sub, &err_string, &mut local_guard).await;
+                    if !recovered {
+                        sub.end_trace();
+                        context.push_sub_context(&mut sub);
+                        return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
+                    }
                 }
 
                 // Enqueue its successors (DFS)
@@ -1195,8 +1437,10 @@ impl InternalNode {
                         }
                     }
                     Err(err) => {
-                        let err_string = format!("{:?}", err);
-                        InternalNode::handle_error(&mut sub, &err_string, &mut local_guard).await?;
+                        sub.log_message(
+                            &format!("Failed to get successors: {:?}", err),
+                            LogLevel::Error,
+                        );
                         sub.end_trace();
                         context.push_sub_context(&mut sub);
                         return Err(InternalNodeError::ExecutionFailed(node.id.clone()));
@@ -1211,3 +1455,300 @@ impl InternalNode {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow::execution::test_board::TestBoard;
+    use crate::{
+        flow::node::{Node, NodeLogic},
+        state::FlowLikeState,
+    };
+    use flow_like_types::{async_trait, bail, tokio};
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicUsize;
+
+    struct DoubleNode;
+
+    #[async_trait]
+    impl NodeLogic for DoubleNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_double", "Double", "", "Test");
+            node.add_input_pin("value", "Value", "", VariableType::Integer);
+            node.add_output_pin("doubled", "Doubled", "", VariableType::Integer);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            let value: i64 = context.evaluate_pin("value").await?;
+            if value < 0 {
+                bail!("negative value");
+            }
+            context.set_pin_value("doubled", json!(value * 2)).await
+        }
+    }
+
+    async fn double_context(value: i64) -> ExecutionContext {
+        let inputs = HashMap::from([("value".to_string(), json!(value))]);
+        ExecutionContext::test_harness(Arc::new(DoubleNode), inputs)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_isolated_returns_outputs() {
+        let mut context = double_context(21).await;
+        let outputs = InternalNode::run_isolated(&mut context, &mut None, true)
+            .await
+            .unwrap();
+
+        assert_eq!(outputs.get("doubled"), Some(&json!(42)));
+        assert!(!outputs.contains_key("value"));
+        assert_eq!(context.get_state(), NodeState::Success);
+    }
+
+    #[tokio::test]
+    async fn run_isolated_reports_failures() {
+        let mut context = double_context(-1).await;
+        let result = InternalNode::run_isolated(&mut context, &mut None, false).await;
+
+        match result {
+            Err(InternalNodeError::ExecutionFailed(node_id)) => assert_eq!(node_id, context.id),
+            other => panic!("expected ExecutionFailed, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(context.get_state(), NodeState::Error);
+    }
+
+    #[tokio::test]
+    async fn failing_pure_dependency_is_reported() {
+        let mut board = TestBoard::new(vec![Arc::new(DoubleNode)]).await;
+        let upstream = board.add(&DoubleNode).await;
+        let downstream = board.add(&DoubleNode).await;
+        board.set_input(&upstream, "value", json!(-1));
+        board.connect(&upstream, "doubled", &downstream, "value");
+
+        let run = board.prepare(&downstream).await;
+        let mut context = board.context(&run, &downstream).await;
+        let result = InternalNode::run_isolated(&mut context, &mut None, true).await;
+
+        match result {
+            Err(InternalNodeError::DependencyFailed {
+                node_id,
+                failed_dependency_id,
+                cause,
+            }) => {
+                assert_eq!(node_id, downstream);
+                assert_eq!(failed_dependency_id, upstream);
+                assert!(cause.contains("negative value"), "{}", cause);
+            }
+            other => panic!("expected DependencyFailed, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    /// Exec node that always fails and exposes the error handling pins.
+    struct FailNode;
+
+    #[async_trait]
+    impl NodeLogic for FailNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_fail", "Fail", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_output_pin("exec_out", "Output", "", VariableType::Execution);
+            node.add_output_pin("auto_handle_error", "Error", "", VariableType::Execution);
+            node.add_output_pin(
+                "auto_handle_error_string",
+                "Error Message",
+                "",
+                VariableType::String,
+            );
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            context.deactivate_exec_pin("exec_out").await?;
+            bail!("always fails")
+        }
+    }
+
+    /// Exec node counting its runs, optionally recovering the handled error.
+    struct CountNode {
+        name: &'static str,
+        recover: bool,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl CountNode {
+        fn new(name: &'static str, recover: bool) -> Arc<Self> {
+            Arc::new(CountNode {
+                name,
+                recover,
+                runs: Arc::new(AtomicUsize::new(0)),
+            })
+        }
+
+        fn runs(&self) -> usize {
+            self.runs.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl NodeLogic for CountNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new(self.name, "Count", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_output_pin("exec_out", "Output", "", VariableType::Execution);
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            if self.recover {
+                context.recover_error();
+            }
+            context.activate_exec_pin("exec_out").await
+        }
+    }
+
+    /// Runs Fail -> Successor with Handler on the error pin, returns the last state
+    /// reported for the failed node.
+    async fn run_error_chain(handler: Arc<CountNode>, successor: Arc<CountNode>) -> NodeState {
+        let mut board =
+            TestBoard::new(vec![Arc::new(FailNode), handler.clone(), successor.clone()]).await;
+        let fail = board.add(&FailNode).await;
+        let handler_id = board.add(handler.as_ref()).await;
+        let successor_id = board.add(successor.as_ref()).await;
+        board.connect(&fail, "auto_handle_error", &handler_id, "exec_in");
+        board.connect(&fail, "exec_out", &successor_id, "exec_in");
+
+        let states = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = states.clone();
+        let mut run = board.prepare(&fail).await;
+        run.set_state_callback(Arc::new(move |node_id, _old, new, _span| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((node_id.to_string(), new.clone()));
+        }))
+        .await;
+        run.execute(board.state.clone()).await;
+
+        let states = states.lock().unwrap();
+        states
+            .iter()
+            .rev()
+            .find(|(node_id, _)| *node_id == fail)
+            .map(|(_, state)| state.clone())
+            .expect("state of the failed node")
+    }
+
+    #[tokio::test]
+    async fn recovered_error_continues_with_successors() {
+        let handler = CountNode::new("test_recover", true);
+        let successor = CountNode::new("test_successor", false);
+        let state = run_error_chain(handler.clone(), successor.clone()).await;
+
+        assert_eq!(handler.runs(), 1);
+        assert_eq!(successor.runs(), 1);
+        assert_eq!(state, NodeState::Success);
+    }
+
+    #[tokio::test]
+    async fn unrecovered_error_stops_the_chain() {
+        let handler = CountNode::new("test_handle", false);
+        let successor = CountNode::new("test_successor", false);
+        let state = run_error_chain(handler.clone(), successor.clone()).await;
+
+        assert_eq!(handler.runs(), 1);
+        assert_eq!(successor.runs(), 0);
+        assert_eq!(state, NodeState::Error);
+    }
+
+    /// Exec node consuming a pure "value" input, counting its runs.
+    struct ConsumeNode {
+        runs: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl NodeLogic for ConsumeNode {
+        async fn get_node(&self, _app_state: &FlowLikeState) -> Node {
+            let mut node = Node::new("test_consume", "Consume", "", "Test");
+            node.add_input_pin("exec_in", "Input", "", VariableType::Execution);
+            node.add_input_pin("value", "Value", "", VariableType::Integer);
+            node.add_output_pin("exec_out", "Output", "", VariableType::Execution);
+            node.add_output_pin("auto_handle_error", "Error", "", VariableType::Execution);
+            node.add_output_pin(
+                "auto_handle_error_string",
+                "Error Message",
+                "",
+                VariableType::String,
+            );
+            node
+        }
+
+        async fn run(&self, context: &mut ExecutionContext) -> flow_like_types::Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            context.evaluate_pin::<i64>("value").await?;
+            context.activate_exec_pin("exec_out").await
+        }
+    }
+
+    #[tokio::test]
+    async fn unhandled_dependency_failure_is_reported_by_trigger() {
+        let consumer = Arc::new(ConsumeNode {
+            runs: Arc::new(AtomicUsize::new(0)),
+        });
+        let mut board = TestBoard::new(vec![Arc::new(DoubleNode), consumer.clone()]).await;
+        let upstream = board.add(&DoubleNode).await;
+        let consumer_id = board.add(consumer.as_ref()).await;
+        board.set_input(&upstream, "value", json!(-1));
+        board.connect(&upstream, "doubled", &consumer_id, "value");
+
+        let run = board.prepare(&consumer_id).await;
+        let mut context = board.context(&run, &consumer_id).await;
+        let result = InternalNode::trigger(&mut context, &mut None, false).await;
+
+        match result {
+            Err(InternalNodeError::DependencyFailed {
+                node_id,
+                failed_dependency_id,
+                cause,
+            }) => {
+                assert_eq!(node_id, consumer_id);
+                assert_eq!(failed_dependency_id, upstream);
+                assert!(cause.contains("negative value"), "{}", cause);
+            }
+            other => panic!("expected DependencyFailed, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(consumer.runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn recovered_dependency_failure_continues_with_successors() {
+        let consumer = Arc::new(ConsumeNode {
+            runs: Arc::new(AtomicUsize::new(0)),
+        });
+        let handler = CountNode::new("test_recover", true);
+        let successor = CountNode::new("test_successor", false);
+        let mut board = TestBoard::new(vec![
+            Arc::new(DoubleNode),
+            consumer.clone(),
+            handler.clone(),
+            successor.clone(),
+        ])
+        .await;
+        let upstream = board.add(&DoubleNode).await;
+        let consumer_id = board.add(consumer.as_ref()).await;
+        let handler_id = board.add(handler.as_ref()).await;
+        let successor_id = board.add(successor.as_ref()).await;
+        board.set_input(&upstream, "value", json!(-1));
+        board.connect(&upstream, "doubled", &consumer_id, "value");
+        board.connect(&consumer_id, "auto_handle_error", &handler_id, "exec_in");
+        board.connect(&consumer_id, "exec_out", &successor_id, "exec_in");
+
+        board.run(&consumer_id).await;
+
+        assert_eq!(handler.runs(), 1);
+        assert_eq!(successor.runs(), 1);
+        assert_eq!(consumer.runs.load(Ordering::SeqCst), 0);
+    }
+}
@@ -136,6 +136,29 @@ impl InternalPin {
         pin.value = Some(value.clone());
     }
 
+    /// True if this pin or a pin directly feeding it is marked sensitive, e.g. a secret wired
+    /// into a generic input, so its value should be redacted wherever it is shown.
+    pub async fn is_sensitive(&self) -> bool {
+        let sensitive = |pin: &Pin| {
+            pin.options
+                .as_ref()
+                .and_then(|options| options.sensitive)
+                .unwrap_or(false)
+        };
+
+        if sensitive(&*self.pin.lock().await) {
+            return true;
+        }
+
+        for source in self.depends_on.iter().filter_map(Weak::upgrade) {
+            let source = source.lock().await;
+            if sensitive(&*source.pin.lock().await) {
+                return true;
+            }
+        }
+        false
+    }
+
     // Pins without a parent report as pure!
     pub async fn is_pure(&self) -> bool {
         if let Some(node) = &self.node {
@@ -6,12 +6,13 @@ use flow_like_storage::{
         schema::{SchemaLike, TracingOptions},
     },
 };
+use flow_like_types::Value;
 use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
-use super::LogLevel;
+use super::{LogLevel, trace::SpanContext};
 
 static STORED_LOG_MESSAGE_FIELDS: Lazy<Vec<FieldRef>> = Lazy::new(|| {
     Vec::<FieldRef>::from_type::<StoredLogMessage>(
@@ -55,6 +56,14 @@ pub struct LogMessage {
     pub node_id: Option<String>,
     pub log_level: LogLevel,
     pub stats: Option<LogStat>,
+    /// Structured context (e.g. node name, pin values, timings) a UI can show as fields
+    #[serde(default)]
+    pub fields: Option<Value>,
+    /// See [`SpanContext`], set for logs written through an [`ExecutionContext`](super::context::ExecutionContext)
+    #[serde(default)]
+    pub trace_id: Option<String>,
+    #[serde(default)]
+    pub span_id: Option<String>,
     pub start: SystemTime,
     pub end: SystemTime,
 }
@@ -68,6 +77,13 @@ pub struct StoredLogMessage {
     pub token_in: Option<u64>,
     pub token_out: Option<u64>,
     pub bit_ids: Option<Vec<String>>,
+    /// JSON encoded [`LogMessage::fields`], absent in logs written before fields existed
+    #[serde(default)]
+    pub fields: Option<String>,
+    #[serde(default)]
+    pub trace_id: Option<String>,
+    #[serde(default)]
+    pub span_id: Option<String>,
     pub start: u64,
     pub end: u64,
 }
@@ -78,6 +94,7 @@ impl From<LogMessage> for StoredLogMessage {
         let token_in = log.stats.as_ref().and_then(|s| s.token_in);
         let token_out = log.stats.as_ref().and_then(|s| s.token_out);
         let bit_ids = log.stats.and_then(|s| s.bit_ids);
+        let fields = log.fields.as_ref().map(Value::to_string);
 
         StoredLogMessage {
             message: log.message,
@@ -87,6 +104,9 @@ impl From<LogMessage> for StoredLogMessage {
             token_in,
             token_out,
             bit_ids,
+            fields,
+            trace_id: log.trace_id,
+            span_id: log.span_id,
             start: log
                 .start
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -107,6 +127,9 @@ impl From<StoredLogMessage> for LogMessage {
         let token_in = log.token_in;
         let token_out = log.token_out;
         let bit_ids = log.bit_ids;
+        let fields = log
+            .fields
+            .and_then(|fields| flow_like_types::json::from_str(&fields).ok());
 
         LogMessage {
             message: log.message,
@@ -114,6 +137,9 @@ impl From<StoredLogMessage> for LogMessage {
             node_id: log.node_id,
             log_level,
             stats: Some(LogStat::new(token_in, token_out, bit_ids)),
+            fields,
+            trace_id: log.trace_id,
+            span_id: log.span_id,
             start: SystemTime::UNIX_EPOCH + std::time::Duration::from_micros(log.start),
             end: SystemTime::UNIX_EPOCH + std::time::Duration::from_micros(log.end),
         }
@@ -129,6 +155,9 @@ impl LogMessage {
             node_id: None,
             operation_id,
             stats: None,
+            fields: None,
+            trace_id: None,
+            span_id: None,
             start: now,
             end: now,
         }
@@ -138,6 +167,15 @@ impl LogMessage {
         self.stats = Some(stats);
     }
 
+    pub fn put_fields(&mut self, fields: Value) {
+        self.fields = Some(fields);
+    }
+
+    pub fn put_span(&mut self, span: &SpanContext) {
+        self.trace_id = span.trace_id.clone();
+        self.span_id = Some(span.span_id.clone());
+    }
+
     pub fn end(&mut self) {
         self.end = SystemTime::now();
     }
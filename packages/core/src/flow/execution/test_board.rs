@@ -0,0 +1,160 @@
+//! Small boards assembled from node logics, for tests that need the full run loop.
+//! Single nodes are easier to test with [`ExecutionContext::test_harness`].
+//!
+//! [`ExecutionContext::test_harness`]: super::context::ExecutionContext::test_harness
+
+use super::{InternalRun, RunPayload, context::ExecutionContext};
+use crate::{
+    flow::{
+        board::{Board, ExecutionStage, commands::pins::connect_pins::connect_pins},
+        node::NodeLogic,
+    },
+    profile::Profile,
+    state::{FlowLikeConfig, FlowLikeState},
+    utils::http::HTTPClient,
+};
+use flow_like_storage::{Path, files::store::FlowLikeStore, object_store::memory::InMemory};
+use flow_like_types::{Value, sync::Mutex};
+use std::sync::Arc;
+
+pub struct TestBoard {
+    pub state: Arc<Mutex<FlowLikeState>>,
+    pub board: Board,
+}
+
+impl TestBoard {
+    /// Empty board, `logics` are registered so their nodes can be added and run.
+    pub async fn new(logics: Vec<Arc<dyn NodeLogic>>) -> Self {
+        let mut config = FlowLikeConfig::new();
+        config.register_app_meta_store(FlowLikeStore::Other(Arc::new(InMemory::new())));
+        let (http_client, _refetch_rx) = HTTPClient::new();
+        let state = Arc::new(Mutex::new(FlowLikeState::new(config, http_client)));
+
+        let registry = state.lock().await.node_registry.clone();
+        {
+            let mut registry = registry.write().await;
+            registry.initialize(Arc::downgrade(&state));
+            registry
+                .push_nodes(logics)
+                .await
+                .expect("Failed to register test nodes");
+        }
+
+        let board = Board::new(None, Path::from("boards"), state.clone());
+        TestBoard { state, board }
+    }
+
+    /// Adds a node of a registered logic, returns its id.
+    pub async fn add(&mut self, logic: &dyn NodeLogic) -> String {
+        let node = {
+            let state = self.state.lock().await;
+            logic.get_node(&state).await
+        };
+        let id = node.id.clone();
+        self.board.nodes.insert(id.clone(), node);
+        id
+    }
+
+    pub fn pin_id(&self, node_id: &str, pin_name: &str) -> String {
+        self.nth_pin_id(node_id, pin_name, 0)
+    }
+
+    /// Id of the `nth` pin named `pin_name` in index order, for nodes repeating a pin
+    /// name like the outputs of a sequence.
+    pub fn nth_pin_id(&self, node_id: &str, pin_name: &str, nth: usize) -> String {
+        let mut pins: Vec<_> = self
+            .board
+            .nodes
+            .get(node_id)
+            .map(|node| {
+                node.pins
+                    .values()
+                    .filter(|pin| pin.name == pin_name)
+                    .collect()
+            })
+            .unwrap_or_default();
+        pins.sort_by_key(|pin| pin.index);
+        pins.get(nth)
+            .map(|pin| pin.id.clone())
+            .unwrap_or_else(|| panic!("Node {} has no pin {} #{}", node_id, pin_name, nth))
+    }
+
+    /// Connects the output pin `from_pin` of `from` to the input pin `to_pin` of `to`.
+    pub fn connect(&mut self, from: &str, from_pin: &str, to: &str, to_pin: &str) {
+        self.connect_nth(from, from_pin, 0, to, to_pin);
+    }
+
+    /// Like [`Self::connect`], using the `nth` output pin named `from_pin`.
+    pub fn connect_nth(&mut self, from: &str, from_pin: &str, nth: usize, to: &str, to_pin: &str) {
+        let from_pin = self.nth_pin_id(from, from_pin, nth);
+        let to_pin = self.pin_id(to, to_pin);
+        connect_pins(&mut self.board, from, &from_pin, to, &to_pin)
+            .expect("Failed to connect pins");
+    }
+
+    /// Sets the default value of an unconnected input pin.
+    pub fn set_input(&mut self, node_id: &str, pin_name: &str, value: Value) {
+        let pin_id = self.pin_id(node_id, pin_name);
+        if let Some(pin) = self
+            .board
+            .nodes
+            .get_mut(node_id)
+            .and_then(|node| node.pins.get_mut(&pin_id))
+        {
+            pin.set_default_value(Some(value));
+        }
+    }
+
+    /// Run starting at `start`, not executed yet so it can still be configured.
+    pub async fn prepare(&self, start: &str) -> InternalRun {
+        let payload = RunPayload {
+            id: start.to_string(),
+            payload: None,
+        };
+        InternalRun::new(
+            "test",
+            Arc::new(self.board.clone()),
+            None,
+            &self.state,
+            &Profile::default(),
+            &payload,
+            None,
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to prepare run")
+    }
+
+    /// Context for running `node_id` of a prepared run by hand, e.g. through
+    /// [`InternalNode::run_isolated`](super::internal_node::InternalNode::run_isolated).
+    pub async fn context(&self, run: &InternalRun, node_id: &str) -> ExecutionContext {
+        let node = run
+            .nodes
+            .get(node_id)
+            .unwrap_or_else(|| panic!("Run has no node {}", node_id));
+        ExecutionContext::new(
+            run.nodes.clone(),
+            &Arc::downgrade(&run.run),
+            &self.state,
+            node,
+            &run.variables,
+            &run.cache,
+            run.log_level,
+            ExecutionStage::Dev,
+            run.profile.clone(),
+            run.callback.clone(),
+            run.completion_callbacks.clone(),
+            run.credentials.clone(),
+        )
+        .await
+    }
+
+    /// Executes a run starting at `start` to completion.
+    pub async fn run(&self, start: &str) -> InternalRun {
+        let mut run = self.prepare(start).await;
+        run.execute(self.state.clone()).await;
+        run
+    }
+}
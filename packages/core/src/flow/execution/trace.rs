@@ -1,10 +1,38 @@
 use super::log::LogMessage;
 use crate::flow::variable::Variable;
 use ahash::AHashMap;
-use flow_like_types::{create_id, sync::Mutex};
+use flow_like_types::{Value, create_id, sync::Mutex};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::{sync::Arc, time::SystemTime};
+use std::{collections::BTreeMap, sync::Arc, time::SystemTime};
+
+/// Correlation ids for distributed tracing. The `trace_id` comes from the request that started
+/// the run and is shared by all of its node executions, each execution gets its own `span_id`.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct SpanContext {
+    pub trace_id: Option<String>,
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+}
+
+impl SpanContext {
+    pub fn root(trace_id: Option<String>) -> Self {
+        SpanContext {
+            trace_id,
+            span_id: create_id(),
+            parent_span_id: None,
+        }
+    }
+
+    /// New span in the same trace, parented to this one.
+    pub fn child(&self) -> Self {
+        SpanContext {
+            trace_id: self.trace_id.clone(),
+            span_id: create_id(),
+            parent_span_id: Some(self.span_id.clone()),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Trace {
@@ -13,6 +41,12 @@ pub struct Trace {
     pub logs: Vec<LogMessage>,
     pub start: SystemTime,
     pub end: SystemTime,
+    #[serde(default)]
+    pub span: Option<SpanContext>,
+    /// Input pin values the node resolved, keyed by pin name. Only recorded if the run
+    /// captures pin values, sensitive pins are redacted.
+    #[serde(default)]
+    pub pin_values: Option<BTreeMap<String, Value>>,
 
     // for debugging purposes only
     pub variables: Option<Vec<Variable>>,
@@ -26,6 +60,8 @@ impl Trace {
             logs: vec![],
             start: SystemTime::now(),
             end: SystemTime::now(),
+            span: None,
+            pin_values: None,
             variables: None,
         }
     }
@@ -47,3 +83,18 @@ impl Trace {
         self.variables = Some(variables.lock().await.values().cloned().collect());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_span_keeps_trace_and_links_parent() {
+        let root = SpanContext::root(Some("request".to_string()));
+        let child = root.child();
+        assert_eq!(child.trace_id.as_deref(), Some("request"));
+        assert_eq!(child.parent_span_id.as_ref(), Some(&root.span_id));
+        assert_ne!(child.span_id, root.span_id);
+        assert_eq!(root.parent_span_id, None);
+    }
+}
@@ -127,6 +127,7 @@ impl Node {
                 depends_on: BTreeSet::new(),
                 connected_to: BTreeSet::new(),
                 default_value: None,
+                default_expr: None,
                 options: None,
                 value: None,
                 index: num_outputs as u16 + 1,
@@ -163,6 +164,7 @@ impl Node {
                 depends_on: BTreeSet::new(),
                 connected_to: BTreeSet::new(),
                 default_value: None,
+                default_expr: None,
                 value: None,
                 index: num_outputs as u16 + 1,
             },
@@ -334,6 +336,9 @@ impl Node {
             if let Some(default_value) = &pin.default_value {
                 hasher.append(default_value);
             }
+            if let Some(default_expr) = &pin.default_expr {
+                hasher.append(default_expr.as_bytes());
+            }
             if let Some(options) = &pin.options {
                 if let Some(valid_values) = &options.valid_values {
                     for value in valid_values {
@@ -115,6 +115,10 @@ pub struct Pin {
     pub depends_on: BTreeSet<String>,
     pub connected_to: BTreeSet<String>,
     pub default_value: Option<Vec<u8>>,
+    /// Computed at read time if the pin has no connection and no `default_value`,
+    /// see [`evaluate_default_expr`](crate::flow::utils::evaluate_default_expr).
+    #[serde(default)]
+    pub default_expr: Option<String>,
     pub index: u16,
     pub options: Option<PinOptions>,
 
@@ -129,6 +133,11 @@ impl Pin {
         self
     }
 
+    pub fn set_default_expr(&mut self, default_expr: Option<&str>) -> &mut Self {
+        self.default_expr = default_expr.map(|expr| expr.to_string());
+        self
+    }
+
     pub fn set_value_type(&mut self, value_type: ValueType) -> &mut Self {
         self.value_type = value_type;
         self
@@ -169,6 +178,10 @@ impl Pin {
             hasher.append(schema.as_bytes());
         }
 
+        if let Some(default_expr) = &self.default_expr {
+            hasher.append(default_expr.as_bytes());
+        }
+
         if let Some(options) = &self.options {
             options.hash(hasher);
         }
@@ -208,6 +221,7 @@ mod tests {
             depends_on: BTreeSet::new(),
             connected_to: BTreeSet::new(),
             default_value: None,
+            default_expr: Some("now".to_string()),
             index: 0,
             options: None,
             value: Some(Arc::new(Mutex::new(Value::Null))),
@@ -219,5 +233,6 @@ mod tests {
         let deser = super::Pin::from_proto(flow_like_types::proto::Pin::decode(&buf[..]).unwrap());
 
         assert_eq!(pin.id, deser.id);
+        assert_eq!(pin.default_expr, deser.default_expr);
     }
 }
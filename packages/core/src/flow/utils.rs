@@ -1,11 +1,120 @@
 use std::sync::{Arc, Weak};
 
-use flow_like_types::{Value, sync::Mutex};
+use highway::{HighwayHash, HighwayHasher};
 
-use super::execution::internal_pin::InternalPin;
+use flow_like_types::{
+    Value,
+    json::{DeserializeOwned, from_value},
+    jsonschema,
+    sync::Mutex,
+};
 
+use super::{execution::internal_pin::InternalPin, pin::ValueType, variable::VariableType};
+use crate::state::{FlowLikeConfig, FlowLikeState};
+
+/// Why a pin could not be evaluated into the requested type.
+///
+/// Evaluation errors still travel as `flow_like_types::Error`, nodes and error handlers can
+/// branch on the cause with `PinError::from_error` (or `error.downcast_ref::<PinError>()`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PinError {
+    /// The pin has no value, no connection and no default value.
+    Missing { pin: String },
+    /// The pin evaluated to `null` but the requested type does not accept it.
+    Null { pin: String, expected: String },
+    /// The pin holds a value that can't be read as the requested type.
+    TypeMismatch {
+        pin: String,
+        expected: String,
+        found: String,
+        reason: String,
+    },
+}
+
+impl PinError {
+    pub fn from_error(error: &flow_like_types::Error) -> Option<&PinError> {
+        error.downcast_ref::<PinError>()
+    }
+
+    pub fn pin(&self) -> &str {
+        match self {
+            PinError::Missing { pin }
+            | PinError::Null { pin, .. }
+            | PinError::TypeMismatch { pin, .. } => pin,
+        }
+    }
+}
+
+impl std::fmt::Display for PinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PinError::Missing { pin } => write!(
+                f,
+                "Pin '{}' has no value, dependencies, or default value",
+                pin
+            ),
+            PinError::Null { pin, expected } => {
+                write!(f, "Pin '{}' is null, expected {}", pin, expected)
+            }
+            PinError::TypeMismatch {
+                pin,
+                expected,
+                found,
+                reason,
+            } => write!(
+                f,
+                "Pin '{}' holds {} but {} was expected: {}",
+                pin, found, expected, reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PinError {}
+
+/// Name of the JSON type of a value, used in type mismatch errors.
+pub fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(number) if number.is_f64() => "a float",
+        Value::Number(_) => "an integer",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Deserializes an evaluated pin value, mapping failures to a [`PinError`].
+pub fn deserialize_pin_value<T: DeserializeOwned>(
+    friendly_name: &str,
+    value: Value,
+) -> flow_like_types::Result<T> {
+    let found = json_type_name(&value);
+    let is_null = value.is_null();
+    from_value(value).map_err(|e| {
+        let expected = std::any::type_name::<T>().to_string();
+        let error = if is_null {
+            PinError::Null {
+                pin: friendly_name.to_string(),
+                expected,
+            }
+        } else {
+            PinError::TypeMismatch {
+                pin: friendly_name.to_string(),
+                expected,
+                found: found.to_string(),
+                reason: e.to_string(),
+            }
+        };
+        error.into()
+    })
+}
+
+/// Like [`evaluate_pin_value`], but hands out the shared value instead of a copy.
 pub async fn evaluate_pin_value_reference(
     pin: Arc<Mutex<InternalPin>>,
+    state: Option<&Mutex<FlowLikeState>>,
 ) -> flow_like_types::Result<Arc<Mutex<Value>>> {
     let mut current_pin = pin;
     let mut visited_pins = std::collections::HashSet::with_capacity(8);
@@ -18,12 +127,14 @@ pub async fn evaluate_pin_value_reference(
         };
 
         // Step 2: Get all pin data with a single lock
-        let (pin_id, value, default_value, friendly_name) = {
+        let (pin_id, value, default_value, default_expr, data_type, friendly_name) = {
             let pin = pin_ref.lock().await;
             (
                 pin.id.clone(),
                 pin.value.clone(),
                 pin.default_value.clone(),
+                pin.default_expr.clone(),
+                pin.data_type.clone(),
                 pin.friendly_name.clone(),
             )
         };
@@ -60,11 +171,16 @@ pub async fn evaluate_pin_value_reference(
             };
         }
 
-        // Case 4: No value found
-        return Err(flow_like_types::anyhow!(
-            "Pin '{}' has no value, dependencies, or default value",
-            friendly_name
-        ));
+        // Case 4: Compute the default expression
+        if let Some(default_expr) = default_expr {
+            let value =
+                evaluate_state_default_expr(&friendly_name, &default_expr, &data_type, state)
+                    .await?;
+            return Ok(Arc::new(Mutex::new(value)));
+        }
+
+        // Case 5: No value found
+        return Err(PinError::Missing { pin: friendly_name }.into());
     }
 }
 
@@ -74,10 +190,16 @@ pub async fn evaluate_pin_value_weak(
     let pin = pin
         .upgrade()
         .ok_or_else(|| flow_like_types::anyhow!("Pin is not set"))?;
-    evaluate_pin_value(pin).await
+    evaluate_pin_value(pin, None).await
 }
 
-pub async fn evaluate_pin_value(pin: Arc<Mutex<InternalPin>>) -> flow_like_types::Result<Value> {
+/// Follows the pin's connection and falls back to its default value or expression.
+/// `state` resolves the secrets of `env:NAME` expressions, without it they count as missing.
+/// It is only locked for pins falling back to a default expression.
+pub async fn evaluate_pin_value(
+    pin: Arc<Mutex<InternalPin>>,
+    state: Option<&Mutex<FlowLikeState>>,
+) -> flow_like_types::Result<Value> {
     let mut current_pin = pin;
     let mut visited_pins = std::collections::HashSet::with_capacity(8);
 
@@ -89,12 +211,14 @@ pub async fn evaluate_pin_value(pin: Arc<Mutex<InternalPin>>) -> flow_like_types
         };
 
         // Step 2: Get all pin data with a single lock
-        let (pin_id, value, default_value, friendly_name) = {
+        let (pin_id, value, default_value, default_expr, data_type, friendly_name) = {
             let pin = pin_ref.lock().await;
             (
                 pin.id.clone(),
                 pin.value.clone(),
                 pin.default_value.clone(),
+                pin.default_expr.clone(),
+                pin.data_type.clone(),
                 pin.friendly_name.clone(),
             )
         };
@@ -131,10 +255,270 @@ pub async fn evaluate_pin_value(pin: Arc<Mutex<InternalPin>>) -> flow_like_types
             };
         }
 
-        // Case 4: No value found
-        return Err(flow_like_types::anyhow!(
-            "Pin '{}' has no value, dependencies, or default value",
+        // Case 4: Compute the default expression
+        if let Some(default_expr) = default_expr {
+            return evaluate_state_default_expr(&friendly_name, &default_expr, &data_type, state)
+                .await;
+        }
+
+        // Case 5: No value found
+        return Err(PinError::Missing { pin: friendly_name }.into());
+    }
+}
+
+/// [`evaluate_default_expr`] with the config of `state`, if there is one.
+async fn evaluate_state_default_expr(
+    friendly_name: &str,
+    expr: &str,
+    data_type: &VariableType,
+    state: Option<&Mutex<FlowLikeState>>,
+) -> flow_like_types::Result<Value> {
+    let Some(state) = state else {
+        return evaluate_default_expr(friendly_name, expr, data_type, None);
+    };
+    let config = state.lock().await.config.clone();
+    let config = config.read().await;
+    evaluate_default_expr(friendly_name, expr, data_type, Some(&config))
+}
+
+/// Evaluates a pin's `default_expr`. The language is deliberately tiny, an expression is one of
+///
+/// - `now`: the current time, a Date for Date pins, unix milliseconds for Integer pins,
+///   unix seconds for Float pins and an RFC 3339 String otherwise
+/// - `id`: a new cuid2 id
+/// - `uuid`: a new random (v4) UUID
+/// - `env:NAME`: the secret `NAME` as resolved by [`FlowLikeConfig::get_secret`], which only
+///   reads the process environment if the host opted in. Without a `config` or without such
+///   a secret the value counts as missing
+///
+/// `now`, `id` and `uuid` are nondeterministic, they ignore the run seed.
+pub fn evaluate_default_expr(
+    friendly_name: &str,
+    expr: &str,
+    data_type: &VariableType,
+    config: Option<&FlowLikeConfig>,
+) -> flow_like_types::Result<Value> {
+    let expr = expr.trim();
+
+    if let Some(name) = expr.strip_prefix("env:") {
+        return match config.and_then(|config| config.get_secret(name.trim())) {
+            Some(value) => Ok(Value::String(value)),
+            None => Err(PinError::Missing {
+                pin: friendly_name.to_string(),
+            }
+            .into()),
+        };
+    }
+
+    match expr {
+        "now" => {
+            let now = std::time::SystemTime::now();
+            let since_epoch = now
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default();
+            let value = match data_type {
+                VariableType::Date => flow_like_types::json::to_value(now)?,
+                VariableType::Integer => Value::from(since_epoch.as_millis() as i64),
+                VariableType::Float => Value::from(since_epoch.as_secs_f64()),
+                _ => Value::String(chrono::DateTime::<chrono::Utc>::from(now).to_rfc3339()),
+            };
+            Ok(value)
+        }
+        "id" => Ok(Value::String(flow_like_types::create_id())),
+        "uuid" => Ok(Value::String(uuid::Uuid::new_v4().to_string())),
+        _ => Err(flow_like_types::anyhow!(
+            "Unknown default expression '{}' on pin '{}', expected now, id, uuid or env:NAME",
+            expr,
             friendly_name
+        )),
+    }
+}
+
+/// Single source of truth for whether an execution pin fired.
+///
+/// A pin is active if, and only if, it evaluates to `true`. `false`, `Null`, any other
+/// value, a missing value and evaluation errors all count as inactive.
+pub async fn is_exec_active(pin: Arc<Mutex<InternalPin>>) -> bool {
+    matches!(evaluate_pin_value(pin, None).await, Ok(Value::Bool(true)))
+}
+
+/// Validates a value against the JSON schema stored on a pin.
+///
+/// Array and HashSet pins validate every element, HashMap pins every map value. The error
+/// lists each failing property path together with what the schema expected there.
+pub fn validate_pin_schema(
+    friendly_name: &str,
+    schema: &str,
+    value_type: &ValueType,
+    value: &Value,
+) -> flow_like_types::Result<()> {
+    let schema: Value = flow_like_types::json::from_str(schema).map_err(|e| {
+        flow_like_types::anyhow!("Invalid schema on pin '{}': {}", friendly_name, e)
+    })?;
+    let validator = jsonschema::validator_for(&schema).map_err(|e| {
+        flow_like_types::anyhow!("Invalid schema on pin '{}': {}", friendly_name, e)
+    })?;
+
+    let items: Vec<(String, &Value)> = match (value_type, value) {
+        (ValueType::Array | ValueType::HashSet, Value::Array(items)) => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (format!("/{}", index), item))
+            .collect(),
+        (ValueType::HashMap, Value::Object(map)) => map
+            .iter()
+            .map(|(key, item)| (format!("/{}", key), item))
+            .collect(),
+        _ => vec![(String::new(), value)],
+    };
+
+    let mut failures = vec![];
+    for (prefix, item) in items {
+        for error in validator.iter_errors(item) {
+            let path = format!("{}{}", prefix, error.instance_path);
+            let path = if path.is_empty() {
+                "/".to_string()
+            } else {
+                path
+            };
+            failures.push(format!("at '{}': {}", path, error));
+        }
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    Err(flow_like_types::anyhow!(
+        "Value of pin '{}' does not match its schema: {}",
+        friendly_name,
+        failures.join("; ")
+    ))
+}
+
+/// Stable hex digest of a value, logically equal values (e.g. objects with a different key
+/// order) hash identically. Uses the same canonical JSON and HighwayHash key as pin hashing.
+pub fn stable_hash(value: &Value) -> flow_like_types::Result<String> {
+    let canonical = canonical_json::ser::to_string(value)
+        .map_err(|e| flow_like_types::anyhow!("Failed to canonicalize value: {:?}", e))?;
+    let mut hasher = HighwayHasher::new(highway::Key([
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0x0011223344556677,
+        0x8899aabbccddeeff,
+    ]));
+    hasher.append(canonical.as_bytes());
+    Ok(hasher
+        .finalize256()
+        .iter()
+        .map(|part| format!("{:016x}", part))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PinError, deserialize_pin_value, evaluate_default_expr, stable_hash, validate_pin_schema,
+    };
+    use crate::flow::pin::ValueType;
+    use crate::flow::variable::VariableType;
+    use crate::state::{FlowLikeConfig, FlowLikeState};
+    use flow_like_types::json::json;
+
+    const SCHEMA: &str =
+        r#"{"type":"object","properties":{"name":{"type":"string"}},"required":["name"]}"#;
+
+    #[test]
+    fn reports_failing_property_path() {
+        let err = validate_pin_schema("Database", SCHEMA, &ValueType::Normal, &json!({"name": 1}))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'Database'"));
+        assert!(err.contains("at '/name'"));
+        assert!(err.contains("\"string\""));
+    }
+
+    #[test]
+    fn validates_array_elements() {
+        let value = json!([{"name": "a"}, {}]);
+        let err = validate_pin_schema("Items", SCHEMA, &ValueType::Array, &value)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at '/1'"));
+        assert!(validate_pin_schema("Items", SCHEMA, &ValueType::Array, &json!([])).is_ok());
+    }
+
+    #[test]
+    fn deserialize_errors_are_typed() {
+        let err = deserialize_pin_value::<i64>("Count", json!(null)).unwrap_err();
+        assert!(matches!(
+            PinError::from_error(&err),
+            Some(PinError::Null { pin, .. }) if pin == "Count"
         ));
+
+        let err = deserialize_pin_value::<i64>("Count", json!("3")).unwrap_err();
+        let Some(PinError::TypeMismatch {
+            expected, found, ..
+        }) = PinError::from_error(&err)
+        else {
+            panic!("expected a type mismatch, got {}", err);
+        };
+        assert_eq!(expected, "i64");
+        assert_eq!(found, "a string");
+
+        let value: Option<i64> = deserialize_pin_value("Count", json!(null)).unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn evaluates_default_expressions() {
+        let now = evaluate_default_expr("At", "now", &VariableType::Integer, None).unwrap();
+        assert!(now.as_i64().unwrap() > 1_600_000_000_000);
+
+        let uuid = evaluate_default_expr("Id", "uuid", &VariableType::String, None).unwrap();
+        let uuid = uuid::Uuid::parse_str(uuid.as_str().unwrap()).unwrap();
+        assert_eq!(uuid.get_version_num(), 4);
+
+        assert!(evaluate_default_expr("X", "1 + 1", &VariableType::Integer, None).is_err());
+    }
+
+    #[test]
+    fn env_expressions_resolve_secrets_only() {
+        let mut config = FlowLikeConfig::new();
+        config.register_secret("FLOW_LIKE_TEST_SECRET", "registered");
+        let value = evaluate_default_expr(
+            "Key",
+            "env:FLOW_LIKE_TEST_SECRET",
+            &VariableType::String,
+            Some(&config),
+        )
+        .unwrap();
+        assert_eq!(value, json!("registered"));
+
+        // PATH is set in every test environment, but must not leak into boards
+        for config in [None, Some(&config)] {
+            let err = evaluate_default_expr("Key", "env:PATH", &VariableType::String, config)
+                .unwrap_err();
+            assert!(matches!(
+                PinError::from_error(&err),
+                Some(PinError::Missing { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn stable_hash_ignores_key_order() {
+        let a = stable_hash(&json!({"a": 1, "b": [1, 2]})).unwrap();
+        let b = stable_hash(&json!({"b": [1, 2], "a": 1})).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, stable_hash(&json!({"a": 2, "b": [1, 2]})).unwrap());
+    }
+
+    #[test]
+    fn values_keep_key_order() {
+        let value: flow_like_types::Value =
+            flow_like_types::json::from_str(r#"{"z": 1, "a": 2, "m": 3}"#).unwrap();
+        assert_eq!(value.to_string(), r#"{"z":1,"a":2,"m":3}"#);
     }
 }
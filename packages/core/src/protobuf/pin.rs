@@ -67,6 +67,7 @@ impl ToProto<flow_like_types::proto::Pin> for Pin {
             default_value: self.default_value.clone().unwrap_or_default(),
             index: self.index as u32,
             options: self.options.as_ref().map(|o| o.to_proto()),
+            default_expr: self.default_expr.clone(),
         }
     }
 }
@@ -93,6 +94,7 @@ impl FromProto<flow_like_types::proto::Pin> for Pin {
             } else {
                 Some(proto.default_value)
             },
+            default_expr: proto.default_expr,
             index: proto.index as u16,
             options: proto.options.map(PinOptions::from_proto),
             value: None,
@@ -4,6 +4,8 @@ use flow_like_storage::object_store::path::Path;
 use flow_like_types::Ok;
 use flow_like_types::sync::{DashMap, Mutex, RwLock};
 #[cfg(feature = "flow-runtime")]
+use flow_like_types::tokio::sync::oneshot;
+#[cfg(feature = "flow-runtime")]
 use flow_like_types::tokio_util::sync::CancellationToken;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -50,6 +52,10 @@ pub struct FlowLikeCallbacks {
 pub struct FlowLikeConfig {
     pub stores: FlowLikeStores,
     pub callbacks: FlowLikeCallbacks,
+    pub secrets: HashMap<String, String>,
+    /// Lets [`Self::get_secret`] fall back to the process environment. Off by default,
+    /// as boards could otherwise read any environment variable of the host.
+    pub secrets_from_env: bool,
 }
 
 impl FlowLikeConfig {
@@ -57,6 +63,8 @@ impl FlowLikeConfig {
         FlowLikeConfig {
             callbacks: FlowLikeCallbacks::default(),
             stores: FlowLikeStores::default(),
+            secrets: HashMap::new(),
+            secrets_from_env: false,
         }
     }
 
@@ -71,6 +79,8 @@ impl FlowLikeConfig {
                 temporary_store: Some(store.clone()),
                 log_store: Some(store),
             },
+            secrets: HashMap::new(),
+            secrets_from_env: false,
         }
     }
 
@@ -111,6 +121,30 @@ impl FlowLikeConfig {
     ) {
         self.callbacks.build_logs_database = Some(callback);
     }
+
+    pub fn register_secret(&mut self, name: &str, value: &str) {
+        self.secrets.insert(name.to_string(), value.to_string());
+    }
+
+    /// Allows secrets to be resolved from the process environment, e.g. for local
+    /// development. Only enable this where every board is trusted.
+    pub fn set_secrets_from_env(&mut self, enabled: bool) {
+        self.secrets_from_env = enabled;
+    }
+
+    /// Resolves a secret registered on the config. Falls back to the process environment
+    /// only if [`Self::secrets_from_env`] is set.
+    pub fn get_secret(&self, name: &str) -> Option<String> {
+        if let Some(secret) = self.secrets.get(name) {
+            return Some(secret.clone());
+        }
+
+        if !self.secrets_from_env {
+            return None;
+        }
+
+        std::env::var(name).ok()
+    }
 }
 
 #[cfg(feature = "flow-runtime")]
@@ -317,6 +351,9 @@ pub struct FlowLikeState {
     pub board_registry: Arc<DashMap<String, Arc<Mutex<Board>>>>, // TODO: should board be wrapped in RWLock or Mutex?
     #[cfg(feature = "flow-runtime")]
     pub board_run_registry: Arc<DashMap<String, Arc<RunData>>>,
+    /// Runs waiting for an external signal, keyed by the token they handed out.
+    #[cfg(feature = "flow-runtime")]
+    pub signal_registry: Arc<DashMap<String, oneshot::Sender<flow_like_types::Value>>>,
 }
 
 impl FlowLikeState {
@@ -342,6 +379,8 @@ impl FlowLikeState {
             board_registry: Arc::new(DashMap::new()),
             #[cfg(feature = "flow-runtime")]
             board_run_registry: Arc::new(DashMap::new()),
+            #[cfg(feature = "flow-runtime")]
+            signal_registry: Arc::new(DashMap::new()),
         }
     }
 
@@ -471,6 +510,37 @@ impl FlowLikeState {
         Ok(())
     }
 
+    /// Registers a pending signal under `token`. The receiver resolves once
+    /// [`Self::send_signal`] is called with the same token.
+    #[cfg(feature = "flow-runtime")]
+    pub fn register_signal(&self, token: &str) -> oneshot::Receiver<flow_like_types::Value> {
+        let (sender, receiver) = oneshot::channel();
+        self.signal_registry.insert(token.to_string(), sender);
+        receiver
+    }
+
+    /// Delivers `payload` to the run waiting for `token`. Fails if no run waits for it,
+    /// e.g. because the token was already used or the wait timed out.
+    #[cfg(feature = "flow-runtime")]
+    pub fn send_signal(
+        &self,
+        token: &str,
+        payload: flow_like_types::Value,
+    ) -> flow_like_types::Result<()> {
+        let (_token, sender) = self
+            .signal_registry
+            .remove(token)
+            .ok_or_else(|| flow_like_types::anyhow!("No run is waiting for signal {}", token))?;
+        sender
+            .send(payload)
+            .map_err(|_| flow_like_types::anyhow!("Run waiting for signal {} is gone", token))
+    }
+
+    #[cfg(feature = "flow-runtime")]
+    pub fn remove_signal(&self, token: &str) {
+        self.signal_registry.remove(token);
+    }
+
     #[cfg(feature = "flow-runtime")]
     pub fn get_run(&self, run_id: &str) -> flow_like_types::Result<Arc<RunData>> {
         let run = self.board_run_registry.try_get(run_id);
@@ -655,6 +725,21 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn secrets_ignore_environment_unless_enabled() {
+        let mut config = FlowLikeConfig::new();
+        config.register_secret("FLOW_LIKE_TEST_SECRET", "registered");
+        assert_eq!(
+            config.get_secret("FLOW_LIKE_TEST_SECRET").as_deref(),
+            Some("registered")
+        );
+
+        // PATH is set in every test environment
+        assert_eq!(config.get_secret("PATH"), None);
+        config.set_secrets_from_env(true);
+        assert!(config.get_secret("PATH").is_some());
+    }
+
     #[test]
     fn object_store_path_serialization() {
         let path = Path::from("test").child("path").child("one");
@@ -663,6 +748,37 @@ mod tests {
         assert_eq!(event.to_str().unwrap(), "random/test/path/one");
     }
 
+    #[cfg(feature = "flow-runtime")]
+    #[tokio::test]
+    async fn signals_resolve_once() {
+        use flow_like_types::json::json;
+
+        let (http_client, _refetch_rx) = HTTPClient::new();
+        let state = FlowLikeState::new(FlowLikeConfig::new(), http_client);
+
+        let receiver = state.register_signal("token");
+        state.send_signal("token", json!("approved")).unwrap();
+        assert_eq!(receiver.await.unwrap(), json!("approved"));
+
+        assert!(state.signal_registry.is_empty());
+        assert!(state.send_signal("token", json!(null)).is_err());
+        assert!(state.send_signal("unknown", json!(null)).is_err());
+    }
+
+    #[cfg(feature = "flow-runtime")]
+    #[tokio::test]
+    async fn removed_signals_are_rejected() {
+        use flow_like_types::json::json;
+
+        let (http_client, _refetch_rx) = HTTPClient::new();
+        let state = FlowLikeState::new(FlowLikeConfig::new(), http_client);
+
+        let _receiver = state.register_signal("token");
+        state.remove_signal("token");
+        assert!(state.signal_registry.is_empty());
+        assert!(state.send_signal("token", json!(null)).is_err());
+    }
+
     #[tokio::test]
     async fn test_object_store_any_cast() {
         let memory_store = flow_like_storage::object_store::memory::InMemory::new();
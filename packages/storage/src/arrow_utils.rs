@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use arrow::datatypes::FieldRef;
 use arrow_array::{RecordBatch, RecordBatchIterator};
-use arrow_schema::{DataType, Field};
+use arrow_schema::{DataType, Field, Fields, Schema};
 use flow_like_types::{
     Result, Value, anyhow,
     json::{Deserialize, Serialize, to_value},
@@ -85,10 +85,143 @@ pub fn record_batch_to_value(record_batch: &RecordBatch) -> Result<Vec<Value>> {
     Ok(items)
 }
 
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(number) if number.is_f64() => "a float",
+        Value::Number(_) => "an integer",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Checks that `value` can be written into a column of `data_type`, returns what was expected otherwise.
+fn check_value(data_type: &DataType, value: &Value, path: &str) -> std::result::Result<(), String> {
+    let mismatch = |expected: &str| {
+        Err(format!(
+            "field '{}' expected {}, got {}",
+            path,
+            expected,
+            json_kind(value)
+        ))
+    };
+
+    match data_type {
+        DataType::Boolean if !value.is_boolean() => mismatch("a boolean"),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+            if !(value.is_i64() || value.is_u64()) =>
+        {
+            mismatch("an integer")
+        }
+        DataType::Float16 | DataType::Float32 | DataType::Float64 if !value.is_number() => {
+            mismatch("a number")
+        }
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View if !value.is_string() => {
+            mismatch("a string")
+        }
+        DataType::List(item) | DataType::LargeList(item) => match value {
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, item_value)| {
+                    check_nullable(item, item_value, &format!("{}.{}", path, index))
+                }),
+            _ => mismatch("an array"),
+        },
+        DataType::FixedSizeList(item, size) => match value {
+            Value::Array(items) if items.len() != *size as usize => Err(format!(
+                "field '{}' expected {} elements, got {}",
+                path,
+                size,
+                items.len()
+            )),
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, item_value)| {
+                    check_nullable(item, item_value, &format!("{}.{}", path, index))
+                }),
+            _ => mismatch("an array"),
+        },
+        DataType::Struct(fields) => match value {
+            Value::Object(_) => check_fields(fields, value, &format!("{}.", path), true),
+            _ => mismatch("an object"),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn check_nullable(field: &Field, value: &Value, path: &str) -> std::result::Result<(), String> {
+    if value.is_null() {
+        if field.is_nullable() {
+            return Ok(());
+        }
+        return Err(format!("field '{}' is required but missing or null", path));
+    }
+    check_value(field.data_type(), value, path)
+}
+
+fn check_fields(
+    fields: &Fields,
+    record: &Value,
+    prefix: &str,
+    strict: bool,
+) -> std::result::Result<(), String> {
+    let Some(object) = record.as_object() else {
+        return Err(format!("expected an object, got {}", json_kind(record)));
+    };
+
+    if strict
+        && let Some(unknown) = object
+            .keys()
+            .find(|key| fields.iter().all(|field| field.name() != *key))
+    {
+        return Err(format!(
+            "field '{}{}' is not part of the schema",
+            prefix, unknown
+        ));
+    }
+
+    for field in fields.iter() {
+        let path = format!("{}{}", prefix, field.name());
+        match object.get(field.name()) {
+            Some(value) => check_nullable(field, value, &path)?,
+            None if strict && !field.is_nullable() => {
+                return Err(format!("field '{}' is required but missing or null", path));
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks every record against `schema` and reports the first record index and field that
+/// can't be converted.
+///
+/// With `strict` unknown fields and missing non-nullable fields are errors (writing into an
+/// existing table), otherwise only fields present in both are type checked.
+pub fn validate_records(schema: &Schema, records: &[Value], strict: bool) -> Result<()> {
+    for (index, record) in records.iter().enumerate() {
+        check_fields(schema.fields(), record, "", strict)
+            .map_err(|reason| anyhow!("Record {}: {}", index, reason))?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use flow_like_types::json::{Deserialize, to_value};
+    use flow_like_types::json::{Deserialize, json, to_value};
 
     #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
     struct TestStruct {
@@ -126,4 +259,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn validate_records_reports_index_and_field() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new(
+                "vector",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 2),
+                true,
+            ),
+        ]);
+
+        let valid = vec![
+            json!({"id": 1, "name": "a", "vector": [0.1, 0.2]}),
+            json!({"id": 2, "name": null}),
+        ];
+        assert!(validate_records(&schema, &valid, true).is_ok());
+
+        let err = validate_records(&schema, &[valid[0].clone(), json!({"id": "3"})], true)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Record 1: field 'id' expected an integer, got a string"
+        );
+
+        let err = validate_records(&schema, &[json!({"id": 1, "vector": [1.0]})], true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected 2 elements"));
+
+        let ragged = [json!({"id": 1}), json!({"id": 2, "extra": true})];
+        assert!(validate_records(&schema, &ragged, true).is_err());
+        assert!(validate_records(&schema, &ragged, false).is_ok());
+    }
 }
@@ -1,5 +1,86 @@
 pub mod lancedb;
-use flow_like_types::{Result, Value, async_trait};
+pub mod migration;
+use flow_like_types::{Result, Value, anyhow, async_trait};
+use std::time::Duration;
+
+/// Limits for the queries of a store, so slow or numerous queries can't hang a flow or
+/// overwhelm the backend.
+///
+/// Passed to a single query, its `timeout` replaces the store's timeout. The concurrency cap
+/// only makes sense shared between queries, so `max_concurrent` only applies when set on the
+/// store (e.g. `LanceDBVectorStore::set_query_options`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// Queries running longer fail with a timeout error.
+    pub timeout: Option<Duration>,
+    /// Queries beyond this many in flight wait for a free slot.
+    pub max_concurrent: Option<usize>,
+}
+
+/// Checks that a filter predicate can be wrapped in parentheses without escaping them:
+/// parentheses outside of literals must balance, literals must be closed, and comments or
+/// statement separators are rejected since they could swallow the closing parenthesis.
+pub fn validate_filter(filter: &str) -> Result<()> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut chars = filter.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if let Some(open) = quote {
+            // doubled quotes are escapes inside a literal
+            if c == open && chars.next_if_eq(&open).is_none() {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("Unbalanced ')' in filter '{}'", filter))?;
+            }
+            ';' => return Err(anyhow!("Filter '{}' must be a single predicate", filter)),
+            '-' if chars.peek() == Some(&'-') => {
+                return Err(anyhow!("Comments are not allowed in filter '{}'", filter));
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                return Err(anyhow!("Comments are not allowed in filter '{}'", filter));
+            }
+            _ => {}
+        }
+    }
+
+    if quote.is_some() {
+        return Err(anyhow!("Unterminated literal in filter '{}'", filter));
+    }
+    if depth > 0 {
+        return Err(anyhow!("Unbalanced '(' in filter '{}'", filter));
+    }
+    Ok(())
+}
+
+/// AND-combines filter predicates, each wrapped in parentheses so none of them can change how
+/// the others are evaluated, e.g. a user filter `a = 1 OR true` next to a tenant filter.
+/// Blank predicates are skipped, `None` if none are left.
+pub fn and_filters<S: AsRef<str>>(filters: &[S]) -> Result<Option<String>> {
+    let mut predicates = vec![];
+    for filter in filters {
+        let filter = filter.as_ref().trim();
+        if filter.is_empty() {
+            continue;
+        }
+        validate_filter(filter)?;
+        predicates.push(format!("({})", filter));
+    }
+
+    if predicates.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(predicates.join(" AND ")))
+}
 
 #[async_trait]
 pub trait VectorStore: Send + Sync {
@@ -10,6 +91,8 @@ pub trait VectorStore: Send + Sync {
     /// * `vector`: The vector to search for similar vectors.
     /// * `filter`: An optional filter to narrow down the search results.
     /// * `limit`: The maximum number of results to return.
+    /// * `fast_search`: Only search indexed data. Rows written since the last index update are skipped.
+    /// * `options`: Optional limits for this query, see [`QueryOptions`].
     ///
     /// # Returns
     ///
@@ -21,6 +104,8 @@ pub trait VectorStore: Send + Sync {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        fast_search: bool,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>>;
 
     /// Perform a full-text search using the given text input.
@@ -29,6 +114,7 @@ pub trait VectorStore: Send + Sync {
     ///
     /// * `text`: The text to search for similar items.
     /// * `limit`: The maximum number of results to return.
+    /// * `options`: Optional limits for this query, see [`QueryOptions`].
     ///
     /// # Returns
     ///
@@ -40,6 +126,7 @@ pub trait VectorStore: Send + Sync {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>>;
 
     /// Perform a hybrid search using both vector and text input.
@@ -49,6 +136,9 @@ pub trait VectorStore: Send + Sync {
     /// * `vector`: The vector to search for similar vectors.
     /// * `text`: The text to search for similar items.
     /// * `limit`: The maximum number of results to return.
+    /// * `rerank`: Fuse both result lists with Reciprocal Rank Fusion.
+    /// * `rrf_k`: RRF smoothing constant, defaults to 60.0. Lower values favor top ranks more.
+    /// * `options`: Optional limits for this query, see [`QueryOptions`].
     ///
     /// # Returns
     ///
@@ -62,6 +152,8 @@ pub trait VectorStore: Send + Sync {
         limit: usize,
         offset: usize,
         rerank: bool,
+        rrf_k: Option<f32>,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>>;
 
     /// Query the vector store based on a filter.
@@ -70,6 +162,7 @@ pub trait VectorStore: Send + Sync {
     ///
     /// * `filter`: The filter to apply to the query.
     /// * `limit`: The maximum number of results to return.
+    /// * `options`: Optional limits for this query, see [`QueryOptions`].
     ///
     /// # Returns
     ///
@@ -80,6 +173,7 @@ pub trait VectorStore: Send + Sync {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>>;
 
     /// Upsert items into the vector store.
@@ -115,6 +209,22 @@ pub trait VectorStore: Send + Sync {
     /// A result indicating success or an error.
     async fn delete(&self, filter: &str) -> Result<()>;
 
+    /// Replace all items matching a filter with new items, e.g. one day of time-partitioned data.
+    ///
+    /// This is not transactional: matching items are deleted first and the new items inserted
+    /// afterwards. Readers in between see the partition empty, and if the insert fails the
+    /// deleted items are gone.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter`: The filter selecting the partition to replace, must not be empty.
+    /// * `items`: A vector of JSON-encoded items making up the new partition.
+    ///
+    /// # Returns
+    ///
+    /// A result indicating success or an error.
+    async fn replace_where(&mut self, filter: &str, items: Vec<Value>) -> Result<()>;
+
     /// Build a search index on the specified column.
     ///
     /// # Arguments
@@ -127,6 +237,18 @@ pub trait VectorStore: Send + Sync {
     /// A result indicating success or an error.
     async fn index(&self, column: &str, index_type: Option<&str>) -> Result<()>;
 
+    /// Build a single index spanning several columns, for filters combining them.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns`: The column names to build the index on.
+    /// * `index_type`: The index type, only types supporting multiple columns are accepted.
+    ///
+    /// # Returns
+    ///
+    /// A result indicating success or an error.
+    async fn index_composite(&self, columns: &[&str], index_type: Option<&str>) -> Result<()>;
+
     /// Optimize the vector store (implementation-specific).
     ///
     /// # Returns
@@ -134,7 +256,7 @@ pub trait VectorStore: Send + Sync {
     /// A result indicating success or an error.
     async fn optimize(&self, keep_versions: bool) -> Result<()>;
 
-    /// List all items in the vector store.
+    /// List all items in the vector store, `options` optionally limits the query.
     ///
     /// # Returns
     ///
@@ -144,6 +266,7 @@ pub trait VectorStore: Send + Sync {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>>;
 
     /// Purge all data from the vector store.
@@ -153,13 +276,53 @@ pub trait VectorStore: Send + Sync {
     /// A result indicating success or an error.
     async fn purge(&self) -> Result<()>;
 
-    /// Returns the total number of items in the vector store.
+    /// Returns the total number of items in the vector store, `options` optionally limits
+    /// the query.
     ///
     /// # Returns
     ///
     /// * `Ok(usize)` - The total count of items in the vector store.
     /// * `Err(anyhow::Error)` - If the count operation fails.
-    async fn count(&self, filter: Option<String>) -> Result<usize>;
+    async fn count(&self, filter: Option<String>, options: Option<QueryOptions>) -> Result<usize>;
 
     async fn schema(&self) -> Result<arrow_schema::Schema>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_each_predicate() {
+        let filter = and_filters(&["a = 1 OR true", " ", "tenant = 'x'"]).unwrap();
+        assert_eq!(
+            filter.as_deref(),
+            Some("(a = 1 OR true) AND (tenant = 'x')")
+        );
+        assert_eq!(and_filters::<&str>(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn ignores_parentheses_in_literals() {
+        assert!(validate_filter("name = ')' OR name = 'it''s ('").is_ok());
+        assert!(validate_filter("\"weird)col\" = 1").is_ok());
+    }
+
+    #[test]
+    fn rejects_escaping_predicates() {
+        for filter in [
+            "1 = 1) OR (true",
+            "(a = 1",
+            "name = 'open",
+            "a = 1 -- ",
+            "a = 1 /* ",
+            "a = 1; DROP",
+        ] {
+            assert!(
+                and_filters(&[filter, "tenant = 'x'"]).is_err(),
+                "{}",
+                filter
+            );
+        }
+    }
+}
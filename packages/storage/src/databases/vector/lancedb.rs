@@ -1,9 +1,13 @@
-use arrow_array::RecordBatch;
+use arrow_array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+use datafusion::execution::context::SQLOptions;
 use datafusion::prelude::*;
 use flow_like_types::Cacheable;
 use flow_like_types::async_trait;
+use flow_like_types::tokio;
+use flow_like_types::tokio_util::sync::CancellationToken;
 use flow_like_types::{Result, Value, anyhow};
-use futures::TryStreamExt;
+use futures::{Stream, TryStreamExt};
+use lancedb::database::CreateTableMode;
 use lancedb::index::IndexConfig;
 use lancedb::index::scalar::BTreeIndexBuilder;
 use lancedb::index::scalar::BitmapIndexBuilder;
@@ -22,13 +26,17 @@ use lancedb::{
     query::{ExecutableQuery, QueryBase},
     table::{CompactionOptions, Duration, OptimizeOptions},
 };
+use std::future::Future;
 
-use std::{any::Any, path::PathBuf, sync::Arc};
+use std::{any::Any, collections::HashMap, path::PathBuf, sync::Arc};
 
 use crate::arrow_utils::record_batch_to_value;
+use crate::arrow_utils::validate_records;
 use crate::arrow_utils::value_to_batch_iterator;
+use crate::arrow_utils::value_to_record_batch;
 
-use super::VectorStore;
+use super::migration::{SchemaChange, plan_migration};
+use super::{QueryOptions, VectorStore, and_filters};
 
 #[derive(serde::Serialize)]
 pub struct IndexConfigDto {
@@ -47,11 +55,41 @@ impl From<IndexConfig> for IndexConfigDto {
     }
 }
 
+/// How a table is created when the first batch is written to a store without an existing table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableWriteMode {
+    /// Fail if a table with the same name already exists.
+    #[default]
+    Create,
+    /// Replace an existing table with the same name.
+    Overwrite,
+}
+
+impl From<TableWriteMode> for CreateTableMode {
+    fn from(mode: TableWriteMode) -> Self {
+        match mode {
+            TableWriteMode::Create => CreateTableMode::Create,
+            TableWriteMode::Overwrite => CreateTableMode::Overwrite,
+        }
+    }
+}
+
+/// Reciprocal Rank Fusion constant used by hybrid search if none is given.
+pub const DEFAULT_RRF_K: f32 = 60.0;
+
 #[derive(Clone)]
 pub struct LanceDBVectorStore {
     connection: Connection,
     table: Option<Table>,
     table_name: String,
+    write_mode: TableWriteMode,
+    data_storage_version: Option<String>,
+    cancellation: Option<CancellationToken>,
+    with_row_id: bool,
+    scope_filter: Option<String>,
+    query_timeout: Option<std::time::Duration>,
+    query_slots: Option<Arc<tokio::sync::Semaphore>>,
+    read_only: bool,
 }
 
 impl Cacheable for LanceDBVectorStore {
@@ -64,16 +102,52 @@ impl Cacheable for LanceDBVectorStore {
     }
 }
 impl LanceDBVectorStore {
-    pub async fn new(path: PathBuf, table_name: String) -> Result<Self> {
-        let connection = connect(path.to_str().unwrap()).execute().await.ok();
-        let connection: Connection = connection.ok_or(anyhow!("Error connecting to LanceDB"))?;
+    /// Connects to the dataset at `path`.
+    ///
+    /// `storage_options` are forwarded to the object store (e.g. `region`, `endpoint`,
+    /// `aws_access_key_id`), which is required for remote locations like `s3://`.
+    pub async fn new(
+        path: PathBuf,
+        table_name: String,
+        storage_options: Option<HashMap<String, String>>,
+    ) -> Result<Self> {
+        let uri = path.to_string_lossy().to_string();
+        let mut builder = connect(&uri);
+        if let Some(storage_options) = storage_options {
+            builder = builder.storage_options(storage_options);
+        }
 
-        let table = connection.open_table(&table_name).execute().await.ok();
+        let connection: Connection = builder
+            .execute()
+            .await
+            .map_err(|e| anyhow!("Error connecting to LanceDB at {}: {}", uri, e))?;
+
+        // a missing table is fine, it gets created on the first write
+        let table = match connection.open_table(&table_name).execute().await {
+            Ok(table) => Some(table),
+            Err(lancedb::Error::TableNotFound { .. }) => None,
+            Err(e) => {
+                return Err(anyhow!(
+                    "Error opening LanceDB table '{}' at {}: {}",
+                    table_name,
+                    uri,
+                    e
+                ));
+            }
+        };
 
         Ok(LanceDBVectorStore {
             connection,
             table,
             table_name,
+            write_mode: TableWriteMode::default(),
+            data_storage_version: None,
+            cancellation: None,
+            with_row_id: false,
+            scope_filter: None,
+            query_timeout: None,
+            query_slots: None,
+            read_only: false,
         })
     }
 
@@ -84,19 +158,281 @@ impl LanceDBVectorStore {
             connection,
             table,
             table_name,
+            write_mode: TableWriteMode::default(),
+            data_storage_version: None,
+            cancellation: None,
+            with_row_id: false,
+            scope_filter: None,
+            query_timeout: None,
+            query_slots: None,
+            read_only: false,
+        }
+    }
+
+    /// Copy of the store targeting another table on the same connection. Every setting,
+    /// including the scope filter, query limits and read-only mode, carries over.
+    pub async fn for_table(&self, table_name: String) -> Self {
+        let mut store = self.clone();
+        store.table = self.connection.open_table(&table_name).execute().await.ok();
+        store.table_name = table_name;
+        store
+    }
+
+    pub fn set_write_mode(&mut self, write_mode: TableWriteMode) -> &mut Self {
+        self.write_mode = write_mode;
+        self
+    }
+
+    /// Lance file format version used for newly created tables, e.g. `"stable"`, `"2.0"` or `"2.1"`.
+    pub fn set_data_storage_version(&mut self, version: &str) -> &mut Self {
+        self.data_storage_version = Some(version.to_string());
+        self
+    }
+
+    /// Copy of the store whose queries stop reading results once `token` is cancelled,
+    /// failing with `Cancelled` instead of running to completion in the background.
+    pub fn with_cancellation(&self, token: Option<CancellationToken>) -> Self {
+        let mut store = self.clone();
+        store.cancellation = token;
+        store
+    }
+
+    /// Copy of the store whose list, filter and search results include Lance's stable `_rowid`
+    /// column, usable in filters like `_rowid = 42` for targeted updates and deletes.
+    pub fn with_row_id(&self, with_row_id: bool) -> Self {
+        let mut store = self.clone();
+        store.with_row_id = with_row_id;
+        store
+    }
+
+    /// Copy of the store restricting every search, filter, list, count, delete and SQL query
+    /// to rows matching `filter`, e.g. a tenant or ACL predicate. It is AND-combined with the
+    /// caller's filter, which can't escape it. Access that would bypass it (`raw`,
+    /// `to_datafusion` and `copy_table`) fails on a scoped store. Scoping a scoped store
+    /// narrows the existing scope.
+    pub fn with_scope_filter(&self, filter: Option<&str>) -> Result<Self> {
+        let mut store = self.clone();
+        store.scope_filter = self.scoped_filter(filter)?;
+        Ok(store)
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        self.scope_filter.is_some()
+    }
+
+    fn ensure_unscoped(&self, access: &str) -> Result<()> {
+        if self.scope_filter.is_some() {
+            return Err(anyhow!(
+                "{} is not available on the scoped store for table '{}'",
+                access,
+                self.table_name
+            ));
+        }
+        Ok(())
+    }
+
+    /// The caller's filter AND-combined with the scope filter.
+    fn scoped_filter(&self, filter: Option<&str>) -> Result<Option<String>> {
+        let filters = [self.scope_filter.as_deref(), filter];
+        and_filters(&filters.into_iter().flatten().collect::<Vec<_>>())
+    }
+
+    /// Copy of the store rejecting every write, e.g. for query-only services. Inserts, deletes,
+    /// schema changes, indexing and optimizing fail with a read-only error before reaching
+    /// lancedb, and SQL is limited to queries. `raw` still hands out the writable table.
+    pub fn with_read_only(&self, read_only: bool) -> Self {
+        let mut store = self.clone();
+        store.read_only = read_only;
+        store
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn ensure_writable(&self) -> Result<()> {
+        if self.read_only {
+            return Err(anyhow!(
+                "Store for table '{}' is read-only",
+                self.table_name
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies query limits to this store and every copy made from it afterwards, which share
+    /// the concurrency slots. Searches, filters, lists, scans and counts are limited.
+    pub fn set_query_options(&mut self, options: QueryOptions) -> &mut Self {
+        self.query_timeout = options.timeout;
+        self.query_slots = options
+            .max_concurrent
+            .filter(|max| *max > 0)
+            .map(|max| Arc::new(tokio::sync::Semaphore::new(max)));
+        self
+    }
+
+    /// Copy of the store with another query timeout, e.g. a deadline for a single node.
+    pub fn with_timeout(&self, timeout: Option<std::time::Duration>) -> Self {
+        let mut store = self.clone();
+        store.query_timeout = timeout;
+        store
+    }
+
+    /// The timeout of `options`, falling back to the store's query timeout.
+    fn query_timeout(&self, options: Option<QueryOptions>) -> Option<std::time::Duration> {
+        options
+            .and_then(|options| options.timeout)
+            .or(self.query_timeout)
+    }
+
+    /// Hands the query timeout to lancedb, for queries supporting execution options.
+    fn execution_options(&self, options: Option<QueryOptions>) -> QueryExecutionOptions {
+        QueryExecutionOptions {
+            timeout: self.query_timeout(options),
+            ..Default::default()
+        }
+    }
+
+    /// Runs a query once a concurrency slot is free, failing if it exceeds the timeout of
+    /// `options` or the store. Waiting for a slot counts towards the timeout.
+    async fn bounded<T>(
+        &self,
+        options: Option<QueryOptions>,
+        query: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let query = async {
+            let _permit = match &self.query_slots {
+                Some(slots) => Some(slots.acquire().await?),
+                None => None,
+            };
+            query.await
+        };
+
+        match self.query_timeout(options) {
+            Some(timeout) => tokio::time::timeout(timeout, query).await.map_err(|_| {
+                anyhow!(
+                    "Query on table '{}' timed out after {:?}",
+                    self.table_name,
+                    timeout
+                )
+            })?,
+            None => query.await,
+        }
+    }
+
+    /// Reads a query result stream, dropping it early if the store's token is cancelled.
+    /// Stream errors yield `None` like a failed `try_collect` does.
+    async fn collect_batches<S, E>(&self, mut stream: S) -> Result<Option<Vec<RecordBatch>>>
+    where
+        S: Stream<Item = std::result::Result<RecordBatch, E>> + Unpin,
+    {
+        let Some(token) = &self.cancellation else {
+            return Ok(stream.try_collect::<Vec<_>>().await.ok());
+        };
+
+        let mut batches = vec![];
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return Err(anyhow!("Cancelled")),
+                batch = stream.try_next() => match batch {
+                    Ok(Some(batch)) => batches.push(batch),
+                    Ok(None) => return Ok(Some(batches)),
+                    Err(_) => return Ok(None),
+                },
+            }
         }
     }
 
+    async fn create_table(&self, items: impl RecordBatchReader + Send + 'static) -> Result<Table> {
+        let mut builder = self
+            .connection
+            .create_table(&self.table_name, items)
+            .mode(self.write_mode.into());
+
+        if let Some(version) = &self.data_storage_version {
+            builder = builder.storage_option("new_table_data_storage_version", version);
+        }
+
+        builder
+            .execute()
+            .await
+            .map_err(|e| anyhow!("Error creating table '{}': {}", self.table_name, e))
+    }
+
+    /// Pre-flight check for writes, reporting the first record index and field that doesn't fit.
+    ///
+    /// Records are checked against the table schema. If the table doesn't exist yet, the schema
+    /// is inferred from the first record and only fields shared with it are type checked.
+    pub async fn validate_records(&self, items: &[Value]) -> Result<()> {
+        if let Some(table) = &self.table {
+            let schema = table.schema().await?;
+            return validate_records(&schema, items, true);
+        }
+
+        let Some(first) = items.first() else {
+            return Ok(());
+        };
+        let schema = value_to_record_batch(vec![first.clone()])
+            .map_err(|e| anyhow!("Record 0: {}", e))?
+            .schema();
+        validate_records(&schema, items, false)
+    }
+
     pub async fn list_tables(&self) -> Result<Vec<String>> {
         let tables = self.connection.table_names().execute().await?;
         Ok(tables)
     }
 
+    /// Copies the table `src` with its schema and data into a new table `dst` on the same
+    /// connection, e.g. as a working copy before a destructive optimize or alter.
+    ///
+    /// Indices are not copied. The data is read into memory before writing, and an existing
+    /// `dst` is only replaced if `overwrite` is set.
+    pub async fn copy_table(&self, src: &str, dst: &str, overwrite: bool) -> Result<()> {
+        self.ensure_writable()?;
+        self.ensure_unscoped("Copying tables")?;
+        if src == dst {
+            return Err(anyhow!("Source and destination table are both '{}'", src));
+        }
+
+        let source = self
+            .connection
+            .open_table(src)
+            .execute()
+            .await
+            .map_err(|e| anyhow!("Error opening source table '{}': {}", src, e))?;
+        let schema = source.schema().await?;
+        let batches = source
+            .query()
+            .execute()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let items = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+
+        let mode = if overwrite {
+            TableWriteMode::Overwrite
+        } else {
+            TableWriteMode::Create
+        };
+        let mut builder = self.connection.create_table(dst, items).mode(mode.into());
+        if let Some(version) = &self.data_storage_version {
+            builder = builder.storage_option("new_table_data_storage_version", version);
+        }
+
+        builder
+            .execute()
+            .await
+            .map_err(|e| anyhow!("Error copying table '{}' to '{}': {}", src, dst, e))?;
+        Ok(())
+    }
+
     pub async fn add_columns(
         &self,
         transform: NewColumnTransform,
         read_columns: Option<Vec<String>>,
     ) -> Result<AddColumnsResult> {
+        self.ensure_writable()?;
         let table = self
             .table
             .clone()
@@ -107,6 +443,7 @@ impl LanceDBVectorStore {
     }
 
     pub async fn drop_columns(&self, column_names: &[&str]) -> Result<()> {
+        self.ensure_writable()?;
         let table = self
             .table
             .clone()
@@ -120,6 +457,7 @@ impl LanceDBVectorStore {
         &self,
         alteration: &[ColumnAlteration],
     ) -> Result<AlterColumnsResult> {
+        self.ensure_writable()?;
         let table = self
             .table
             .clone()
@@ -129,6 +467,52 @@ impl LanceDBVectorStore {
         Ok(result)
     }
 
+    /// Converges the table schema towards `target`, see `plan_migration` for which changes are
+    /// considered safe. Nothing is changed if any step is refused or `dry_run` is set.
+    /// Returns the planned changes.
+    pub async fn migrate_schema(
+        &self,
+        target: &arrow_schema::Schema,
+        allow_destructive: bool,
+        dry_run: bool,
+    ) -> Result<Vec<SchemaChange>> {
+        let current = self.schema().await?;
+        let changes = plan_migration(&current, target, allow_destructive)?;
+        if dry_run || changes.is_empty() {
+            return Ok(changes);
+        }
+        self.ensure_writable()?;
+
+        let mut added = vec![];
+        let mut alterations = vec![];
+        let mut dropped = vec![];
+        for change in &changes {
+            match change {
+                SchemaChange::AddColumn(field) => added.push(field.clone()),
+                SchemaChange::CastColumn { column, data_type } => alterations
+                    .push(ColumnAlteration::new(column.clone()).cast_to(data_type.clone())),
+                SchemaChange::MakeNullable { column } => {
+                    alterations.push(ColumnAlteration::new(column.clone()).set_nullable(true))
+                }
+                SchemaChange::DropColumn { column } => dropped.push(column.as_str()),
+            }
+        }
+
+        if !alterations.is_empty() {
+            self.alter_column(&alterations).await?;
+        }
+        if !added.is_empty() {
+            let schema = arrow_schema::Schema::new(added);
+            self.add_columns(NewColumnTransform::AllNulls(Arc::new(schema)), None)
+                .await?;
+        }
+        if !dropped.is_empty() {
+            self.drop_columns(&dropped).await?;
+        }
+
+        Ok(changes)
+    }
+
     pub async fn list_indices(&self) -> Result<Vec<IndexConfigDto>> {
         let indices = self
             .table
@@ -138,7 +522,164 @@ impl LanceDBVectorStore {
         Ok(indices.into_iter().map(IndexConfigDto::from).collect())
     }
 
+    /// Upserts `items` like `upsert`, deriving the id of records lacking `id_field` (or holding
+    /// null) with `id_fn`, e.g. a content hash, so logically identical content merges into one row
+    /// instead of being inserted again. The completed records are validated before merging.
+    pub async fn upsert_with_id_fn<F>(
+        &mut self,
+        mut items: Vec<Value>,
+        id_field: String,
+        id_fn: F,
+    ) -> Result<()>
+    where
+        F: Fn(&Value) -> Result<Value>,
+    {
+        self.ensure_writable()?;
+        for item in items.iter_mut() {
+            if !item.get(&id_field).is_none_or(Value::is_null) {
+                continue;
+            }
+            let id = id_fn(item)?;
+            item.as_object_mut()
+                .ok_or_else(|| anyhow!("Items to upsert have to be objects, got {}", item))?
+                .insert(id_field.clone(), id);
+        }
+
+        self.validate_records(&items).await?;
+        self.upsert(items, id_field).await
+    }
+
+    /// One page of a keyset scan over the table in `_rowid` order, for walking a large table
+    /// without loading it whole. Pass the returned cursor as `after` to get the next page, it is
+    /// `None` once the table is exhausted.
+    ///
+    /// Unlike `list` with an offset, rows written or deleted between pages don't shift the pages:
+    /// every row present for the whole scan is returned exactly once. Compaction reassigns row ids,
+    /// so it should not run during a scan.
+    pub async fn scan_page(
+        &self,
+        after: Option<u64>,
+        filter: Option<&str>,
+        limit: usize,
+    ) -> Result<(Vec<Value>, Option<u64>)> {
+        let table = self
+            .table
+            .clone()
+            .ok_or_else(|| anyhow!("Table not initialized"))?;
+
+        let cursor = after.map(|after| format!("_rowid > {}", after));
+        let filters = [self.scope_filter.as_deref(), filter, cursor.as_deref()];
+        let filter = and_filters(&filters.into_iter().flatten().collect::<Vec<_>>())?;
+
+        let mut query = table.query().with_row_id().limit(limit);
+        if let Some(filter) = filter {
+            query = query.only_if(filter);
+        }
+
+        let result = self
+            .bounded(None, async {
+                let result = query
+                    .execute_with_options(self.execution_options(None))
+                    .await?;
+                self.collect_batches(result).await
+            })
+            .await?;
+        let mut items = record_batches_to_vec(result)?;
+
+        let next = items
+            .iter()
+            .filter_map(|item| item.get("_rowid").and_then(Value::as_u64))
+            .max();
+        if !self.with_row_id {
+            for item in items.iter_mut() {
+                if let Some(item) = item.as_object_mut() {
+                    item.remove("_rowid");
+                }
+            }
+        }
+
+        if items.len() < limit {
+            return Ok((items, None));
+        }
+        Ok((items, next))
+    }
+
+    /// Removes old table versions, `older_than` defaults to lancedb's retention (7 days).
+    /// Tagged versions are never removed, pruning fails if one of them would be.
+    pub async fn prune(&self, older_than: Option<std::time::Duration>) -> Result<()> {
+        self.ensure_writable()?;
+        let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
+        let older_than = older_than.map(Duration::from_std).transpose()?;
+
+        table
+            .optimize(lancedb::table::OptimizeAction::Prune {
+                delete_unverified: Some(true),
+                error_if_tagged_old_versions: Some(true),
+                older_than,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Merges small data files and materializes deletions, cheap enough to run often.
+    pub async fn compact(&self) -> Result<()> {
+        self.ensure_writable()?;
+        let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
+
+        table
+            .optimize(lancedb::table::OptimizeAction::Compact {
+                options: CompactionOptions {
+                    ..Default::default()
+                },
+                remap_options: None,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Adds rows written since the last index update to all indices, the expensive part of
+    /// [`VectorStore::optimize`].
+    pub async fn reindex(&self) -> Result<()> {
+        self.ensure_writable()?;
+        let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
+
+        table
+            .optimize(lancedb::table::OptimizeAction::Index(OptimizeOptions {
+                ..Default::default()
+            }))
+            .await?;
+        Ok(())
+    }
+
+    /// Indexed and not yet indexed row counts of the first index on `column`,
+    /// `None` if the column has no index.
+    pub async fn index_coverage(&self, column: &str) -> Result<Option<(usize, usize)>> {
+        let table = self
+            .table
+            .clone()
+            .ok_or_else(|| anyhow!("Table not initialized"))?;
+        let index = table
+            .list_indices()
+            .await?
+            .into_iter()
+            .find(|index| index.columns.iter().any(|indexed| indexed == column));
+        let Some(index) = index else {
+            return Ok(None);
+        };
+
+        let stats = table
+            .index_stats(&index.name)
+            .await?
+            .ok_or_else(|| anyhow!("No statistics for index '{}'", index.name))?;
+        Ok(Some((stats.num_indexed_rows, stats.num_unindexed_rows)))
+    }
+
     pub async fn to_datafusion(&self) -> Result<lancedb::table::datafusion::BaseTableAdapter> {
+        self.ensure_unscoped("DataFusion access")?;
+        self.base_table_adapter().await
+    }
+
+    async fn base_table_adapter(&self) -> Result<lancedb::table::datafusion::BaseTableAdapter> {
         let table = self
             .table
             .clone()
@@ -150,6 +691,7 @@ impl LanceDBVectorStore {
     }
 
     pub async fn raw(&self) -> Result<Table> {
+        self.ensure_unscoped("Raw table access")?;
         let table = self
             .table
             .clone()
@@ -162,13 +704,77 @@ impl LanceDBVectorStore {
         table_name: &str,
         sql: &str,
     ) -> Result<datafusion::dataframe::DataFrame> {
-        let table = self.to_datafusion().await?;
+        self.sql_with_options(table_name, sql, SQLOptions::new())
+            .await
+    }
+
+    /// Runs `sql` against the table registered as `table_name`, rejecting the statement
+    /// kinds `options` disallows (e.g. DDL and DML for read only queries). On a scoped store
+    /// `table_name` only holds the rows matching the scope filter.
+    pub async fn sql_with_options(
+        &self,
+        table_name: &str,
+        sql: &str,
+        options: SQLOptions,
+    ) -> Result<datafusion::dataframe::DataFrame> {
+        let options = if self.read_only {
+            options.with_allow_ddl(false).with_allow_dml(false)
+        } else {
+            options
+        };
+        let table = self.base_table_adapter().await?;
         let ctx = SessionContext::new();
-        ctx.register_table(table_name, Arc::new(table))?;
-        let results = ctx.sql(sql).await?;
+        match &self.scope_filter {
+            None => {
+                ctx.register_table(table_name, Arc::new(table))?;
+            }
+            Some(scope) => {
+                // scoped in a separate session, so `sql` can't name the unscoped table
+                let scope_ctx = SessionContext::new();
+                scope_ctx.register_table("scoped", Arc::new(table))?;
+                let view = scope_ctx
+                    .sql(&format!("SELECT * FROM scoped WHERE {}", scope))
+                    .await?
+                    .into_view();
+                ctx.register_table(table_name, view)?;
+            }
+        }
+        let results = ctx.sql_with_options(sql, options).await?;
 
         Ok(results)
     }
+
+    /// Returns the logical and physical plan DataFusion picks for a read only query,
+    /// `analyze` runs the query to add the measured metrics to the plan.
+    pub async fn explain(&self, table_name: &str, sql: &str, analyze: bool) -> Result<String> {
+        let batches = self
+            .sql_with_options(table_name, sql, SQLOptions::new())
+            .await?
+            .explain(false, analyze)?
+            .collect()
+            .await?;
+
+        let mut plan = String::new();
+        for row in record_batches_to_vec(Some(batches))? {
+            let plan_type = row.get("plan_type").and_then(Value::as_str).unwrap_or("");
+            let text = row.get("plan").and_then(Value::as_str).unwrap_or("");
+            plan.push_str(&format!("{}:\n{}\n\n", plan_type, text));
+        }
+
+        Ok(plan.trim_end().to_string())
+    }
+}
+
+/// Maps an index type name to an index that can span several columns.
+fn composite_index(index_type: &str) -> Result<Index> {
+    match index_type {
+        "FULL TEXT" => Ok(Index::FTS(FtsIndexBuilder::default())),
+        "BTREE" => Ok(Index::BTree(BTreeIndexBuilder::default())),
+        other => Err(anyhow!(
+            "Index type '{}' can't span multiple columns, use BTREE for combined filters, FULL TEXT for searching several text columns, or build one index per column",
+            other
+        )),
+    }
 }
 
 pub fn record_batches_to_vec(batches: Option<Vec<RecordBatch>>) -> Result<Vec<Value>> {
@@ -178,19 +784,33 @@ pub fn record_batches_to_vec(batches: Option<Vec<RecordBatch>>) -> Result<Vec<Va
 
     let batches = batches.unwrap();
     let mut items = vec![];
+    let mut failed = vec![];
 
-    for batch in batches {
-        let values = record_batch_to_value(&batch);
-        match values {
+    for (index, batch) in batches.iter().enumerate() {
+        match record_batch_to_value(batch) {
             Ok(mut values) => {
                 items.append(&mut values);
             }
             Err(err) => {
-                println!("Error converting batch to value: {:?}", err);
+                failed.push(format!(
+                    "batch {} ({} rows): {}",
+                    index,
+                    batch.num_rows(),
+                    err
+                ));
             }
         }
     }
 
+    if !failed.is_empty() {
+        return Err(anyhow!(
+            "Error converting {} of {} record batches to values: {}",
+            failed.len(),
+            batches.len(),
+            failed.join("; ")
+        ));
+    }
+
     Ok(items)
 }
 
@@ -203,6 +823,8 @@ impl VectorStore for LanceDBVectorStore {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        fast_search: bool,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>> {
         let table = self
             .table
@@ -213,11 +835,14 @@ impl VectorStore for LanceDBVectorStore {
             .query()
             .nearest_to(vector)?
             .distance_type(lancedb::DistanceType::Cosine)
-            .fast_search()
             .limit(limit)
             .offset(offset);
 
-        if let Some(filter) = filter {
+        if fast_search {
+            query = query.fast_search();
+        }
+
+        if let Some(filter) = self.scoped_filter(filter)? {
             query = query.only_if(filter);
         }
 
@@ -225,8 +850,18 @@ impl VectorStore for LanceDBVectorStore {
             query = query.select(lancedb::query::Select::Columns(select));
         }
 
-        let result = query.execute().await?;
-        let result = result.try_collect::<Vec<_>>().await.ok();
+        if self.with_row_id {
+            query = query.with_row_id();
+        }
+
+        let result = self
+            .bounded(options, async {
+                let result = query
+                    .execute_with_options(self.execution_options(options))
+                    .await?;
+                self.collect_batches(result).await
+            })
+            .await?;
         let result = record_batches_to_vec(result)?;
         Ok(result)
     }
@@ -238,6 +873,7 @@ impl VectorStore for LanceDBVectorStore {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>> {
         let table = self
             .table
@@ -250,7 +886,7 @@ impl VectorStore for LanceDBVectorStore {
             .limit(limit)
             .offset(offset);
 
-        if let Some(filter) = filter {
+        if let Some(filter) = self.scoped_filter(filter)? {
             query = query.only_if(filter);
         }
 
@@ -258,8 +894,18 @@ impl VectorStore for LanceDBVectorStore {
             query = query.select(lancedb::query::Select::Columns(select));
         }
 
-        let result = query.execute().await?;
-        let result = result.try_collect::<Vec<_>>().await.ok();
+        if self.with_row_id {
+            query = query.with_row_id();
+        }
+
+        let result = self
+            .bounded(options, async {
+                let result = query
+                    .execute_with_options(self.execution_options(options))
+                    .await?;
+                self.collect_batches(result).await
+            })
+            .await?;
         let result = record_batches_to_vec(result)?;
         Ok(result)
     }
@@ -273,6 +919,8 @@ impl VectorStore for LanceDBVectorStore {
         limit: usize,
         offset: usize,
         rerank: bool,
+        rrf_k: Option<f32>,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>> {
         let table = self
             .table
@@ -289,11 +937,15 @@ impl VectorStore for LanceDBVectorStore {
             .offset(offset);
 
         if rerank {
-            let reranker = Arc::new(lancedb::rerankers::rrf::RRFReranker::new(60.0));
+            let rrf_k = rrf_k.unwrap_or(DEFAULT_RRF_K);
+            if rrf_k <= 0.0 {
+                return Err(anyhow!("RRF k must be positive, got {}", rrf_k));
+            }
+            let reranker = Arc::new(lancedb::rerankers::rrf::RRFReranker::new(rrf_k));
             query = query.rerank(reranker);
         }
 
-        if let Some(filter) = filter {
+        if let Some(filter) = self.scoped_filter(filter)? {
             query = query.only_if(filter);
         }
 
@@ -301,10 +953,18 @@ impl VectorStore for LanceDBVectorStore {
             query = query.select(lancedb::query::Select::Columns(select));
         }
 
-        let result = query
-            .execute_hybrid(QueryExecutionOptions::default())
+        if self.with_row_id {
+            query = query.with_row_id();
+        }
+
+        let result = self
+            .bounded(options, async {
+                let result = query
+                    .execute_hybrid(self.execution_options(options))
+                    .await?;
+                self.collect_batches(result).await
+            })
             .await?;
-        let result = result.try_collect::<Vec<_>>().await.ok();
         let result = record_batches_to_vec(result)?;
         Ok(result)
     }
@@ -315,25 +975,40 @@ impl VectorStore for LanceDBVectorStore {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>> {
         let table = self
             .table
             .clone()
             .ok_or_else(|| anyhow!("Table not initialized"))?;
 
+        let filter = self
+            .scoped_filter(Some(filter))?
+            .ok_or_else(|| anyhow!("A filter is required"))?;
         let mut query = table.query().limit(limit).only_if(filter).offset(offset);
 
         if let Some(select) = select {
             query = query.select(lancedb::query::Select::Columns(select));
         }
 
-        let result = query.execute().await?;
-        let result = result.try_collect::<Vec<_>>().await.ok();
+        if self.with_row_id {
+            query = query.with_row_id();
+        }
+
+        let result = self
+            .bounded(options, async {
+                let result = query
+                    .execute_with_options(self.execution_options(options))
+                    .await?;
+                self.collect_batches(result).await
+            })
+            .await?;
         let result = record_batches_to_vec(result)?;
         Ok(result)
     }
 
     async fn upsert(&mut self, items: Vec<Value>, id_field: String) -> Result<()> {
+        self.ensure_writable()?;
         let items = match value_to_batch_iterator(items) {
             Ok(items) => items,
             Err(err) => {
@@ -342,21 +1017,8 @@ impl VectorStore for LanceDBVectorStore {
         };
 
         if self.table.is_none() {
-            match self
-                .connection
-                .create_table(&self.table_name, items)
-                .execute()
-                .await
-            {
-                Ok(table) => {
-                    self.table = Some(table);
-                    return Ok(());
-                }
-                Err(err) => {
-                    println!("Error creating table: {:?}", err);
-                    return Err(anyhow!("Error creating table"));
-                }
-            }
+            self.table = Some(self.create_table(items).await?);
+            return Ok(());
         }
 
         let table = self.table.clone().unwrap();
@@ -366,11 +1028,13 @@ impl VectorStore for LanceDBVectorStore {
             .when_not_matched_insert_all()
             .to_owned()
             .execute(Box::new(items))
-            .await?;
+            .await
+            .map_err(|e| anyhow!("Error upserting into table '{}': {}", self.table_name, e))?;
         Ok(())
     }
 
     async fn insert(&mut self, items: Vec<Value>) -> Result<()> {
+        self.ensure_writable()?;
         let items = match value_to_batch_iterator(items) {
             Ok(items) => items,
             Err(err) => {
@@ -379,69 +1043,81 @@ impl VectorStore for LanceDBVectorStore {
         };
 
         if self.table.is_none() {
-            match self
-                .connection
-                .create_table(&self.table_name, items)
-                .execute()
-                .await
-            {
-                Ok(table) => {
-                    self.table = Some(table);
-                    return Ok(());
-                }
-                Err(err) => {
-                    println!("Error creating table: {:?}", err);
-                    return Err(anyhow!("Error creating table"));
-                }
-            }
+            self.table = Some(self.create_table(items).await?);
+            return Ok(());
         }
 
         let table = self.table.clone().unwrap();
         match table.add(items).execute().await {
             Ok(_) => return Ok(()),
             Err(err) => {
-                return Err(anyhow!(err.to_string()));
+                return Err(anyhow!(
+                    "Error inserting into table '{}': {}",
+                    self.table_name,
+                    err
+                ));
             }
         }
     }
 
     async fn delete(&self, filter: &str) -> Result<()> {
+        self.ensure_writable()?;
         let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
-        table.delete(filter).await?;
+        let filter = self
+            .scoped_filter(Some(filter))?
+            .ok_or_else(|| anyhow!("A filter is required to delete items"))?;
+        table.delete(&filter).await?;
         return Ok(());
     }
 
-    async fn optimize(&self, keep_versions: bool) -> Result<()> {
-        let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
+    async fn replace_where(&mut self, filter: &str, items: Vec<Value>) -> Result<()> {
+        self.ensure_writable()?;
+        if filter.trim().is_empty() {
+            return Err(anyhow!("A filter is required to replace items"));
+        }
+
+        let filter = self
+            .scoped_filter(Some(filter))?
+            .ok_or_else(|| anyhow!("A filter is required to replace items"))?;
+
+        // catch malformed records before anything is deleted
+        self.validate_records(&items).await?;
+
+        if let Some(table) = &self.table {
+            table.delete(&filter).await.map_err(|e| {
+                anyhow!(
+                    "Error deleting '{}' from table '{}': {}",
+                    filter,
+                    self.table_name,
+                    e
+                )
+            })?;
+        }
 
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        self.insert(items).await.map_err(|e| {
+            anyhow!(
+                "Deleted '{}' but inserting the replacement failed: {}",
+                filter,
+                e
+            )
+        })
+    }
+
+    async fn optimize(&self, keep_versions: bool) -> Result<()> {
+        self.ensure_writable()?;
         let older_than = if keep_versions {
             None
         } else {
-            Some(Duration::milliseconds(1))
+            Some(std::time::Duration::from_millis(1))
         };
 
-        table
-            .optimize(lancedb::table::OptimizeAction::Prune {
-                delete_unverified: Some(true),
-                error_if_tagged_old_versions: Some(true),
-                older_than,
-            })
-            .await?;
-
-        table
-            .optimize(lancedb::table::OptimizeAction::Compact {
-                options: CompactionOptions {
-                    ..Default::default()
-                },
-                remap_options: None,
-            })
-            .await?;
-
-        table
-            .optimize(lancedb::table::OptimizeAction::Index(OptimizeOptions {
-                ..Default::default()
-            }))
-            .await?;
+        self.prune(older_than).await?;
+        self.compact().await?;
+        self.reindex().await?;
 
         return Ok(());
     }
@@ -451,6 +1127,7 @@ impl VectorStore for LanceDBVectorStore {
         select: Option<Vec<String>>,
         limit: usize,
         offset: usize,
+        options: Option<QueryOptions>,
     ) -> Result<Vec<Value>> {
         let table = self
             .table
@@ -459,19 +1136,32 @@ impl VectorStore for LanceDBVectorStore {
 
         let mut query = table.query().limit(limit).offset(offset);
 
+        if let Some(scope) = &self.scope_filter {
+            query = query.only_if(scope);
+        }
+
         if let Some(select) = select {
             query = query.select(lancedb::query::Select::Columns(select));
         }
 
-        let result = query.execute().await.ok();
-
-        result.as_ref().ok_or(anyhow!("Error executing query"))?;
+        if self.with_row_id {
+            query = query.with_row_id();
+        }
 
-        let result = result.unwrap().try_collect::<Vec<_>>().await.ok();
+        let result = self
+            .bounded(options, async {
+                let result = query
+                    .execute_with_options(self.execution_options(options))
+                    .await
+                    .map_err(|_| anyhow!("Error executing query"))?;
+                self.collect_batches(result).await
+            })
+            .await?;
         return record_batches_to_vec(result);
     }
 
     async fn index(&self, column: &str, index_type: Option<&str>) -> Result<()> {
+        self.ensure_writable()?;
         let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
         let index_type = index_type.unwrap_or("AUTO");
         let index_type = match index_type {
@@ -486,15 +1176,36 @@ impl VectorStore for LanceDBVectorStore {
         Ok(())
     }
 
+    async fn index_composite(&self, columns: &[&str], index_type: Option<&str>) -> Result<()> {
+        self.ensure_writable()?;
+        if columns.len() < 2 {
+            let column = columns
+                .first()
+                .ok_or(anyhow!("No columns given for the composite index"))?;
+            return self.index(column, index_type).await;
+        }
+
+        let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
+        let index_type = composite_index(index_type.unwrap_or("BTREE"))?;
+        table.create_index(columns, index_type).execute().await?;
+        Ok(())
+    }
+
     async fn purge(&self) -> Result<()> {
+        self.ensure_writable()?;
         let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
-        table.delete("1=1").await?;
+        table
+            .delete(self.scope_filter.as_deref().unwrap_or("1=1"))
+            .await?;
         Ok(())
     }
 
-    async fn count(&self, filter: Option<String>) -> Result<usize> {
+    async fn count(&self, filter: Option<String>, options: Option<QueryOptions>) -> Result<usize> {
         let table = self.table.clone().ok_or(anyhow!("Table not initialized"))?;
-        Ok(table.count_rows(filter).await?)
+        let filter = self.scoped_filter(filter.as_deref())?;
+        // counting takes no execution options, only the tokio timeout applies
+        self.bounded(options, async { Ok(table.count_rows(filter).await?) })
+            .await
     }
 
     async fn schema(&self) -> Result<arrow_schema::Schema> {
@@ -517,6 +1228,14 @@ mod tests {
     };
     use serde::{Deserialize, Serialize};
 
+    #[test]
+    fn composite_index_rejects_single_column_types() {
+        assert!(composite_index("BTREE").is_ok());
+        assert!(composite_index("FULL TEXT").is_ok());
+        assert!(composite_index("BITMAP").is_err());
+        assert!(composite_index("AUTO").is_err());
+    }
+
     #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
     struct TestStruct {
         id: i32,
@@ -534,7 +1253,8 @@ mod tests {
     async fn test_lance_ingest() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -564,7 +1284,8 @@ mod tests {
     async fn test_lance_search_first() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -587,7 +1308,7 @@ mod tests {
         db.upsert(json_records, "id".to_string()).await?;
 
         let search_results: Vec<Value> = db
-            .vector_search(vec![1.0, 2.0, 3.0], None, None, 10, 0)
+            .vector_search(vec![1.0, 2.0, 3.0], None, None, 10, 0, true, None)
             .await?;
 
         assert!(!search_results.is_empty());
@@ -605,7 +1326,8 @@ mod tests {
     async fn test_lance_search_fts() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -628,7 +1350,7 @@ mod tests {
         db.upsert(json_records, "id".to_string()).await?;
         db.index("name", Some("FULL TEXT")).await?;
 
-        let search_results: Vec<Value> = db.fts_search("Alice", None, None, 10, 0).await?;
+        let search_results: Vec<Value> = db.fts_search("Alice", None, None, 10, 0, None).await?;
 
         assert!(!search_results.is_empty());
 
@@ -645,7 +1367,8 @@ mod tests {
     async fn test_lance_search_second() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -668,7 +1391,7 @@ mod tests {
         db.upsert(json_records, "id".to_string()).await?;
 
         let search_results: Vec<Value> = db
-            .vector_search(vec![2.0, 3.0, 4.0], None, None, 10, 0)
+            .vector_search(vec![2.0, 3.0, 4.0], None, None, 10, 0, true, None)
             .await?;
 
         assert!(!search_results.is_empty());
@@ -686,7 +1409,8 @@ mod tests {
     async fn test_lance_search_filter() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -709,7 +1433,7 @@ mod tests {
         db.upsert(json_records, "id".to_string()).await?;
 
         let search_results: Vec<Value> = db
-            .vector_search(vec![1.0, 2.0, 3.0], Some("id = 2"), None, 10, 0)
+            .vector_search(vec![1.0, 2.0, 3.0], Some("id = 2"), None, 10, 0, true, None)
             .await?;
 
         assert!(!search_results.is_empty());
@@ -723,11 +1447,78 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_lance_scope_filter() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let records = vec![
+            TestStruct {
+                id: 1,
+                name: "Alice".to_string(),
+                vector: vec![1.0, 2.0, 3.0],
+            },
+            TestStruct {
+                id: 2,
+                name: "Bob".to_string(),
+                vector: vec![2.0, 3.0, 4.0],
+            },
+        ];
+
+        let json_records: Vec<Value> = records
+            .clone()
+            .into_iter()
+            .map(to_value)
+            .collect::<Result<_, _>>()?;
+        db.upsert(json_records.clone(), "id".to_string()).await?;
+
+        let scoped = db.with_scope_filter(Some("name = 'Alice'"))?;
+        assert_eq!(scoped.count(None, None).await?, 1);
+        assert_eq!(scoped.list(None, 10, 0, None).await?.len(), 1);
+
+        let results = scoped.filter("id = 2 OR true", None, 10, 0, None).await?;
+        let results: Vec<TestStruct> = results
+            .into_iter()
+            .map(from_value)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(results, vec![records[0].clone()]);
+
+        assert!(
+            scoped
+                .filter("id = 2) OR (true", None, 10, 0, None)
+                .await
+                .is_err()
+        );
+
+        let rows = scoped.sql("t", "SELECT * FROM t").await?.collect().await?;
+        assert_eq!(record_batches_to_vec(Some(rows))?.len(), 1);
+        assert!(scoped.raw().await.is_err());
+        assert!(scoped.to_datafusion().await.is_err());
+        assert!(scoped.copy_table("t", "t_copy", false).await.is_err());
+
+        scoped.delete("true").await?;
+        assert_eq!(db.count(None, None).await?, 1);
+
+        let mut other = db.for_table("u".to_string()).await;
+        other.upsert(json_records, "id".to_string()).await?;
+        let scoped_other = scoped.for_table("u".to_string()).await;
+        assert_eq!(other.count(None, None).await?, 2);
+        assert_eq!(scoped_other.count(None, None).await?, 1);
+        let narrowed = scoped_other.with_scope_filter(Some("id = 2"))?;
+        assert_eq!(narrowed.count(None, None).await?, 0);
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lance_no_vec() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct2 {
                 id: 1,
@@ -747,7 +1538,7 @@ mod tests {
 
         db.upsert(json_records, "id".to_string()).await?;
 
-        let count = db.count(None).await?;
+        let count = db.count(None, None).await?;
 
         assert_eq!(count, 2);
 
@@ -760,7 +1551,7 @@ mod tests {
     async fn test_casting() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string())
+        let db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None)
             .await
             .unwrap();
         let cacheable: Arc<dyn Cacheable> = Arc::new(db.clone());
@@ -778,7 +1569,8 @@ mod tests {
     async fn test_lance_select() -> Result<()> {
         let test_path = format!("./tmp/{}", create_id());
         std::fs::create_dir_all(&test_path).unwrap();
-        let mut db = LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string()).await?;
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
         let records = vec![
             TestStruct {
                 id: 1,
@@ -801,7 +1593,7 @@ mod tests {
         db.upsert(json_records, "id".to_string()).await?;
 
         let select = Some(vec!["id".to_string(), "name".to_string()]);
-        let results: Vec<Value> = db.list(select, 10, 0).await?;
+        let results: Vec<Value> = db.list(select, 10, 0, None).await?;
 
         assert!(!results.is_empty());
 
@@ -819,4 +1611,254 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_lance_row_id() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let records = vec![
+            TestStruct {
+                id: 1,
+                name: "Alice".to_string(),
+                vector: vec![1.0, 2.0, 3.0],
+            },
+            TestStruct {
+                id: 2,
+                name: "Bob".to_string(),
+                vector: vec![2.0, 3.0, 4.0],
+            },
+        ];
+
+        let json_records: Vec<Value> = records
+            .into_iter()
+            .map(to_value)
+            .collect::<Result<_, _>>()?;
+        db.insert(json_records).await?;
+
+        let results = db.list(None, 10, 0, None).await?;
+        assert!(results.iter().all(|item| item.get("_rowid").is_none()));
+
+        let results = db.with_row_id(true).list(None, 10, 0, None).await?;
+        let bob = results
+            .iter()
+            .find(|item| item["name"] == "Bob")
+            .expect("Bob should be listed");
+        let row_id = bob["_rowid"].as_u64().expect("_rowid should be set");
+
+        db.delete(&format!("_rowid = {}", row_id)).await?;
+        assert_eq!(db.count(None, None).await?, 1);
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_upsert_with_id_fn() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let id_fn = |item: &Value| Ok(Value::from(item["name"].as_str().unwrap_or("").len()));
+
+        let items = vec![
+            flow_like_types::json::json!({"id": 1, "name": "Al"}),
+            flow_like_types::json::json!({"id": null, "name": "Bob"}),
+        ];
+        db.upsert_with_id_fn(items, "id".to_string(), id_fn).await?;
+
+        // the same content without an id merges into the row created before
+        let items = vec![flow_like_types::json::json!({"name": "Eve"})];
+        db.upsert_with_id_fn(items, "id".to_string(), id_fn).await?;
+        assert_eq!(db.count(None, None).await?, 2);
+        assert_eq!(
+            db.filter("id = 3", None, 10, 0, None).await?[0]["name"],
+            "Eve"
+        );
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_migrate_schema() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let json_records: Vec<Value> = vec![to_value(TestStruct2 {
+            id: 1,
+            name: "Alice".to_string(),
+        })?];
+        db.insert(json_records).await?;
+
+        let mut fields: Vec<arrow_schema::Field> = db
+            .schema()
+            .await?
+            .fields()
+            .iter()
+            .map(|field| field.as_ref().clone())
+            .collect();
+        fields.push(arrow_schema::Field::new(
+            "score",
+            arrow_schema::DataType::Float64,
+            true,
+        ));
+        let target = arrow_schema::Schema::new(fields);
+
+        let planned = db.migrate_schema(&target, false, true).await?;
+        assert_eq!(planned.len(), 1);
+        assert!(db.schema().await?.field_with_name("score").is_err());
+
+        db.migrate_schema(&target, false, false).await?;
+        assert!(db.schema().await?.field_with_name("score").is_ok());
+        assert!(db.migrate_schema(&target, false, false).await?.is_empty());
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_query_options() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        db.set_query_options(QueryOptions {
+            timeout: Some(std::time::Duration::from_secs(10)),
+            max_concurrent: Some(1),
+        });
+
+        let json_records: Vec<Value> = vec![to_value(TestStruct2 {
+            id: 1,
+            name: "Alice".to_string(),
+        })?];
+        db.insert(json_records).await?;
+        assert_eq!(db.count(None, None).await?, 1);
+
+        // the only slot is taken, so the query can't start before its deadline
+        let copy = db.with_timeout(Some(std::time::Duration::from_millis(20)));
+        let _permit = db.query_slots.as_ref().unwrap().acquire().await?;
+        assert!(copy.count(None, None).await.is_err());
+        let deadline = QueryOptions {
+            timeout: Some(std::time::Duration::from_millis(20)),
+            max_concurrent: None,
+        };
+        assert!(db.count(None, Some(deadline)).await.is_err());
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_read_only() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let json_records: Vec<Value> = vec![to_value(TestStruct2 {
+            id: 1,
+            name: "Alice".to_string(),
+        })?];
+        db.insert(json_records.clone()).await?;
+
+        let mut read_only = db.with_read_only(true);
+        let error = read_only.insert(json_records.clone()).await.unwrap_err();
+        assert!(error.to_string().contains("read-only"));
+        assert!(read_only.delete("id = 1").await.is_err());
+        assert!(read_only.optimize(true).await.is_err());
+        assert!(read_only.sql("t", "DELETE FROM t").await.is_err());
+
+        assert_eq!(read_only.count(None, None).await?, 1);
+        assert_eq!(
+            read_only.filter("id = 1", None, 10, 0, None).await?.len(),
+            1
+        );
+
+        let mut other = read_only.for_table("u".to_string()).await;
+        assert!(other.is_read_only());
+        let error = other.insert(json_records).await.unwrap_err();
+        assert!(error.to_string().contains("read-only"));
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_scan_page() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let json_records: Vec<Value> = (0..5)
+            .map(|id| {
+                to_value(TestStruct {
+                    id,
+                    name: format!("Item {}", id),
+                    vector: vec![1.0, 2.0, 3.0],
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        db.insert(json_records).await?;
+
+        let (first, cursor) = db.scan_page(None, None, 2).await?;
+        assert_eq!(first.len(), 2);
+        assert!(first.iter().all(|item| item.get("_rowid").is_none()));
+
+        // deleting an already seen row must not make the scan skip unseen ones
+        db.delete(&format!("id = {}", first[0]["id"])).await?;
+
+        let mut seen = first;
+        let mut cursor = cursor;
+        while let Some(after) = cursor {
+            let (page, next) = db.scan_page(Some(after), None, 2).await?;
+            seen.extend(page);
+            cursor = next;
+        }
+
+        let mut ids: Vec<i64> = seen.iter().filter_map(|item| item["id"].as_i64()).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lance_copy_table() -> Result<()> {
+        let test_path = format!("./tmp/{}", create_id());
+        std::fs::create_dir_all(&test_path).unwrap();
+        let mut db =
+            LanceDBVectorStore::new(PathBuf::from(&test_path), "t".to_string(), None).await?;
+        let records = vec![TestStruct {
+            id: 1,
+            name: "Alice".to_string(),
+            vector: vec![1.0, 2.0, 3.0],
+        }];
+        let json_records: Vec<Value> = records
+            .into_iter()
+            .map(to_value)
+            .collect::<Result<_, _>>()?;
+        db.insert(json_records).await?;
+
+        db.copy_table("t", "t_copy", false).await?;
+        let copy = db.for_table("t_copy".to_string()).await;
+        assert_eq!(copy.count(None, None).await?, 1);
+        assert_eq!(copy.schema().await?, db.schema().await?);
+
+        assert!(db.copy_table("t", "t_copy", false).await.is_err());
+        db.copy_table("t", "t_copy", true).await?;
+        assert!(db.copy_table("t", "t", true).await.is_err());
+
+        std::fs::remove_dir_all(&test_path).unwrap();
+
+        Ok(())
+    }
 }
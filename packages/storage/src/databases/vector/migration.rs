@@ -0,0 +1,207 @@
+use std::fmt;
+
+use arrow_schema::{DataType, Field, Schema};
+use flow_like_types::{Result, anyhow};
+
+/// A single step converging a table's schema towards a target schema.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaChange {
+    /// Adds a nullable column, existing rows get null.
+    AddColumn(Field),
+    /// Casts a column to a wider type that holds every current value.
+    CastColumn { column: String, data_type: DataType },
+    /// Allows null in a column that was non-nullable.
+    MakeNullable { column: String },
+    /// Removes a column and its data, only planned if destructive changes are allowed.
+    DropColumn { column: String },
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaChange::AddColumn(field) => {
+                write!(f, "add column '{}' ({})", field.name(), field.data_type())
+            }
+            SchemaChange::CastColumn { column, data_type } => {
+                write!(f, "cast column '{}' to {}", column, data_type)
+            }
+            SchemaChange::MakeNullable { column } => {
+                write!(f, "make column '{}' nullable", column)
+            }
+            SchemaChange::DropColumn { column } => write!(f, "drop column '{}'", column),
+        }
+    }
+}
+
+/// True if a column of type `from` can be altered to `to` without losing or rejecting values,
+/// e.g. widening integers or floats. Narrowing and cross-kind casts are refused.
+pub fn can_alter(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+
+    if from == to {
+        return true;
+    }
+
+    let rank = |data_type: &DataType| match data_type {
+        Int8 => Some((0, 0)),
+        Int16 => Some((0, 1)),
+        Int32 => Some((0, 2)),
+        Int64 => Some((0, 3)),
+        UInt8 => Some((1, 0)),
+        UInt16 => Some((1, 1)),
+        UInt32 => Some((1, 2)),
+        UInt64 => Some((1, 3)),
+        Float16 => Some((2, 0)),
+        Float32 => Some((2, 1)),
+        Float64 => Some((2, 2)),
+        Utf8 => Some((3, 0)),
+        LargeUtf8 => Some((3, 1)),
+        Binary => Some((4, 0)),
+        LargeBinary => Some((4, 1)),
+        _ => None,
+    };
+
+    match (rank(from), rank(to)) {
+        (Some((from_kind, from_width)), Some((to_kind, to_width))) => {
+            from_kind == to_kind && from_width < to_width
+        }
+        _ => false,
+    }
+}
+
+/// Changes turning `current` into `target`. Columns are matched by name, only top-level columns
+/// are compared. Fails without planning anything if a change would be unsafe: a missing
+/// non-nullable column, a narrowing cast, making a column non-nullable, or dropping a column
+/// while `allow_destructive` is false.
+pub fn plan_migration(
+    current: &Schema,
+    target: &Schema,
+    allow_destructive: bool,
+) -> Result<Vec<SchemaChange>> {
+    let mut changes = vec![];
+    let mut refused = vec![];
+
+    for field in target.fields() {
+        let Ok(existing) = current.field_with_name(field.name()) else {
+            if field.is_nullable() {
+                changes.push(SchemaChange::AddColumn(field.as_ref().clone()));
+            } else {
+                refused.push(format!(
+                    "column '{}' can't be added as non-nullable, existing rows have no value",
+                    field.name()
+                ));
+            }
+            continue;
+        };
+
+        if existing.data_type() != field.data_type() {
+            if can_alter(existing.data_type(), field.data_type()) {
+                changes.push(SchemaChange::CastColumn {
+                    column: field.name().clone(),
+                    data_type: field.data_type().clone(),
+                });
+            } else {
+                refused.push(format!(
+                    "column '{}' can't be cast from {} to {}",
+                    field.name(),
+                    existing.data_type(),
+                    field.data_type()
+                ));
+            }
+        }
+
+        match (existing.is_nullable(), field.is_nullable()) {
+            (false, true) => changes.push(SchemaChange::MakeNullable {
+                column: field.name().clone(),
+            }),
+            (true, false) => refused.push(format!(
+                "column '{}' can't be made non-nullable",
+                field.name()
+            )),
+            _ => {}
+        }
+    }
+
+    for field in current.fields() {
+        if target.field_with_name(field.name()).is_ok() {
+            continue;
+        }
+        if allow_destructive {
+            changes.push(SchemaChange::DropColumn {
+                column: field.name().clone(),
+            });
+        } else {
+            refused.push(format!(
+                "column '{}' would be dropped, which requires allowing destructive changes",
+                field.name()
+            ));
+        }
+    }
+
+    if !refused.is_empty() {
+        return Err(anyhow!("Unsafe migration: {}", refused.join("; ")));
+    }
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(fields: Vec<Field>) -> Schema {
+        Schema::new(fields)
+    }
+
+    #[test]
+    fn plans_safe_changes() {
+        let current = schema(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, false),
+        ]);
+        let target = schema(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("text", DataType::Utf8, true),
+            Field::new("score", DataType::Float32, true),
+        ]);
+
+        let changes = plan_migration(&current, &target, false).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                SchemaChange::CastColumn {
+                    column: "id".to_string(),
+                    data_type: DataType::Int64
+                },
+                SchemaChange::MakeNullable {
+                    column: "text".to_string()
+                },
+                SchemaChange::AddColumn(Field::new("score", DataType::Float32, true)),
+            ]
+        );
+        assert!(plan_migration(&target, &target, false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn refuses_unsafe_changes() {
+        let current = schema(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("old", DataType::Utf8, true),
+        ]);
+
+        let narrowing = schema(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("old", DataType::Utf8, true),
+        ]);
+        assert!(plan_migration(&current, &narrowing, true).is_err());
+
+        let dropping = schema(vec![Field::new("id", DataType::Int64, true)]);
+        assert!(plan_migration(&current, &dropping, false).is_err());
+        assert_eq!(
+            plan_migration(&current, &dropping, true).unwrap(),
+            vec![SchemaChange::DropColumn {
+                column: "old".to_string()
+            }]
+        );
+    }
+}